@@ -8,13 +8,16 @@ use brace_ec::fitness::summed::Summed;
 use brace_ec::generation::Generation;
 use brace_ec::individual::evaluated::Evaluated;
 use brace_ec::individual::Individual;
+use brace_ec::operator::adaptive::Adaptive;
 use brace_ec::operator::evaluator::hiff::Hiff;
 use brace_ec::operator::evolver::Evolver;
 use brace_ec::operator::generator::random::Random;
 use brace_ec::operator::generator::Generator;
 use brace_ec::operator::mutator::invert::Invert;
 use brace_ec::operator::mutator::Mutator;
+use brace_ec::operator::recombinator::mutate::Mutate;
 use brace_ec::operator::recombinator::point::TwoPointCrossover;
+use brace_ec::operator::recombinator::Recombinator;
 use brace_ec::operator::selector::best::Best;
 use brace_ec::operator::selector::lexicase::Lexicase;
 use brace_ec::operator::selector::tournament::Tournament;
@@ -43,12 +46,17 @@ fn main() -> Result<(), Error> {
         .generate(&mut rng)?;
 
     let selector = Weighted::selector(Best, 1)
-        .with_selector(Lexicase, 5)
-        .with_selector(Tournament::binary(), args.population as u64 - 1)
-        .twice() // ICE: .repeat(2).take::<2>()
-        .reproduce(TwoPointCrossover)
-        .mutate(Invert.each_reciprocal_rate())
-        .evaluate(Hiff)
+        .with_selector(
+            Adaptive::selector(Lexicase.twice())
+                .with_selector(Tournament::binary().twice())
+                .reproduce(
+                    TwoPointCrossover
+                        .then(Mutate::new(Invert.each_reciprocal_rate()))
+                        .evaluate(Hiff),
+                )
+                .take::<1>(),
+            args.population as u64 - 1,
+        )
         .fill()
         .parallel_if(args.parallel);
 
@@ -0,0 +1,26 @@
+use brace_ec::operator::evolver::cellular::{Cellular, Update};
+use brace_ec::operator::evolver::Evolver;
+use brace_ec::operator::generator::random::Random;
+use brace_ec::operator::generator::Generator;
+use brace_ec::operator::mutator::noise::Noise;
+use brace_ec::operator::mutator::Mutator;
+use brace_ec::operator::selector::tournament::Tournament;
+use brace_ec::operator::selector::Selector;
+use brace_ec::population::grid::{Adjacency, Grid};
+use brace_ec_tui::evolver::Terminal;
+use brace_ec_tui::renderer::grid::GridRenderer;
+use ratatui::style::Color;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cells: Vec<u8> = Random::from(0..32)
+        .populate(60 * 30)
+        .generate(&mut rand::rng())?;
+
+    let selector = Tournament::binary().mutate(Noise(1..5).rate(0.05));
+    let evolver = Cellular::new(selector, Adjacency::von_neumann()).with_update(Update::LineSweep);
+    let renderer = GridRenderer::new(|cell: &u8| Color::Rgb(*cell, *cell, *cell));
+
+    Terminal::new(evolver, renderer).evolve((0, Grid::new(60, cells)?), &mut rand::rng())?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use std::convert::Infallible;
+
+use brace_ec::individual::evaluated::Evaluated;
+use brace_ec::operator::evolver::map_elites::MapElites;
+use brace_ec::operator::evolver::Evolver;
+use brace_ec::operator::mutator::each::Each;
+use brace_ec::operator::mutator::noise::Noise;
+use brace_ec::operator::mutator::Mutator;
+use brace_ec::operator::selector::random::Random;
+use brace_ec::operator::selector::Selector;
+use brace_ec::population::elites::{Dimension, Elites, Tessellation};
+use brace_ec_tui::evolver::Terminal;
+use brace_ec_tui::renderer::elites::ElitesRenderer;
+use ratatui::style::Color;
+
+type Point = Evaluated<[i32; 2], i32>;
+
+fn evaluate(point: &Point) -> Result<i32, Infallible> {
+    let [x, y] = point.individual.map(|value| value as f64 / 25.0);
+
+    Ok(((x.sin() * y.cos() + 1.0) * 127.0) as i32)
+}
+
+fn describe(point: &Point) -> [f64; 2] {
+    point.individual.map(f64::from)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let tessellation = Tessellation::grid([
+        Dimension::new(-300.0..=300.0, 60)?,
+        Dimension::new(-150.0..=150.0, 30)?,
+    ]);
+
+    let selector = Random.mutate(Each::new(Noise::new(-15..=15)).evaluate_with(evaluate));
+    let evolver = MapElites::new(selector, describe).with_batch(20);
+    let renderer = ElitesRenderer::new(|point: &Point| {
+        let value = point.fitness.clamp(0, 255) as u8;
+
+        Color::Rgb(value, value / 2, 255 - value)
+    });
+
+    let mut elites = Elites::new(tessellation);
+
+    evolver.insert(&mut elites, Evaluated::new([0, 0], 127));
+
+    Terminal::new(evolver, renderer).evolve((0, elites), &mut rand::rng())?;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use std::cmp::Reverse;
+use std::convert::Infallible;
+
+use brace_ec::generation::Generation;
+use brace_ec::individual::Individual;
+use brace_ec::operator::evaluator::function::Function;
+use brace_ec::operator::evolver::swarm::ParticleSwarm;
+use brace_ec::operator::evolver::Evolver;
+use brace_ec::operator::generator::random::Random;
+use brace_ec::operator::generator::Generator;
+use brace_ec::swarm::particle::Particle;
+use brace_ec::swarm::topology::Topology;
+use brace_ec::swarm::Swarm;
+use brace_ec_tui::evolver::Terminal;
+use brace_ec_tui::renderer::Renderer;
+use ordered_float::OrderedFloat;
+use ratatui::style::Color;
+use ratatui::text::Text;
+use ratatui::widgets::canvas::{Canvas, Points};
+use ratatui::Frame;
+
+type Fitness = Reverse<OrderedFloat<f64>>;
+
+fn rastrigin(particle: &Particle<Fitness>) -> Result<Fitness, Infallible> {
+    let value = particle
+        .genome()
+        .iter()
+        .map(|x| x * x - 10.0 * (2.0 * std::f64::consts::PI * x).cos() + 10.0)
+        .sum();
+
+    Ok(Reverse(OrderedFloat(value)))
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let swarm = Random::from(-5.0..5.0)
+        .populate(2)
+        .populate(100)
+        .generate(&mut rand::rng())
+        .map(Swarm::new)?;
+
+    let evolver = ParticleSwarm::new(Function::new(rastrigin))
+        .with_topology(Topology::VonNeumann)
+        .with_bounds(vec![-5.0..=5.0; 2]);
+
+    Terminal::new(evolver, SwarmRenderer).evolve(swarm, &mut rand::rng())?;
+
+    Ok(())
+}
+
+pub struct SwarmRenderer;
+
+impl Renderer<Swarm<Fitness>> for SwarmRenderer {
+    fn render(&self, generation: &Swarm<Fitness>, frame: &mut Frame) {
+        let help = Text::from("P = Pause, Esc = Exit").left_aligned();
+        let generation_id = Text::from(generation.id().to_string()).right_aligned();
+
+        let points = generation
+            .particles()
+            .iter()
+            .map(|particle| (particle.position()[0], particle.position()[1]))
+            .collect::<Vec<_>>();
+
+        let best = generation
+            .best()
+            .map(|(position, _)| vec![(position[0], position[1])])
+            .unwrap_or_default();
+
+        let canvas = Canvas::default()
+            .x_bounds([-5.0, 5.0])
+            .y_bounds([-5.0, 5.0])
+            .paint(|context| {
+                context.draw(&Points {
+                    coords: &points,
+                    color: Color::White,
+                });
+                context.draw(&Points {
+                    coords: &best,
+                    color: Color::Red,
+                });
+            });
+
+        frame.render_widget(canvas, frame.area());
+        frame.render_widget(help, frame.area());
+        frame.render_widget(generation_id, frame.area());
+    }
+}
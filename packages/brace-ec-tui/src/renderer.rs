@@ -1,3 +1,6 @@
+pub mod elites;
+pub mod grid;
+
 use ratatui::Frame;
 
 pub trait Renderer<G> {
@@ -0,0 +1,118 @@
+use brace_ec::fitness::numeric::Numeric;
+use brace_ec::generation::Generation;
+use brace_ec::individual::Individual;
+use brace_ec::population::elites::{Elites, Tessellation};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+use ratatui::text::Text;
+use ratatui::widgets::Widget;
+use ratatui::Frame;
+
+use super::Renderer;
+
+pub struct ElitesRenderer<F> {
+    colour: F,
+}
+
+impl<F> ElitesRenderer<F> {
+    pub fn new(colour: F) -> Self {
+        Self { colour }
+    }
+}
+
+impl<G, F, T> Renderer<G> for ElitesRenderer<F>
+where
+    G: Generation<Id: ToString, Population = Elites<T>>,
+    T: Individual<Fitness: Numeric>,
+    F: Fn(&T) -> Color,
+{
+    fn render(&self, generation: &G, frame: &mut Frame) {
+        let elites = generation.population();
+        let help = Text::from("P = Pause, Esc = Exit").left_aligned();
+        let statistics = Text::from(format!(
+            "Coverage: {:.1}%  QD: {:.2}",
+            elites.coverage() * 100.0,
+            elites.qd_score()
+        ))
+        .centered();
+        let generation_id = Text::from(generation.id().to_string()).right_aligned();
+
+        frame.render_widget(help, frame.area());
+        frame.render_widget(statistics, frame.area());
+        frame.render_widget(generation_id, frame.area());
+        frame.render_widget(ElitesWidget::new(elites, &self.colour), frame.area());
+    }
+}
+
+pub struct ElitesWidget<'a, T, F> {
+    elites: &'a Elites<T>,
+    colour: &'a F,
+}
+
+impl<'a, T, F> ElitesWidget<'a, T, F> {
+    pub fn new(elites: &'a Elites<T>, colour: &'a F) -> Self {
+        Self { elites, colour }
+    }
+}
+
+impl<T, F> Widget for ElitesWidget<'_, T, F>
+where
+    F: Fn(&T) -> Color,
+{
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let tessellation = self.elites.tessellation();
+        let bounds = tessellation.bounds();
+
+        if bounds.is_empty() || bounds.len() > 2 {
+            return;
+        }
+
+        let (width, height) = match tessellation {
+            Tessellation::Grid(dimensions) => (
+                dimensions[0].bins(),
+                dimensions.get(1).map_or(1, |dimension| dimension.bins()),
+            ),
+            Tessellation::Centroids(_) => (
+                area.width as usize / 2,
+                area.height.saturating_sub(1) as usize,
+            ),
+        };
+
+        let scale = |index: usize, cells: usize, dimension: usize| {
+            let (min, max) = bounds[dimension].clone().into_inner();
+
+            min + (index as f64 + 0.5) / cells as f64 * (max - min)
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                let mut descriptor = vec![scale(x, width, 0)];
+
+                if bounds.len() == 2 {
+                    descriptor.push(scale(y, height, 1));
+                }
+
+                let Some(elite) = self.elites.locate(&descriptor) else {
+                    continue;
+                };
+
+                let colour = (self.colour)(elite);
+
+                for offset in 0..2 {
+                    let position = Position::new(
+                        area.x.saturating_add((x * 2 + offset) as u16),
+                        area.y.saturating_add(y as u16 + 1),
+                    );
+
+                    if area.contains(position) {
+                        buf[position].set_char('█').set_fg(colour);
+                    }
+                }
+            }
+        }
+    }
+}
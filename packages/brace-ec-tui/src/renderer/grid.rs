@@ -0,0 +1,76 @@
+use brace_ec::generation::Generation;
+use brace_ec::population::grid::Grid;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+use ratatui::text::Text;
+use ratatui::widgets::Widget;
+use ratatui::Frame;
+
+use super::Renderer;
+
+pub struct GridRenderer<F> {
+    colour: F,
+}
+
+impl<F> GridRenderer<F> {
+    pub fn new(colour: F) -> Self {
+        Self { colour }
+    }
+}
+
+impl<G, F, T> Renderer<G> for GridRenderer<F>
+where
+    G: Generation<Id: ToString, Population = Grid<T>>,
+    F: Fn(&T) -> Color,
+{
+    fn render(&self, generation: &G, frame: &mut Frame) {
+        let help = Text::from("P = Pause, Esc = Exit").left_aligned();
+        let generation_id = Text::from(generation.id().to_string()).right_aligned();
+
+        frame.render_widget(help, frame.area());
+        frame.render_widget(generation_id, frame.area());
+        frame.render_widget(
+            GridWidget::new(generation.population(), &self.colour),
+            frame.area(),
+        );
+    }
+}
+
+pub struct GridWidget<'a, T, F> {
+    grid: &'a Grid<T>,
+    colour: &'a F,
+}
+
+impl<'a, T, F> GridWidget<'a, T, F> {
+    pub fn new(grid: &'a Grid<T>, colour: &'a F) -> Self {
+        Self { grid, colour }
+    }
+}
+
+impl<T, F> Widget for GridWidget<'_, T, F>
+where
+    F: Fn(&T) -> Color,
+{
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        for (y, row) in self.grid.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let colour = (self.colour)(cell);
+
+                for offset in 0..2 {
+                    let position = Position::new(
+                        area.x.saturating_add((x * 2 + offset) as u16),
+                        area.y.saturating_add(y as u16 + 1),
+                    );
+
+                    if area.contains(position) {
+                        buf[position].set_char('█').set_fg(colour);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod crossover;
+pub mod size;
 
 pub trait Chromosome {
     type Gene;
@@ -0,0 +1,14 @@
+use super::Chromosome;
+
+pub trait Size {
+    fn size(&self) -> usize;
+}
+
+impl<T> Size for T
+where
+    T: Chromosome + ?Sized,
+{
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
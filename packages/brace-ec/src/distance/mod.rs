@@ -0,0 +1,150 @@
+use crate::individual::Individual;
+
+pub trait Distance {
+    fn distance(&self, other: &Self) -> f64;
+}
+
+macro_rules! impl_hamming {
+    ($($ty:ty),*) => {
+        $(
+            impl Distance for $ty {
+                fn distance(&self, other: &Self) -> f64 {
+                    if self == other { 0.0 } else { 1.0 }
+                }
+            }
+
+            impl Distance for [$ty] {
+                fn distance(&self, other: &Self) -> f64 {
+                    hamming(self, other)
+                }
+            }
+
+            impl Distance for Vec<$ty> {
+                fn distance(&self, other: &Self) -> f64 {
+                    hamming(self, other)
+                }
+            }
+
+            impl<const N: usize> Distance for [$ty; N] {
+                fn distance(&self, other: &Self) -> f64 {
+                    hamming(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_euclidean {
+    ($($ty:ty),*) => {
+        $(
+            impl Distance for $ty {
+                fn distance(&self, other: &Self) -> f64 {
+                    (f64::from(*self) - f64::from(*other)).abs()
+                }
+            }
+
+            impl Distance for [$ty] {
+                fn distance(&self, other: &Self) -> f64 {
+                    euclidean(self, other)
+                }
+            }
+
+            impl Distance for Vec<$ty> {
+                fn distance(&self, other: &Self) -> f64 {
+                    euclidean(self, other)
+                }
+            }
+
+            impl<const N: usize> Distance for [$ty; N] {
+                fn distance(&self, other: &Self) -> f64 {
+                    euclidean(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_hamming!(bool, u8);
+impl_euclidean!(f32, f64);
+
+pub fn hamming<T>(lhs: &[T], rhs: &[T]) -> f64
+where
+    T: PartialEq,
+{
+    let differences = lhs.iter().zip(rhs).filter(|(a, b)| a != b).count();
+
+    (differences + lhs.len().abs_diff(rhs.len())) as f64
+}
+
+pub fn euclidean<T>(lhs: &[T], rhs: &[T]) -> f64
+where
+    T: Copy + Into<f64>,
+{
+    let len = lhs.len().max(rhs.len());
+    let gene = |genes: &[T], index: usize| genes.get(index).map_or(0.0, |gene| (*gene).into());
+
+    (0..len)
+        .map(|index| gene(lhs, index) - gene(rhs, index))
+        .map(|difference| difference * difference)
+        .sum::<f64>()
+        .sqrt()
+}
+
+pub trait Metric<T> {
+    fn measure(&self, lhs: &T, rhs: &T) -> f64;
+}
+
+impl<T, F> Metric<T> for F
+where
+    F: Fn(&T, &T) -> f64,
+{
+    fn measure(&self, lhs: &T, rhs: &T) -> f64 {
+        self(lhs, rhs)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Genotypic;
+
+impl<T> Metric<T> for Genotypic
+where
+    T: Individual<Genome: Distance>,
+{
+    fn measure(&self, lhs: &T, rhs: &T) -> f64 {
+        lhs.genome().distance(rhs.genome())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+
+    use super::{Distance, Genotypic, Metric};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!([true, false, true].distance(&[true, true, false]), 2.0);
+        assert_eq!(vec![1u8, 2, 3].distance(&vec![1, 2, 3]), 0.0);
+        assert_eq!(b"abc"[..].distance(&b"abd"[..]), 1.0);
+        assert_eq!([0.0, 0.0].distance(&[3.0, 4.0]), 5.0);
+        assert_eq!(vec![3.0].distance(&vec![3.0, 4.0]), 4.0);
+        assert_eq!(vec![true].distance(&vec![true, false]), 1.0);
+        assert_eq!(vec![1.0f32].distance(&vec![-1.0]), 2.0);
+        assert_eq!(2.5f64.distance(&1.0), 1.5);
+        assert_eq!(true.distance(&false), 1.0);
+    }
+
+    #[test]
+    fn test_metric() {
+        let a = Evaluated::new([0.0, 0.0], 0);
+        let b = Evaluated::new([0.0, 2.0], 0);
+
+        assert_eq!(Genotypic.measure(&a, &b), 2.0);
+        assert_eq!(
+            (|a: &Evaluated<[f64; 2], i32>, b: &Evaluated<[f64; 2], i32>| a.individual[1]
+                - b.individual[1])
+                .measure(&b, &a),
+            2.0
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::evaluator::Evaluator;
+use crate::operator::generator::Generator;
+use crate::util::iter::TryFromIterator;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Model {
+    probabilities: Vec<f64>,
+}
+
+impl Model {
+    pub fn new(len: usize) -> Self {
+        Self::from_probabilities(vec![0.5; len])
+    }
+
+    pub fn from_probabilities(probabilities: Vec<f64>) -> Self {
+        Self {
+            probabilities: probabilities.into_iter().map(bound).collect(),
+        }
+    }
+
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    pub fn len(&self) -> usize {
+        self.probabilities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.probabilities.is_empty()
+    }
+
+    pub fn mode(&self) -> Vec<bool> {
+        self.probabilities
+            .iter()
+            .map(|probability| *probability >= 0.5)
+            .collect()
+    }
+
+    pub fn is_converged(&self) -> bool {
+        self.probabilities
+            .iter()
+            .all(|probability| *probability <= 0.0 || *probability >= 1.0)
+    }
+
+    pub fn learn(&mut self, target: &[f64], rate: f64) {
+        for (probability, target) in self.probabilities.iter_mut().zip(target) {
+            *probability = bound(*probability + rate * (target - *probability));
+        }
+    }
+
+    pub fn shift(&mut self, index: usize, bit: bool, rate: f64) {
+        if let Some(probability) = self.probabilities.get_mut(index) {
+            let target = if bit { 1.0 } else { 0.0 };
+
+            *probability = bound(*probability + rate * (target - *probability));
+        }
+    }
+
+    pub fn step(&mut self, index: usize, bit: bool, amount: f64) {
+        if let Some(probability) = self.probabilities.get_mut(index) {
+            let amount = if bit { amount } else { -amount };
+
+            *probability = bound(*probability + amount);
+        }
+    }
+
+    pub fn restrict(&mut self, margin: f64) {
+        let margin = match margin.is_nan() {
+            true => 0.0,
+            false => margin.clamp(0.0, 0.5),
+        };
+
+        for probability in &mut self.probabilities {
+            *probability = probability.clamp(margin, 1.0 - margin);
+        }
+    }
+
+    pub fn frequencies<'a, T>(bits: impl IntoIterator<Item = &'a T>, len: usize) -> Vec<f64>
+    where
+        T: AsRef<[bool]> + ?Sized + 'a,
+    {
+        let mut counts = vec![0; len];
+        let mut total = 0;
+
+        for bits in bits {
+            for (count, bit) in counts.iter_mut().zip(bits.as_ref()) {
+                *count += usize::from(*bit);
+            }
+
+            total += 1;
+        }
+
+        counts
+            .into_iter()
+            .map(|count| count as f64 / total.max(1) as f64)
+            .collect()
+    }
+}
+
+fn bound(probability: f64) -> f64 {
+    match probability.is_nan() {
+        true => 0.5,
+        false => probability.clamp(0.0, 1.0),
+    }
+}
+
+impl<T> Generator<T> for Model
+where
+    T: Individual<Genome: AsRef<[bool]>> + TryFromIterator<bool>,
+{
+    type Error = ModelError;
+
+    fn generate<Rng>(&self, rng: &mut Rng) -> Result<T, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let individual = T::try_from_iter(
+            self.probabilities
+                .iter()
+                .map(|probability| rng.random_bool(*probability)),
+        )
+        .map_err(|_| ModelError::Length)?;
+
+        if individual.genome().as_ref().len() != self.probabilities.len() {
+            return Err(ModelError::Length);
+        }
+
+        Ok(individual)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EdaGeneration<I> {
+    id: usize,
+    population: Vec<I>,
+    model: Model,
+    best: Option<I>,
+}
+
+impl<I> EdaGeneration<I> {
+    pub fn new(model: Model) -> Self {
+        Self {
+            id: 0,
+            population: Vec::new(),
+            model,
+            best: None,
+        }
+    }
+
+    pub fn model(&self) -> &Model {
+        &self.model
+    }
+
+    pub fn model_mut(&mut self) -> &mut Model {
+        &mut self.model
+    }
+
+    pub fn best(&self) -> Option<&I> {
+        self.best.as_ref()
+    }
+
+    pub(crate) fn sample<E, Rng>(
+        &self,
+        evaluator: &E,
+        size: usize,
+        rng: &mut Rng,
+    ) -> Result<Vec<I>, EdaError<E::Error>>
+    where
+        I: Individual<Genome: AsRef<[bool]>> + TryFromIterator<bool>,
+        E: Evaluator<I>,
+        Rng: rand::Rng + ?Sized,
+    {
+        (0..size)
+            .map(|_| {
+                let mut individual: I = self.model.generate(rng)?;
+
+                *individual.fitness_mut() = evaluator
+                    .evaluate(&individual, rng)
+                    .map_err(EdaError::Evaluate)?;
+
+                Ok(individual)
+            })
+            .collect()
+    }
+
+    pub(crate) fn record(&mut self, population: Vec<I>)
+    where
+        I: Individual + Clone,
+    {
+        if let Some(best) = population
+            .iter()
+            .max_by_key(|individual| individual.fitness())
+        {
+            if self
+                .best
+                .as_ref()
+                .is_none_or(|current| best.fitness() > current.fitness())
+            {
+                self.best = Some(best.clone());
+            }
+        }
+
+        self.population = population;
+    }
+}
+
+impl<I> Generation for EdaGeneration<I>
+where
+    I: Individual,
+{
+    type Id = usize;
+    type Population = Vec<I>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn population(&self) -> &Self::Population {
+        &self.population
+    }
+
+    fn population_mut(&mut self) -> &mut Self::Population {
+        &mut self.population
+    }
+
+    fn advance(&mut self) {
+        self.id += 1;
+    }
+}
+
+impl<I> From<Model> for EdaGeneration<I> {
+    fn from(model: Model) -> Self {
+        Self::new(model)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ModelError {
+    #[error("model length does not match the genome length")]
+    Length,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EdaError<E> {
+    #[error("model must have at least one probability")]
+    Empty,
+    #[error("population size is too small")]
+    InvalidSize,
+    #[error("rate must be between zero and one")]
+    InvalidRate,
+    #[error(transparent)]
+    Sample(#[from] ModelError),
+    #[error(transparent)]
+    Evaluate(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::generator::Generator;
+
+    use super::{Model, ModelError};
+
+    #[test]
+    fn test_model() {
+        let mut model = Model::from_probabilities(vec![0.0, 1.0, 0.5, 2.0]);
+
+        assert_eq!(model.probabilities(), [0.0, 1.0, 0.5, 1.0]);
+        assert_eq!(model.mode(), [false, true, true, true]);
+        assert!(!model.is_converged());
+
+        model.learn(&[1.0, 0.0, 1.0, 1.0], 0.5);
+
+        assert_eq!(model.probabilities(), [0.5, 0.5, 0.75, 1.0]);
+
+        model.shift(0, false, 0.5);
+        model.shift(9, false, 0.5);
+
+        assert_eq!(model.probabilities(), [0.25, 0.5, 0.75, 1.0]);
+
+        model.step(1, true, 0.25);
+        model.step(2, false, 0.25);
+
+        assert_eq!(model.probabilities(), [0.25, 0.75, 0.5, 1.0]);
+
+        model.restrict(0.1);
+
+        assert_eq!(model.probabilities(), [0.25, 0.75, 0.5, 0.9]);
+        assert!(Model::from_probabilities(vec![0.0, 1.0]).is_converged());
+
+        let mut model = Model::from_probabilities(vec![f64::NAN, f64::INFINITY, 0.5]);
+
+        model.learn(&[1.0, 0.0, f64::NAN], 0.5);
+        model.step(0, true, f64::NAN);
+
+        assert_eq!(model.probabilities(), [0.5, 0.5, 0.5]);
+        assert_eq!(
+            Model::frequencies(&[vec![true, false], vec![true, true]], 2),
+            [1.0, 0.5]
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut rng = rand::rng();
+
+        let model = Model::from_probabilities(vec![1.0, 0.0, 1.0]);
+
+        let a: Vec<bool> = model.generate(&mut rng).unwrap();
+        let b: [bool; 3] = model.generate(&mut rng).unwrap();
+        let c: Result<[bool; 4], _> = model.generate(&mut rng);
+        let d: Result<[bool; 2], _> = model.generate(&mut rng);
+        let e: Vec<Evaluated<Vec<bool>, usize>> =
+            model.clone().populate(5).generate(&mut rng).unwrap();
+
+        assert_eq!(a, [true, false, true]);
+        assert_eq!(b, [true, false, true]);
+        assert_eq!(c, Err(ModelError::Length));
+        assert_eq!(d, Err(ModelError::Length));
+        assert_eq!(e.len(), 5);
+        assert_eq!(e[0].individual, [true, false, true]);
+    }
+}
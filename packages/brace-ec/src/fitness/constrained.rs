@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+
+use ordered_float::OrderedFloat;
+
+use super::Fitness;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Constrained<T> {
+    objective: T,
+    violation: OrderedFloat<f64>,
+}
+
+impl<T> Constrained<T> {
+    pub fn new(objective: T, violation: f64) -> Self {
+        Self {
+            objective,
+            violation: OrderedFloat(violation.max(0.0)),
+        }
+    }
+
+    pub fn feasible(objective: T) -> Self {
+        Self::new(objective, 0.0)
+    }
+
+    pub fn from_violations<I>(objective: T, violations: I) -> Self
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        Self::new(
+            objective,
+            violations
+                .into_iter()
+                .map(|violation| violation.max(0.0))
+                .sum(),
+        )
+    }
+
+    pub fn objective(&self) -> &T {
+        &self.objective
+    }
+
+    pub fn violation(&self) -> f64 {
+        self.violation.0
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.violation.0 == 0.0
+    }
+}
+
+impl<T> Fitness for Constrained<T>
+where
+    T: Fitness,
+{
+    fn nil() -> Self {
+        Self::feasible(T::nil())
+    }
+}
+
+impl<T> PartialOrd for Constrained<T>
+where
+    T: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Constrained<T>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_feasible(), other.is_feasible()) {
+            (true, true) => self.objective.cmp(&other.objective),
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => other
+                .violation
+                .cmp(&self.violation)
+                .then_with(|| self.objective.cmp(&other.objective)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Constrained;
+
+    #[test]
+    fn test_cmp() {
+        let a = Constrained::feasible(5);
+        let b = Constrained::feasible(10);
+        let c = Constrained::new(100, 0.5);
+        let d = Constrained::new(200, 2.0);
+
+        assert!(b > a);
+        assert!(a > c);
+        assert!(c > d);
+        assert_eq!(Constrained::new(0, -1.0), Constrained::feasible(0));
+        assert_eq!(
+            Constrained::from_violations(1, [0.5, -2.0, 1.5]).violation(),
+            2.0
+        );
+        assert!(!d.is_feasible());
+        assert!(Constrained::new(200, 0.5) > Constrained::new(100, 0.5));
+        assert_ne!(Constrained::new(200, 0.5), Constrained::new(100, 0.5));
+        assert_eq!([a, b, c, d].into_iter().max(), Some(b));
+    }
+}
@@ -1,4 +1,6 @@
+pub mod constrained;
 pub mod nil;
+pub mod numeric;
 pub mod summed;
 
 use std::cmp::Reverse;
@@ -19,6 +21,15 @@ where
     }
 }
 
+impl<T> Fitness for Option<T>
+where
+    T: Fitness,
+{
+    fn nil() -> Self {
+        None
+    }
+}
+
 impl<T> Fitness for Reverse<T>
 where
     T: Fitness,
@@ -52,3 +63,55 @@ macro_rules! impl_fitness_default {
 }
 
 impl_fitness_default!(char, bool);
+
+macro_rules! impl_fitness_tuple {
+    ($(($($name:ident),+)),+) => {
+        $(impl<$($name),+> Fitness for ($($name,)+)
+        where
+            $($name: Fitness),+
+        {
+            fn nil() -> Self {
+                ($($name::nil(),)+)
+            }
+        })+
+    };
+}
+
+// Tuples compare their keys lexicographically, so `(accuracy, Reverse(size))`
+// prefers higher accuracy and breaks ties on smaller size.
+impl_fitness_tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F)
+);
+impl_fitness_tuple!((A, B, C, D, E, F, G), (A, B, C, D, E, F, G, H));
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use ordered_float::OrderedFloat;
+
+    use super::Fitness;
+
+    #[test]
+    fn test_tuple() {
+        let fitness = |accuracy: f64, size: usize| (OrderedFloat(accuracy), Reverse(size));
+
+        let a = fitness(0.9, 12);
+        let b = fitness(0.9, 8);
+        let c = fitness(0.8, 1);
+
+        assert!(b > a);
+        assert!(a > c);
+        assert_eq!([a, b, c].into_iter().max(), Some(b));
+        assert_eq!(<(u8, Reverse<i32>)>::nil(), (0, Reverse(0)));
+        assert_eq!(
+            <(OrderedFloat<f64>, bool, u8)>::nil(),
+            (OrderedFloat(0.0), false, 0)
+        );
+    }
+}
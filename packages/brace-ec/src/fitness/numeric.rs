@@ -0,0 +1,87 @@
+use std::cmp::Reverse;
+use std::iter::Sum;
+
+use ordered_float::{FloatCore, OrderedFloat};
+
+use crate::util::iter::Iterable;
+
+use super::nil::Nil;
+use super::summed::Summed;
+use super::Fitness;
+
+pub trait Numeric: Fitness {
+    fn as_f64(&self) -> f64;
+}
+
+impl<T> Numeric for OrderedFloat<T>
+where
+    T: FloatCore,
+{
+    fn as_f64(&self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+impl<T> Numeric for Reverse<T>
+where
+    T: Numeric,
+{
+    fn as_f64(&self) -> f64 {
+        -self.0.as_f64()
+    }
+}
+
+impl<T> Numeric for Summed<T>
+where
+    T: Iterable<Item: Numeric + for<'a> Sum<&'a T::Item>> + Default,
+{
+    fn as_f64(&self) -> f64 {
+        self.total().as_f64()
+    }
+}
+
+impl Numeric for Nil {
+    fn as_f64(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Numeric for bool {
+    fn as_f64(&self) -> f64 {
+        *self as u8 as f64
+    }
+}
+
+macro_rules! impl_numeric {
+    ($($type:path),+) => {
+        $(impl Numeric for $type {
+            fn as_f64(&self) -> f64 {
+                *self as f64
+            }
+        })+
+    };
+}
+
+impl_numeric!(u8, u16, u32, u64, u128, usize);
+impl_numeric!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use ordered_float::OrderedFloat;
+
+    use crate::fitness::summed::Summed;
+
+    use super::Numeric;
+
+    #[test]
+    fn test_as_f64() {
+        assert_eq!(3u8.as_f64(), 3.0);
+        assert_eq!((-2i64).as_f64(), -2.0);
+        assert_eq!(OrderedFloat(1.5).as_f64(), 1.5);
+        assert_eq!(Reverse(OrderedFloat(1.5f32)).as_f64(), -1.5);
+        assert_eq!(Summed::new(vec![1, 2, 3]).as_f64(), 6.0);
+        assert!(Reverse(4) < Reverse(2) && Reverse(4).as_f64() < Reverse(2).as_f64());
+    }
+}
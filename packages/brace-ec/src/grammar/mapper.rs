@@ -0,0 +1,171 @@
+use num_traits::ToPrimitive;
+use thiserror::Error;
+
+use crate::chromosome::Chromosome;
+
+use super::{Grammar, Symbol};
+
+#[derive(Clone, Debug)]
+pub struct Mapper {
+    grammar: Grammar,
+    wraps: usize,
+    expansions: usize,
+}
+
+impl Mapper {
+    pub fn new(grammar: Grammar) -> Self {
+        Self {
+            grammar,
+            wraps: 0,
+            expansions: 10_000,
+        }
+    }
+
+    pub fn with_wraps(mut self, wraps: usize) -> Self {
+        self.wraps = wraps;
+        self
+    }
+
+    pub fn with_expansion_limit(mut self, expansions: usize) -> Self {
+        self.expansions = expansions;
+        self
+    }
+
+    pub fn grammar(&self) -> &Grammar {
+        &self.grammar
+    }
+
+    pub fn map<C>(&self, codons: &C) -> Result<String, MappingError>
+    where
+        C: Chromosome<Gene: ToPrimitive> + ?Sized,
+    {
+        let mut output = String::new();
+        let mut stack = vec![Symbol::NonTerminal(0)];
+        let mut position = 0;
+        let mut wraps = 0;
+        let mut expansions = 0;
+
+        while let Some(symbol) = stack.pop() {
+            let index = match symbol {
+                Symbol::Terminal(text) => {
+                    output.push_str(&text);
+
+                    continue;
+                }
+                Symbol::NonTerminal(index) => index,
+            };
+
+            expansions += 1;
+
+            if expansions > self.expansions {
+                return Err(MappingError::ExpansionLimit);
+            }
+
+            let productions = self
+                .grammar
+                .rule(index)
+                .expect("grammar rules are resolved on parse")
+                .productions();
+
+            let choice = match productions.len() {
+                1 => 0,
+                len => {
+                    if codons.is_empty() {
+                        return Err(MappingError::Empty);
+                    }
+
+                    if position == codons.len() {
+                        position = 0;
+                        wraps += 1;
+
+                        if wraps > self.wraps {
+                            return Err(MappingError::WrapLimit);
+                        }
+                    }
+
+                    let codon = codons
+                        .gene(position)
+                        .and_then(ToPrimitive::to_u64)
+                        .ok_or(MappingError::InvalidCodon(position))?;
+
+                    position += 1;
+
+                    (codon % len as u64) as usize
+                }
+            };
+
+            stack.extend(productions[choice].iter().rev().cloned());
+        }
+
+        Ok(output)
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum MappingError {
+    #[error("empty genome")]
+    Empty,
+    #[error("wrapping limit exceeded")]
+    WrapLimit,
+    #[error("expansion limit exceeded")]
+    ExpansionLimit,
+    #[error("invalid codon at position {0}")]
+    InvalidCodon(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grammar::Grammar;
+
+    use super::{Mapper, MappingError};
+
+    fn grammar() -> Grammar {
+        Grammar::parse(
+            "
+            <expr> ::= <expr><op><expr> | <var>
+            <op>   ::= + | *
+            <var>  ::= x | y
+            ",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_map() {
+        let mapper = Mapper::new(grammar());
+
+        let a = mapper.map(&[1u8, 0]);
+        let b = mapper.map(&[0u8, 1, 1, 1, 1, 0]);
+        let c = mapper.map(&vec![2u32, 3, 4, 5, 1, 1]);
+        let d = mapper.map(&[3i64, 0]);
+
+        assert_eq!(a.as_deref(), Ok("x"));
+        assert_eq!(b.as_deref(), Ok("y*x"));
+        assert_eq!(c.as_deref(), Ok("x*y"));
+        assert_eq!(d.as_deref(), Ok("x"));
+    }
+
+    #[test]
+    fn test_map_wrap() {
+        let a = Mapper::new(grammar()).map(&[0u8, 1, 1]);
+        let b = Mapper::new(grammar()).with_wraps(1).map(&[0u8, 1, 1]);
+        let c = Mapper::new(grammar()).with_wraps(3).map(&[0u8]);
+
+        assert_eq!(a, Err(MappingError::WrapLimit));
+        assert_eq!(b.as_deref(), Ok("y+y"));
+        assert_eq!(c, Err(MappingError::WrapLimit));
+    }
+
+    #[test]
+    fn test_map_errors() {
+        let a = Mapper::new(grammar()).map(&Vec::<u8>::new());
+        let b = Mapper::new(grammar()).map(&[-1i32]);
+        let c = Mapper::new(Grammar::parse("<a> ::= <a>").unwrap()).map(&[0u8]);
+        let d = Mapper::new(Grammar::parse("<a> ::= x").unwrap()).map(&Vec::<u8>::new());
+
+        assert_eq!(a, Err(MappingError::Empty));
+        assert_eq!(b, Err(MappingError::InvalidCodon(0)));
+        assert_eq!(c, Err(MappingError::ExpansionLimit));
+        assert_eq!(d.as_deref(), Ok("x"));
+    }
+}
@@ -0,0 +1,299 @@
+pub mod mapper;
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    Terminal(String),
+    NonTerminal(usize),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    name: String,
+    productions: Vec<Vec<Symbol>>,
+}
+
+impl Rule {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn productions(&self) -> &[Vec<Symbol>] {
+        &self.productions
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grammar {
+    rules: Vec<Rule>,
+}
+
+impl Grammar {
+    pub fn parse(input: &str) -> Result<Self, GrammarError> {
+        let mut definitions = Vec::<(String, Vec<Token>)>::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(rest) = line.strip_prefix('|') {
+                let (_, tokens) = definitions
+                    .last_mut()
+                    .ok_or(GrammarError::Syntax(index + 1))?;
+
+                tokens.push(Token::Bar);
+                tokens.extend(tokenize(rest).ok_or(GrammarError::Syntax(index + 1))?);
+
+                continue;
+            }
+
+            let (lhs, rhs) = line
+                .split_once("::=")
+                .ok_or(GrammarError::Syntax(index + 1))?;
+
+            let name = match tokenize(lhs).as_deref() {
+                Some([Token::NonTerminal(name)]) => name.clone(),
+                _ => return Err(GrammarError::Syntax(index + 1)),
+            };
+
+            if definitions.iter().any(|(other, _)| other == &name) {
+                return Err(GrammarError::Duplicate(name));
+            }
+
+            definitions.push((name, tokenize(rhs).ok_or(GrammarError::Syntax(index + 1))?));
+        }
+
+        if definitions.is_empty() {
+            return Err(GrammarError::Empty);
+        }
+
+        let indices = definitions
+            .iter()
+            .enumerate()
+            .map(|(index, (name, _))| (name.clone(), index))
+            .collect::<HashMap<_, _>>();
+
+        let rules = definitions
+            .into_iter()
+            .map(|(name, tokens)| {
+                let productions = tokens
+                    .split(|token| token == &Token::Bar)
+                    .map(|production| {
+                        production
+                            .iter()
+                            .map(|token| match token {
+                                Token::Terminal(text) => Ok(Symbol::Terminal(text.clone())),
+                                Token::NonTerminal(name) => indices
+                                    .get(name)
+                                    .map(|index| Symbol::NonTerminal(*index))
+                                    .ok_or_else(|| GrammarError::Undefined(name.clone())),
+                                Token::Bar => unreachable!("split on bar"),
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Rule { name, productions })
+            })
+            .collect::<Result<Vec<_>, GrammarError>>()?;
+
+        Ok(Self { rules })
+    }
+
+    pub fn read<P>(path: P) -> Result<Self, GrammarError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::parse(&std::fs::read_to_string(path).map_err(GrammarError::Io)?)
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    pub fn rule(&self, index: usize) -> Option<&Rule> {
+        self.rules.get(index)
+    }
+
+    pub fn start(&self) -> &Rule {
+        &self.rules[0]
+    }
+}
+
+impl FromStr for Grammar {
+    type Err = GrammarError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Terminal(String),
+    NonTerminal(String),
+    Bar,
+}
+
+fn tokenize(input: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            char if char.is_whitespace() => {}
+            '|' => tokens.push(Token::Bar),
+            '<' => {
+                let mut name = String::new();
+                let mut closed = false;
+
+                for next in chars.by_ref() {
+                    if next == '>' {
+                        closed = true;
+                        break;
+                    }
+
+                    name.push(next);
+                }
+
+                if !closed {
+                    return None;
+                }
+
+                tokens.push(Token::NonTerminal(name));
+            }
+            '"' | '\'' => {
+                let mut text = String::new();
+                let mut closed = false;
+
+                for next in chars.by_ref() {
+                    if next == char {
+                        closed = true;
+                        break;
+                    }
+
+                    text.push(next);
+                }
+
+                if !closed {
+                    return None;
+                }
+
+                tokens.push(Token::Terminal(text));
+            }
+            char => {
+                let mut text = String::from(char);
+
+                while let Some(next) = chars.next_if(|next| !matches!(next, '|' | '<' | '"' | '\''))
+                {
+                    text.push(next);
+                }
+
+                tokens.push(Token::Terminal(text.trim_end().to_owned()));
+            }
+        }
+    }
+
+    Some(tokens)
+}
+
+#[derive(Debug, Error)]
+pub enum GrammarError {
+    #[error("empty grammar")]
+    Empty,
+    #[error("syntax error on line {0}")]
+    Syntax(usize),
+    #[error("duplicate rule <{0}>")]
+    Duplicate(String),
+    #[error("undefined rule <{0}>")]
+    Undefined(String),
+    #[error(transparent)]
+    Io(std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grammar, GrammarError, Symbol};
+
+    #[test]
+    fn test_parse() {
+        let grammar = Grammar::parse(
+            r#"
+            # Arithmetic expressions.
+            <expr> ::= <expr> <op> <expr> | (<expr>) | <var>
+            <op>   ::= + | - | "*"
+            <var>  ::= x
+                     | y
+                     | ' 1.0 '
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(grammar.rules().len(), 3);
+        assert_eq!(grammar.start().name(), "expr");
+        assert_eq!(
+            grammar.start().productions(),
+            [
+                vec![
+                    Symbol::NonTerminal(0),
+                    Symbol::NonTerminal(1),
+                    Symbol::NonTerminal(0)
+                ],
+                vec![
+                    Symbol::Terminal("(".into()),
+                    Symbol::NonTerminal(0),
+                    Symbol::Terminal(")".into())
+                ],
+                vec![Symbol::NonTerminal(2)],
+            ]
+        );
+        assert_eq!(grammar.rule(1).unwrap().productions().len(), 3);
+        assert_eq!(
+            grammar.rule(2).unwrap().productions()[2],
+            [Symbol::Terminal(" 1.0 ".into())]
+        );
+    }
+
+    #[test]
+    fn test_parse_terminals() {
+        let grammar = Grammar::parse("<a> ::= if  x then <a> | else y").unwrap();
+
+        assert_eq!(
+            grammar.start().productions(),
+            [
+                vec![
+                    Symbol::Terminal("if  x then".into()),
+                    Symbol::NonTerminal(0)
+                ],
+                vec![Symbol::Terminal("else y".into())],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let a = "".parse::<Grammar>();
+        let b = "<a> ::= <b>".parse::<Grammar>();
+        let c = "<a> ::= x\n<a> ::= y".parse::<Grammar>();
+        let d = "<a> x".parse::<Grammar>();
+        let e = "| x".parse::<Grammar>();
+        let f = "<a> ::= \"x".parse::<Grammar>();
+        let g = "<a> ::= <b".parse::<Grammar>();
+
+        assert!(matches!(a, Err(GrammarError::Empty)));
+        assert!(matches!(b, Err(GrammarError::Undefined(name)) if name == "b"));
+        assert!(matches!(c, Err(GrammarError::Duplicate(name)) if name == "a"));
+        assert!(matches!(d, Err(GrammarError::Syntax(1))));
+        assert!(matches!(e, Err(GrammarError::Syntax(1))));
+        assert!(matches!(f, Err(GrammarError::Syntax(1))));
+        assert!(matches!(g, Err(GrammarError::Syntax(1))));
+    }
+}
@@ -0,0 +1,69 @@
+use super::Individual;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Aged<T> {
+    pub individual: T,
+    pub birth: usize,
+}
+
+impl<T> Aged<T> {
+    pub fn new(individual: T, birth: usize) -> Self {
+        Self { individual, birth }
+    }
+
+    pub fn age(&self, generation: usize) -> usize {
+        generation.saturating_sub(self.birth)
+    }
+}
+
+impl<T> Individual for Aged<T>
+where
+    T: Individual,
+{
+    type Genome = T::Genome;
+    type Fitness = T::Fitness;
+
+    fn genome(&self) -> &Self::Genome {
+        self.individual.genome()
+    }
+
+    fn genome_mut(&mut self) -> &mut Self::Genome {
+        self.individual.genome_mut()
+    }
+
+    fn fitness(&self) -> &Self::Fitness {
+        self.individual.fitness()
+    }
+
+    fn fitness_mut(&mut self) -> &mut Self::Fitness {
+        self.individual.fitness_mut()
+    }
+}
+
+impl<T> From<T> for Aged<T> {
+    fn from(individual: T) -> Self {
+        Self::new(individual, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+
+    use super::Aged;
+
+    #[test]
+    fn test_individual() {
+        let mut a = Aged::new(Evaluated::new([1, 2, 3], 6), 4);
+
+        a.genome_mut()[0] = 0;
+        a.set_fitness(5);
+
+        assert_eq!(a.genome(), &[0, 2, 3]);
+        assert_eq!(a.fitness(), &5);
+        assert_eq!(a.age(10), 6);
+        assert_eq!(a.age(2), 0);
+        assert_eq!(Aged::from(1).birth, 0);
+    }
+}
@@ -1,5 +1,7 @@
+pub mod aged;
 pub mod evaluated;
 pub mod reversed;
+pub mod self_adaptive;
 
 use ordered_float::OrderedFloat;
 
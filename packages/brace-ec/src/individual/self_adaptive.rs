@@ -0,0 +1,133 @@
+use super::Individual;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Parameters {
+    Single(f64),
+    Genewise(Vec<f64>),
+    Correlated { sigmas: Vec<f64>, angles: Vec<f64> },
+}
+
+impl Parameters {
+    pub fn single(sigma: f64) -> Self {
+        Self::Single(sigma)
+    }
+
+    pub fn genewise(dimension: usize, sigma: f64) -> Self {
+        Self::Genewise(vec![sigma; dimension])
+    }
+
+    pub fn correlated(dimension: usize, sigma: f64) -> Self {
+        Self::Correlated {
+            sigmas: vec![sigma; dimension],
+            angles: vec![0.0; dimension * dimension.saturating_sub(1) / 2],
+        }
+    }
+
+    pub fn sigmas(&self) -> &[f64] {
+        match self {
+            Self::Single(sigma) => std::slice::from_ref(sigma),
+            Self::Genewise(sigmas) => sigmas,
+            Self::Correlated { sigmas, .. } => sigmas,
+        }
+    }
+
+    pub fn angles(&self) -> &[f64] {
+        match self {
+            Self::Correlated { angles, .. } => angles,
+            _ => &[],
+        }
+    }
+
+    pub fn sigma(&self, index: usize) -> f64 {
+        match self {
+            Self::Single(sigma) => *sigma,
+            Self::Genewise(sigmas) | Self::Correlated { sigmas, .. } => sigmas[index],
+        }
+    }
+
+    pub fn is_valid(&self, dimension: usize) -> bool {
+        match self {
+            Self::Single(_) => true,
+            Self::Genewise(sigmas) => sigmas.len() == dimension,
+            Self::Correlated { sigmas, angles } => {
+                sigmas.len() == dimension
+                    && angles.len() == dimension * dimension.saturating_sub(1) / 2
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelfAdaptive<T> {
+    pub individual: T,
+    pub parameters: Parameters,
+}
+
+impl<T> SelfAdaptive<T> {
+    pub fn new(individual: T, parameters: Parameters) -> Self {
+        Self {
+            individual,
+            parameters,
+        }
+    }
+}
+
+impl<T> Individual for SelfAdaptive<T>
+where
+    T: Individual,
+{
+    type Genome = T::Genome;
+    type Fitness = T::Fitness;
+
+    fn genome(&self) -> &Self::Genome {
+        self.individual.genome()
+    }
+
+    fn genome_mut(&mut self) -> &mut Self::Genome {
+        self.individual.genome_mut()
+    }
+
+    fn fitness(&self) -> &Self::Fitness {
+        self.individual.fitness()
+    }
+
+    fn fitness_mut(&mut self) -> &mut Self::Fitness {
+        self.individual.fitness_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+
+    use super::{Parameters, SelfAdaptive};
+
+    #[test]
+    fn test_individual() {
+        let mut a = SelfAdaptive::new(Evaluated::new(vec![1.0, 2.0], 3), Parameters::single(0.5));
+
+        a.genome_mut()[0] = 0.0;
+        a.set_fitness(2);
+
+        assert_eq!(a.genome(), &[0.0, 2.0]);
+        assert_eq!(a.fitness(), &2);
+        assert_eq!(a.parameters.sigma(1), 0.5);
+    }
+
+    #[test]
+    fn test_parameters() {
+        let a = Parameters::genewise(3, 1.0);
+        let b = Parameters::correlated(3, 2.0);
+
+        assert_eq!(a.sigmas(), &[1.0, 1.0, 1.0]);
+        assert!(a.angles().is_empty());
+        assert_eq!(b.sigmas(), &[2.0, 2.0, 2.0]);
+        assert_eq!(b.angles(), &[0.0, 0.0, 0.0]);
+        assert!(Parameters::single(1.0).is_valid(5));
+        assert!(a.is_valid(3));
+        assert!(!a.is_valid(2));
+        assert!(b.is_valid(3));
+        assert!(!b.is_valid(4));
+    }
+}
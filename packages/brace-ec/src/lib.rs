@@ -1,7 +1,14 @@
 pub mod chromosome;
+pub mod distance;
+pub mod eda;
 pub mod fitness;
 pub mod generation;
+pub mod grammar;
 pub mod individual;
+pub mod linear;
+pub mod novelty;
 pub mod operator;
 pub mod population;
+pub mod schedule;
+pub mod swarm;
 pub mod util;
@@ -0,0 +1,336 @@
+use std::ops::Range;
+
+use rand::seq::IndexedRandom;
+use thiserror::Error;
+
+use crate::fitness::nil::Nil;
+use crate::individual::Individual;
+use crate::operator::generator::Generator;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Min,
+    Max,
+    Sin,
+    Cos,
+    IfLess,
+    IfGreater,
+}
+
+impl Operation {
+    pub fn arithmetic() -> Vec<Self> {
+        vec![Self::Add, Self::Sub, Self::Mul, Self::Div]
+    }
+
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Add,
+            Self::Sub,
+            Self::Mul,
+            Self::Div,
+            Self::Min,
+            Self::Max,
+            Self::Sin,
+            Self::Cos,
+            Self::IfLess,
+            Self::IfGreater,
+        ]
+    }
+
+    pub fn arity(&self) -> usize {
+        match self {
+            Self::Sin | Self::Cos => 1,
+            _ => 2,
+        }
+    }
+
+    pub fn is_conditional(&self) -> bool {
+        matches!(self, Self::IfLess | Self::IfGreater)
+    }
+
+    pub fn apply(&self, lhs: f64, rhs: f64) -> f64 {
+        match self {
+            Self::Add => lhs + rhs,
+            Self::Sub => lhs - rhs,
+            Self::Mul => lhs * rhs,
+            Self::Div => match rhs.abs() < f64::EPSILON {
+                true => lhs,
+                false => lhs / rhs,
+            },
+            Self::Min => lhs.min(rhs),
+            Self::Max => lhs.max(rhs),
+            Self::Sin => lhs.sin(),
+            Self::Cos => lhs.cos(),
+            Self::IfLess => f64::from(u8::from(lhs < rhs)),
+            Self::IfGreater => f64::from(u8::from(lhs > rhs)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operand {
+    Register(usize),
+    Input(usize),
+    Constant(f64),
+}
+
+impl Operand {
+    pub fn register(&self) -> Option<usize> {
+        match self {
+            Self::Register(index) => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Instruction {
+    pub operation: Operation,
+    pub target: usize,
+    pub lhs: Operand,
+    pub rhs: Operand,
+}
+
+impl Instruction {
+    pub fn new(operation: Operation, target: usize, lhs: Operand, rhs: Operand) -> Self {
+        Self {
+            operation,
+            target,
+            lhs,
+            rhs,
+        }
+    }
+
+    pub fn operands(&self) -> impl Iterator<Item = &Operand> {
+        [&self.lhs, &self.rhs]
+            .into_iter()
+            .take(self.operation.arity())
+    }
+
+    pub fn registers(&self) -> impl Iterator<Item = usize> + '_ {
+        self.operands().filter_map(Operand::register)
+    }
+}
+
+impl Individual for Instruction {
+    type Genome = Self;
+    type Fitness = Nil;
+
+    fn genome(&self) -> &Self::Genome {
+        self
+    }
+
+    fn genome_mut(&mut self) -> &mut Self::Genome {
+        self
+    }
+
+    fn fitness(&self) -> &Self::Fitness {
+        Nil::r#ref()
+    }
+
+    fn fitness_mut(&mut self) -> &mut Self::Fitness {
+        Nil::r#mut()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InstructionSet {
+    operations: Vec<Operation>,
+    registers: usize,
+    inputs: usize,
+    constants: Range<f64>,
+    constant_rate: f64,
+}
+
+impl InstructionSet {
+    pub fn new(registers: usize, inputs: usize) -> Self {
+        Self {
+            operations: Operation::arithmetic(),
+            registers,
+            inputs,
+            constants: 0.0..1.0,
+            constant_rate: 0.0,
+        }
+    }
+
+    pub fn with_operations(mut self, operations: impl IntoIterator<Item = Operation>) -> Self {
+        self.operations = operations.into_iter().collect();
+        self
+    }
+
+    pub fn with_constants(mut self, constants: Range<f64>, rate: f64) -> Self {
+        self.constants = constants;
+        self.constant_rate = rate;
+        self
+    }
+
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    pub fn registers(&self) -> usize {
+        self.registers
+    }
+
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    pub fn validate(&self) -> Result<(), InstructionSetError> {
+        if self.operations.is_empty() {
+            return Err(InstructionSetError::NoOperations);
+        }
+
+        if self.registers == 0 {
+            return Err(InstructionSetError::NoRegisters);
+        }
+
+        if !(0.0..=1.0).contains(&self.constant_rate) {
+            return Err(InstructionSetError::InvalidRate);
+        }
+
+        if self.constant_rate > 0.0 && self.constants.is_empty() {
+            return Err(InstructionSetError::InvalidConstants);
+        }
+
+        Ok(())
+    }
+
+    pub fn random_operation<Rng>(&self, rng: &mut Rng) -> Result<Operation, InstructionSetError>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.operations
+            .choose(rng)
+            .copied()
+            .ok_or(InstructionSetError::NoOperations)
+    }
+
+    pub fn random_target<Rng>(&self, rng: &mut Rng) -> Result<usize, InstructionSetError>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.registers == 0 {
+            return Err(InstructionSetError::NoRegisters);
+        }
+
+        Ok(rng.random_range(0..self.registers))
+    }
+
+    pub fn random_operand<Rng>(&self, rng: &mut Rng) -> Result<Operand, InstructionSetError>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.validate()?;
+
+        if self.constant_rate > 0.0 && rng.random_bool(self.constant_rate) {
+            return Ok(Operand::Constant(rng.random_range(self.constants.clone())));
+        }
+
+        let index = rng.random_range(0..self.registers + self.inputs);
+
+        match index < self.registers {
+            true => Ok(Operand::Register(index)),
+            false => Ok(Operand::Input(index - self.registers)),
+        }
+    }
+}
+
+impl Generator<Instruction> for InstructionSet {
+    type Error = InstructionSetError;
+
+    fn generate<Rng>(&self, rng: &mut Rng) -> Result<Instruction, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.validate()?;
+
+        Ok(Instruction::new(
+            self.random_operation(rng)?,
+            self.random_target(rng)?,
+            self.random_operand(rng)?,
+            self.random_operand(rng)?,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum InstructionSetError {
+    #[error("instruction set has no operations")]
+    NoOperations,
+    #[error("instruction set has no registers")]
+    NoRegisters,
+    #[error("constant rate must be between 0 and 1")]
+    InvalidRate,
+    #[error("constant range is empty")]
+    InvalidConstants,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::generator::Generator;
+
+    use super::{Instruction, InstructionSet, InstructionSetError, Operand, Operation};
+
+    #[test]
+    fn test_operation() {
+        assert_eq!(Operation::Add.apply(2.0, 3.0), 5.0);
+        assert_eq!(Operation::Sub.apply(2.0, 3.0), -1.0);
+        assert_eq!(Operation::Mul.apply(2.0, 3.0), 6.0);
+        assert_eq!(Operation::Div.apply(3.0, 2.0), 1.5);
+        assert_eq!(Operation::Div.apply(3.0, 0.0), 3.0);
+        assert_eq!(Operation::Min.apply(2.0, 3.0), 2.0);
+        assert_eq!(Operation::Max.apply(2.0, 3.0), 3.0);
+        assert_eq!(Operation::IfLess.apply(2.0, 3.0), 1.0);
+        assert_eq!(Operation::IfGreater.apply(2.0, 3.0), 0.0);
+    }
+
+    #[test]
+    fn test_instruction() {
+        let a = Instruction::new(Operation::Add, 0, Operand::Register(1), Operand::Input(0));
+        let b = Instruction::new(
+            Operation::Sin,
+            0,
+            Operand::Register(1),
+            Operand::Register(2),
+        );
+
+        assert_eq!(a.registers().collect::<Vec<_>>(), [1]);
+        assert_eq!(b.registers().collect::<Vec<_>>(), [1]);
+        assert_eq!(b.operands().count(), 1);
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut rng = rand::rng();
+
+        let set = InstructionSet::new(4, 2).with_constants(-1.0..1.0, 0.5);
+
+        for _ in 0..100 {
+            let instruction = set.generate(&mut rng).unwrap();
+
+            assert!(set.operations().contains(&instruction.operation));
+            assert!(instruction.target < 4);
+
+            for operand in instruction.operands() {
+                match operand {
+                    Operand::Register(index) => assert!(*index < 4),
+                    Operand::Input(index) => assert!(*index < 2),
+                    Operand::Constant(value) => assert!((-1.0..1.0).contains(value)),
+                }
+            }
+        }
+
+        let a = InstructionSet::new(0, 2).generate(&mut rng);
+        let b = InstructionSet::new(2, 2)
+            .with_operations([])
+            .generate(&mut rng);
+
+        assert_eq!(a, Err(InstructionSetError::NoRegisters));
+        assert_eq!(b, Err(InstructionSetError::NoOperations));
+    }
+}
@@ -0,0 +1,215 @@
+use thiserror::Error;
+
+use super::instruction::{Instruction, Operand};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Interpreter {
+    registers: usize,
+    steps: usize,
+}
+
+impl Interpreter {
+    pub fn new(registers: usize) -> Self {
+        Self {
+            registers,
+            steps: usize::MAX,
+        }
+    }
+
+    pub fn with_step_limit(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    pub fn registers(&self) -> usize {
+        self.registers
+    }
+
+    pub fn run(
+        &self,
+        program: &[Instruction],
+        inputs: &[f64],
+    ) -> Result<Vec<f64>, InterpreterError> {
+        let mut registers = vec![0.0; self.registers];
+
+        self.run_with(program, inputs, &mut registers)?;
+
+        Ok(registers)
+    }
+
+    pub fn run_with(
+        &self,
+        program: &[Instruction],
+        inputs: &[f64],
+        registers: &mut [f64],
+    ) -> Result<usize, InterpreterError> {
+        let mut steps = 0;
+        let mut index = 0;
+
+        while let Some(instruction) = program.get(index) {
+            if steps >= self.steps {
+                return Err(InterpreterError::StepLimit);
+            }
+
+            steps += 1;
+            index += 1;
+
+            let lhs = Self::read(&instruction.lhs, inputs, registers)?;
+            let rhs = match instruction.operation.arity() {
+                1 => 0.0,
+                _ => Self::read(&instruction.rhs, inputs, registers)?,
+            };
+
+            let value = instruction.operation.apply(lhs, rhs);
+
+            if instruction.operation.is_conditional() {
+                if value == 0.0 {
+                    index += Self::skip(&program[index..]);
+                }
+
+                continue;
+            }
+
+            if !value.is_finite() {
+                return Err(InterpreterError::NonFinite);
+            }
+
+            *registers
+                .get_mut(instruction.target)
+                .ok_or(InterpreterError::InvalidRegister(instruction.target))? = value;
+        }
+
+        Ok(steps)
+    }
+
+    fn read(operand: &Operand, inputs: &[f64], registers: &[f64]) -> Result<f64, InterpreterError> {
+        match operand {
+            Operand::Register(index) => registers
+                .get(*index)
+                .copied()
+                .ok_or(InterpreterError::InvalidRegister(*index)),
+            Operand::Input(index) => inputs
+                .get(*index)
+                .copied()
+                .ok_or(InterpreterError::InvalidInput(*index)),
+            Operand::Constant(value) => Ok(*value),
+        }
+    }
+
+    fn skip(program: &[Instruction]) -> usize {
+        program
+            .iter()
+            .position(|instruction| !instruction.operation.is_conditional())
+            .map(|position| position + 1)
+            .unwrap_or(program.len())
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum InterpreterError {
+    #[error("step limit exceeded")]
+    StepLimit,
+    #[error("non-finite register value")]
+    NonFinite,
+    #[error("invalid register {0}")]
+    InvalidRegister(usize),
+    #[error("invalid input {0}")]
+    InvalidInput(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linear::instruction::{Instruction, Operand, Operation};
+
+    use super::{Interpreter, InterpreterError};
+
+    #[test]
+    fn test_run() {
+        let program = [
+            Instruction::new(Operation::Mul, 0, Operand::Input(0), Operand::Input(0)),
+            Instruction::new(
+                Operation::Add,
+                0,
+                Operand::Register(0),
+                Operand::Constant(1.0),
+            ),
+        ];
+
+        let a = Interpreter::new(2).run(&program, &[3.0]).unwrap();
+        let b = Interpreter::new(2).run(&program, &[-2.0]).unwrap();
+
+        assert_eq!(a, [10.0, 0.0]);
+        assert_eq!(b, [5.0, 0.0]);
+    }
+
+    #[test]
+    fn test_run_conditional() {
+        let program = [
+            Instruction::new(
+                Operation::IfLess,
+                0,
+                Operand::Input(0),
+                Operand::Constant(0.0),
+            ),
+            Instruction::new(Operation::Sub, 0, Operand::Constant(0.0), Operand::Input(0)),
+            Instruction::new(
+                Operation::IfGreater,
+                0,
+                Operand::Input(0),
+                Operand::Constant(0.0),
+            ),
+            Instruction::new(Operation::Add, 0, Operand::Input(0), Operand::Constant(0.0)),
+        ];
+
+        let a = Interpreter::new(1).run(&program, &[-4.0]).unwrap();
+        let b = Interpreter::new(1).run(&program, &[4.0]).unwrap();
+        let c = Interpreter::new(1).run(&program, &[0.0]).unwrap();
+
+        assert_eq!(a, [4.0]);
+        assert_eq!(b, [4.0]);
+        assert_eq!(c, [0.0]);
+    }
+
+    #[test]
+    fn test_run_errors() {
+        let a = Interpreter::new(1).with_step_limit(1).run(
+            &[
+                Instruction::new(Operation::Add, 0, Operand::Input(0), Operand::Input(0)),
+                Instruction::new(Operation::Add, 0, Operand::Input(0), Operand::Input(0)),
+            ],
+            &[1.0],
+        );
+        let b = Interpreter::new(1).run(
+            &[Instruction::new(
+                Operation::Mul,
+                0,
+                Operand::Input(0),
+                Operand::Input(0),
+            )],
+            &[f64::MAX],
+        );
+        let c = Interpreter::new(1).run(
+            &[Instruction::new(
+                Operation::Add,
+                3,
+                Operand::Input(0),
+                Operand::Input(0),
+            )],
+            &[1.0],
+        );
+        let d = Interpreter::new(1).run(
+            &[Instruction::new(
+                Operation::Add,
+                0,
+                Operand::Input(1),
+                Operand::Input(0),
+            )],
+            &[1.0],
+        );
+
+        assert_eq!(a, Err(InterpreterError::StepLimit));
+        assert_eq!(b, Err(InterpreterError::NonFinite));
+        assert_eq!(c, Err(InterpreterError::InvalidRegister(3)));
+        assert_eq!(d, Err(InterpreterError::InvalidInput(1)));
+    }
+}
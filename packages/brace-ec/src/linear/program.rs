@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
+
+use crate::chromosome::size::Size;
+use crate::fitness::nil::Nil;
+use crate::individual::Individual;
+use crate::population::Population;
+use crate::util::iter::TryFromIterator;
+
+use super::instruction::Instruction;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn new(instructions: Vec<Instruction>) -> Self {
+        Self { instructions }
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn into_instructions(self) -> Vec<Instruction> {
+        self.instructions
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    pub fn effective(&self, outputs: &[usize]) -> Vec<bool> {
+        effective(&self.instructions, outputs)
+    }
+
+    pub fn introns(&self, outputs: &[usize]) -> Vec<usize> {
+        self.effective(outputs)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, effective)| (!effective).then_some(index))
+            .collect()
+    }
+
+    pub fn without_introns(&self, outputs: &[usize]) -> Self {
+        Self::new(
+            self.instructions
+                .iter()
+                .zip(self.effective(outputs))
+                .filter_map(|(instruction, effective)| effective.then_some(*instruction))
+                .collect(),
+        )
+    }
+}
+
+pub fn effective(program: &[Instruction], outputs: &[usize]) -> Vec<bool> {
+    let mut effective = vec![false; program.len()];
+    let mut live = outputs.iter().copied().collect::<HashSet<_>>();
+
+    for (index, instruction) in program.iter().enumerate().rev() {
+        let guarded = index > 0 && program[index - 1].operation.is_conditional();
+
+        if instruction.operation.is_conditional() {
+            effective[index] = effective.get(index + 1).copied().unwrap_or(false);
+        } else if live.contains(&instruction.target) {
+            effective[index] = true;
+
+            if !guarded {
+                live.remove(&instruction.target);
+            }
+        }
+
+        if effective[index] {
+            live.extend(instruction.registers());
+        }
+    }
+
+    effective
+}
+
+impl Individual for Program {
+    type Genome = Vec<Instruction>;
+    type Fitness = Nil;
+
+    fn genome(&self) -> &Self::Genome {
+        &self.instructions
+    }
+
+    fn genome_mut(&mut self) -> &mut Self::Genome {
+        &mut self.instructions
+    }
+
+    fn fitness(&self) -> &Self::Fitness {
+        Nil::r#ref()
+    }
+
+    fn fitness_mut(&mut self) -> &mut Self::Fitness {
+        Nil::r#mut()
+    }
+}
+
+impl Size for Program {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Population for Program {
+    type Individual = Instruction;
+
+    fn len(&self) -> usize {
+        self.instructions.len()
+    }
+}
+
+impl TryFromIterator<Instruction> for Program {
+    type Error = Infallible;
+
+    fn try_from_iter<I>(iter: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = Instruction>,
+    {
+        Ok(Self::from_iter(iter))
+    }
+}
+
+impl FromIterator<Instruction> for Program {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Instruction>,
+    {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl From<Vec<Instruction>> for Program {
+    fn from(instructions: Vec<Instruction>) -> Self {
+        Self::new(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chromosome::size::Size;
+    use crate::individual::evaluated::Evaluated;
+    use crate::linear::instruction::{Instruction, InstructionSet, Operand, Operation};
+    use crate::linear::interpreter::Interpreter;
+    use crate::operator::generator::Generator;
+    use crate::operator::selector::double_tournament::DoubleTournament;
+    use crate::operator::selector::parsimony::Parsimony;
+    use crate::population::Population;
+
+    use super::Program;
+
+    #[test]
+    fn test_introns() {
+        let program = Program::new(vec![
+            Instruction::new(Operation::Add, 1, Operand::Input(0), Operand::Input(0)),
+            Instruction::new(Operation::Mul, 0, Operand::Input(0), Operand::Input(0)),
+            Instruction::new(Operation::Sub, 2, Operand::Register(1), Operand::Input(0)),
+            Instruction::new(
+                Operation::Add,
+                0,
+                Operand::Register(0),
+                Operand::Register(1),
+            ),
+            Instruction::new(Operation::Mul, 1, Operand::Register(0), Operand::Input(0)),
+        ]);
+
+        assert_eq!(program.effective(&[0]), [true, true, false, true, false]);
+        assert_eq!(program.introns(&[0]), [2, 4]);
+        assert_eq!(program.introns(&[1]), [2]);
+        assert_eq!(program.introns(&[2]), [1, 3, 4]);
+
+        let stripped = program.without_introns(&[0]);
+        let interpreter = Interpreter::new(3);
+
+        assert_eq!(stripped.len(), 3);
+
+        for input in [-2.0, 0.0, 1.5, 7.0] {
+            assert_eq!(
+                interpreter.run(program.instructions(), &[input]).unwrap()[0],
+                interpreter.run(stripped.instructions(), &[input]).unwrap()[0]
+            );
+        }
+    }
+
+    #[test]
+    fn test_introns_conditional() {
+        let program = Program::new(vec![
+            Instruction::new(Operation::Add, 0, Operand::Input(0), Operand::Constant(1.0)),
+            Instruction::new(
+                Operation::IfLess,
+                0,
+                Operand::Register(1),
+                Operand::Input(0),
+            ),
+            Instruction::new(Operation::Mul, 0, Operand::Input(0), Operand::Input(0)),
+            Instruction::new(
+                Operation::IfLess,
+                0,
+                Operand::Register(2),
+                Operand::Input(0),
+            ),
+            Instruction::new(Operation::Mul, 3, Operand::Input(0), Operand::Input(0)),
+        ]);
+
+        assert_eq!(program.effective(&[0]), [true, true, true, false, false]);
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut rng = rand::rng();
+
+        let a: Program = InstructionSet::new(4, 1)
+            .populate(10)
+            .generate(&mut rng)
+            .unwrap();
+        let b: Evaluated<Program, u8> = InstructionSet::new(4, 1)
+            .populate(5)
+            .generate(&mut rng)
+            .unwrap();
+
+        assert_eq!(a.len(), 10);
+        assert_eq!(b.individual.len(), 5);
+    }
+
+    #[test]
+    fn test_size() {
+        let instruction = Instruction::new(Operation::Add, 0, Operand::Input(0), Operand::Input(0));
+        let population = [
+            Evaluated::new(Program::new(vec![instruction; 6]), 1),
+            Evaluated::new(Program::new(vec![instruction; 2]), 1),
+            Evaluated::new(Program::new(vec![instruction; 9]), 1),
+        ];
+
+        let a = population.select(Parsimony::new(3)).unwrap();
+        let b = (0..200)
+            .map(|_| {
+                population.select(DoubleTournament::new(1, 1.0)).unwrap()[0]
+                    .individual
+                    .size()
+            })
+            .sum::<usize>();
+
+        assert_eq!(Program::new(vec![instruction; 4]).size(), 4);
+        assert_eq!(a[0].individual.size(), 2);
+        assert!(b < 200 * 5);
+    }
+}
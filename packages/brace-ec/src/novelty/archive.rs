@@ -0,0 +1,143 @@
+use super::Behaviour;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Insertion {
+    Threshold(f64),
+    Random(f64),
+}
+
+impl Default for Insertion {
+    fn default() -> Self {
+        Self::Random(0.01)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Archive<B> {
+    behaviours: Vec<B>,
+    insertion: Insertion,
+    capacity: Option<usize>,
+}
+
+impl<B> Archive<B> {
+    pub fn new(insertion: Insertion) -> Self {
+        Self {
+            behaviours: Vec::new(),
+            insertion,
+            capacity: None,
+        }
+    }
+
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    pub fn behaviours(&self) -> &[B] {
+        &self.behaviours
+    }
+
+    pub fn len(&self) -> usize {
+        self.behaviours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.behaviours.is_empty()
+    }
+
+    pub fn insert<Rng>(&mut self, behaviour: &B, novelty: f64, rng: &mut Rng) -> bool
+    where
+        B: Behaviour,
+        Rng: rand::Rng + ?Sized,
+    {
+        let accept = match self.insertion {
+            Insertion::Threshold(threshold) => novelty >= threshold,
+            Insertion::Random(probability) => rng.random_bool(probability.clamp(0.0, 1.0)),
+        };
+
+        if accept {
+            if let Some(capacity) = self.capacity {
+                if capacity == 0 {
+                    return false;
+                }
+
+                if self.behaviours.len() >= capacity {
+                    self.behaviours.remove(0);
+                }
+            }
+
+            self.behaviours.push(behaviour.clone());
+        }
+
+        accept
+    }
+
+    pub fn novelty(&self, index: usize, population: &[B], neighbours: usize) -> f64
+    where
+        B: Behaviour,
+    {
+        let behaviour = &population[index];
+
+        let mut distances = population
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, other)| behaviour.distance(other))
+            .chain(
+                self.behaviours
+                    .iter()
+                    .map(|other| behaviour.distance(other)),
+            )
+            .collect::<Vec<_>>();
+
+        if distances.is_empty() || neighbours == 0 {
+            return 0.0;
+        }
+
+        distances.sort_by(f64::total_cmp);
+        distances.truncate(neighbours);
+
+        distances.iter().sum::<f64>() / distances.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Archive, Insertion};
+
+    #[test]
+    fn test_novelty() {
+        let population = [0.0, 1.0, 3.0];
+        let mut archive = Archive::new(Insertion::Threshold(2.0));
+
+        assert_eq!(archive.novelty(0, &population, 1), 1.0);
+        assert_eq!(archive.novelty(2, &population, 1), 2.0);
+        assert_eq!(archive.novelty(1, &population, 2), 1.5);
+        assert_eq!(archive.novelty(0, &population, 5), 2.0);
+        assert_eq!(archive.novelty(0, &[0.0], 5), 0.0);
+
+        archive.insert(&-1.0, 2.0, &mut rand::rng());
+
+        assert_eq!(archive.novelty(0, &population, 2), 1.0);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut rng = rand::rng();
+
+        let mut a = Archive::new(Insertion::Threshold(1.0)).with_capacity(2);
+        let mut b = Archive::new(Insertion::Random(1.0));
+        let mut c = Archive::new(Insertion::Random(0.0));
+
+        assert!(!a.insert(&[0.0], 0.5, &mut rng));
+        assert!(a.insert(&[1.0], 1.0, &mut rng));
+        assert!(a.insert(&[2.0], 1.5, &mut rng));
+        assert!(a.insert(&[3.0], 2.0, &mut rng));
+        assert!(b.insert(&true, 0.0, &mut rng));
+        assert!(!c.insert(&true, 10.0, &mut rng));
+
+        assert_eq!(a.behaviours(), [[2.0], [3.0]]);
+        assert_eq!(b.len(), 1);
+        assert!(c.is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+pub mod archive;
+
+use std::cmp::Ordering;
+
+use ordered_float::OrderedFloat;
+
+use crate::distance::Distance;
+use crate::fitness::Fitness;
+use crate::util::iter::Iterable;
+
+pub trait Behaviour: Clone {
+    fn distance(&self, other: &Self) -> f64;
+}
+
+impl<T> Behaviour for T
+where
+    T: Distance + Clone,
+{
+    fn distance(&self, other: &Self) -> f64 {
+        Distance::distance(self, other)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Behavioural<F, B> {
+    fitness: F,
+    behaviour: B,
+    objectives: [OrderedFloat<f64>; 2],
+    score: OrderedFloat<f64>,
+}
+
+impl<F, B> Behavioural<F, B> {
+    pub fn new(fitness: F, behaviour: B) -> Self {
+        Self {
+            fitness,
+            behaviour,
+            objectives: [OrderedFloat(0.0); 2],
+            score: OrderedFloat(0.0),
+        }
+    }
+
+    pub fn fitness(&self) -> &F {
+        &self.fitness
+    }
+
+    pub fn behaviour(&self) -> &B {
+        &self.behaviour
+    }
+
+    pub fn novelty(&self) -> f64 {
+        self.objectives[0].0
+    }
+
+    pub fn objectives(&self) -> [f64; 2] {
+        self.objectives.map(|objective| objective.0)
+    }
+
+    pub fn score(&self) -> f64 {
+        self.score.0
+    }
+
+    pub(crate) fn set_novelty(&mut self, novelty: f64, fitness: f64, score: f64) {
+        self.objectives = [OrderedFloat(novelty), OrderedFloat(fitness)];
+        self.score = OrderedFloat(score);
+    }
+}
+
+impl<F, B> PartialEq for Behavioural<F, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score
+    }
+}
+
+impl<F, B> Eq for Behavioural<F, B> {}
+
+impl<F, B> PartialOrd for Behavioural<F, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F, B> Ord for Behavioural<F, B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.cmp(&other.score)
+    }
+}
+
+impl<F, B> Fitness for Behavioural<F, B>
+where
+    F: Fitness,
+    B: Default,
+{
+    fn nil() -> Self {
+        Self::new(F::nil(), B::default())
+    }
+}
+
+impl<F, B> Iterable for Behavioural<F, B> {
+    type Item = OrderedFloat<f64>;
+    type Iter<'a>
+        = std::slice::Iter<'a, OrderedFloat<f64>>
+    where
+        Self: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.objectives.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use crate::util::iter::Iterable;
+
+    use super::{Behaviour, Behavioural};
+
+    #[test]
+    fn test_behaviour() {
+        assert_eq!(Behaviour::distance(&vec![0.0, 0.0], &vec![3.0, 4.0]), 5.0);
+        assert_eq!(Behaviour::distance(&[true, false], &[false, false]), 1.0);
+    }
+
+    #[test]
+    fn test_behavioural() {
+        let mut a = Behavioural::new(10, [0.0, 1.0]);
+        let mut b = Behavioural::new(5, [0.0, 2.0]);
+
+        a.set_novelty(2.0, 10.0, 2.0);
+        b.set_novelty(4.0, 5.0, 4.0);
+
+        assert_eq!(a.fitness(), &10);
+        assert_eq!(a.behaviour(), &[0.0, 1.0]);
+        assert_eq!(a.novelty(), 2.0);
+        assert_eq!(b.objectives(), [4.0, 5.0]);
+        assert!(b > a);
+        assert_eq!(
+            a.iter().copied().collect::<Vec<_>>(),
+            [OrderedFloat(2.0), OrderedFloat(10.0)]
+        );
+    }
+}
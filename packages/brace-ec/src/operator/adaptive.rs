@@ -0,0 +1,645 @@
+use std::error::Error;
+use std::sync::Mutex;
+
+use rand::seq::IndexedRandom;
+use thiserror::Error;
+
+use crate::fitness::numeric::Numeric;
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+use crate::util::iter::Iterable;
+
+use super::evolver::{DynEvolver, Evolver};
+use super::mutator::{DynMutator, Mutator};
+use super::recombinator::{DynRecombinator, Recombinator};
+use super::selector::{DynSelector, Selector};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Strategy {
+    ProbabilityMatching {
+        minimum: f64,
+        rate: f64,
+    },
+    AdaptivePursuit {
+        minimum: f64,
+        rate: f64,
+        learning: f64,
+    },
+    Ucb {
+        exploration: f64,
+    },
+    EpsilonGreedy {
+        epsilon: f64,
+    },
+}
+
+impl Strategy {
+    fn is_valid(&self, operators: usize) -> bool {
+        let unit = |value: f64| value > 0.0 && value <= 1.0;
+
+        match *self {
+            Self::ProbabilityMatching { minimum, rate } => {
+                minimum >= 0.0 && minimum * operators as f64 <= 1.0 && unit(rate)
+            }
+            Self::AdaptivePursuit {
+                minimum,
+                rate,
+                learning,
+            } => {
+                minimum >= 0.0 && minimum * operators as f64 <= 1.0 && unit(rate) && unit(learning)
+            }
+            Self::Ucb { exploration } => exploration >= 0.0,
+            Self::EpsilonGreedy { epsilon } => (0.0..=1.0).contains(&epsilon),
+        }
+    }
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        Self::ProbabilityMatching {
+            minimum: 0.05,
+            rate: 0.3,
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    qualities: Vec<f64>,
+    probabilities: Vec<f64>,
+    counts: Vec<usize>,
+}
+
+impl State {
+    fn push(&mut self) {
+        let len = self.qualities.len() + 1;
+
+        self.qualities.push(0.0);
+        self.counts.push(0);
+        self.probabilities = vec![1.0 / len as f64; len];
+    }
+
+    fn best(&self) -> usize {
+        self.qualities
+            .iter()
+            .enumerate()
+            .fold(0, |best, (index, quality)| {
+                match *quality > self.qualities[best] {
+                    true => index,
+                    false => best,
+                }
+            })
+    }
+}
+
+pub struct Adaptive<T>
+where
+    T: ?Sized,
+{
+    operators: Vec<Box<T>>,
+    strategy: Strategy,
+    state: Mutex<State>,
+}
+
+impl<T> Adaptive<T>
+where
+    T: ?Sized,
+{
+    fn new(operator: Box<T>) -> Self {
+        Self {
+            operators: Vec::new(),
+            strategy: Strategy::default(),
+            state: Mutex::new(State::default()),
+        }
+        .push(operator)
+    }
+
+    fn push(mut self, operator: Box<T>) -> Self {
+        self.operators.push(operator);
+        self.state.get_mut().unwrap().push();
+        self
+    }
+
+    pub fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn weights(&self) -> Vec<f64> {
+        let state = self.state.lock().unwrap();
+        let len = state.qualities.len();
+
+        match self.strategy {
+            Strategy::ProbabilityMatching { .. } | Strategy::AdaptivePursuit { .. } => {
+                state.probabilities.clone()
+            }
+            Strategy::Ucb { .. } => {
+                let total = state.counts.iter().sum::<usize>();
+
+                state
+                    .counts
+                    .iter()
+                    .map(|count| match total {
+                        0 => 1.0 / len as f64,
+                        _ => *count as f64 / total as f64,
+                    })
+                    .collect()
+            }
+            Strategy::EpsilonGreedy { epsilon } => {
+                let best = state.best();
+
+                (0..len)
+                    .map(|index| match index == best {
+                        true => 1.0 - epsilon + epsilon / len as f64,
+                        false => epsilon / len as f64,
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    pub fn qualities(&self) -> Vec<f64> {
+        self.state.lock().unwrap().qualities.clone()
+    }
+
+    pub fn counts(&self) -> Vec<usize> {
+        self.state.lock().unwrap().counts.clone()
+    }
+
+    fn choose<Rng>(&self, rng: &mut Rng) -> Result<usize, AdaptiveError>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if !self.strategy.is_valid(self.operators.len()) {
+            return Err(AdaptiveError::InvalidStrategy);
+        }
+
+        let state = self.state.lock().unwrap();
+        let indices = (0..self.operators.len()).collect::<Vec<_>>();
+
+        Ok(match self.strategy {
+            Strategy::ProbabilityMatching { .. } | Strategy::AdaptivePursuit { .. } => *indices
+                .choose_weighted(rng, |index| state.probabilities[*index])
+                .expect("cannot construct without at least 1 operator"),
+            Strategy::Ucb { exploration } => {
+                if let Some(index) = state.counts.iter().position(|count| *count == 0) {
+                    return Ok(index);
+                }
+
+                let total = (state.counts.iter().sum::<usize>() as f64).ln();
+
+                indices
+                    .into_iter()
+                    .map(|index| {
+                        let bonus = (2.0 * total / state.counts[index] as f64).sqrt();
+
+                        (index, state.qualities[index] + exploration * bonus)
+                    })
+                    .fold((0, f64::NEG_INFINITY), |best, next| match next.1 > best.1 {
+                        true => next,
+                        false => best,
+                    })
+                    .0
+            }
+            Strategy::EpsilonGreedy { epsilon } => match rng.random_bool(epsilon) {
+                true => rng.random_range(0..self.operators.len()),
+                false => state.best(),
+            },
+        })
+    }
+
+    pub fn reward(&self, index: usize, credit: f64) {
+        let mut state = self.state.lock().unwrap();
+        let credit = credit.max(0.0);
+        let len = state.qualities.len();
+
+        state.counts[index] += 1;
+
+        match self.strategy {
+            Strategy::ProbabilityMatching { minimum, rate } => {
+                state.qualities[index] += rate * (credit - state.qualities[index]);
+
+                let total = state.qualities.iter().sum::<f64>();
+
+                state.probabilities = match total > 0.0 {
+                    true => state
+                        .qualities
+                        .iter()
+                        .map(|quality| minimum + (1.0 - len as f64 * minimum) * quality / total)
+                        .collect(),
+                    false => vec![1.0 / len as f64; len],
+                };
+            }
+            Strategy::AdaptivePursuit {
+                minimum,
+                rate,
+                learning,
+            } => {
+                state.qualities[index] += rate * (credit - state.qualities[index]);
+
+                let best = state.best();
+                let maximum = 1.0 - (len - 1) as f64 * minimum;
+
+                for (position, probability) in state.probabilities.iter_mut().enumerate() {
+                    let target = match position == best {
+                        true => maximum,
+                        false => minimum,
+                    };
+
+                    *probability += learning * (target - *probability);
+                }
+            }
+            Strategy::Ucb { .. } | Strategy::EpsilonGreedy { .. } => {
+                let count = state.counts[index] as f64;
+
+                state.qualities[index] += (credit - state.qualities[index]) / count;
+            }
+        }
+    }
+}
+
+impl<P, O> Adaptive<dyn DynSelector<P, O> + Send + Sync>
+where
+    P: Population + ?Sized,
+    O: Population<Individual = P::Individual>,
+{
+    pub fn selector<S>(selector: S) -> Self
+    where
+        S: Selector<P, Output = O, Error: Error + Send + Sync + 'static> + Send + Sync + 'static,
+    {
+        Self::new(Box::new(selector))
+    }
+
+    pub fn with_selector<S>(self, selector: S) -> Self
+    where
+        S: Selector<P, Output: Into<O>, Error: Error + Send + Sync + 'static>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.push(Box::new(selector))
+    }
+
+    pub fn reproduce<R>(self, recombinator: R) -> Reproduce<Self, R>
+    where
+        R: Recombinator<O>,
+    {
+        Reproduce {
+            adaptive: self,
+            recombinator,
+        }
+    }
+}
+
+pub struct Reproduce<A, R> {
+    adaptive: A,
+    recombinator: R,
+}
+
+impl<A, R> Reproduce<A, R> {
+    pub fn adaptive(&self) -> &A {
+        &self.adaptive
+    }
+}
+
+impl<P, O, R> Selector<P> for Reproduce<Adaptive<dyn DynSelector<P, O> + Send + Sync>, R>
+where
+    P: Population<Individual: Individual<Fitness: Numeric>> + ?Sized,
+    O: IterablePopulation<Individual = P::Individual>,
+    R: Recombinator<
+        O,
+        Output: IterablePopulation<Individual = P::Individual>,
+        Error: Error + Send + Sync + 'static,
+    >,
+{
+    type Output = R::Output;
+    type Error = AdaptiveError;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let index = self.adaptive.choose(rng)?;
+
+        let parents = self.adaptive.operators[index]
+            .select(population, rng)
+            .map_err(AdaptiveError::Operate)?;
+        let parent = mean(parents.iter());
+
+        let offspring = self
+            .recombinator
+            .recombine(parents, rng)
+            .map_err(|error| AdaptiveError::Operate(Box::new(error)))?;
+
+        self.adaptive.reward(index, mean(offspring.iter()) - parent);
+
+        Ok(offspring)
+    }
+}
+
+impl<I> Adaptive<dyn DynMutator<I> + Send + Sync>
+where
+    I: Individual,
+{
+    pub fn mutator<M>(mutator: M) -> Self
+    where
+        M: Mutator<I, Error: Error + Send + Sync + 'static> + Send + Sync + 'static,
+    {
+        Self::new(Box::new(mutator))
+    }
+
+    pub fn with_mutator<M>(self, mutator: M) -> Self
+    where
+        M: Mutator<I, Error: Error + Send + Sync + 'static> + Send + Sync + 'static,
+    {
+        self.push(Box::new(mutator))
+    }
+}
+
+impl<I> Mutator<I> for Adaptive<dyn DynMutator<I> + Send + Sync>
+where
+    I: Individual<Fitness: Numeric>,
+{
+    type Error = AdaptiveError;
+
+    fn mutate<Rng>(&self, individual: I, rng: &mut Rng) -> Result<I, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let index = self.choose(rng)?;
+        let parent = individual.fitness().as_f64();
+
+        let offspring = self.operators[index]
+            .mutate(individual, rng)
+            .map_err(AdaptiveError::Operate)?;
+
+        self.reward(index, offspring.fitness().as_f64() - parent);
+
+        Ok(offspring)
+    }
+}
+
+impl<P, O> Adaptive<dyn DynRecombinator<P, O> + Send + Sync>
+where
+    P: Population,
+    O: Population<Individual = P::Individual>,
+{
+    pub fn recombinator<R>(recombinator: R) -> Self
+    where
+        R: Recombinator<P, Output = O, Error: Error + Send + Sync + 'static>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self::new(Box::new(recombinator))
+    }
+
+    pub fn with_recombinator<R>(self, recombinator: R) -> Self
+    where
+        R: Recombinator<P, Output: Into<O>, Error: Error + Send + Sync + 'static>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.push(Box::new(recombinator))
+    }
+}
+
+impl<P, O> Recombinator<P> for Adaptive<dyn DynRecombinator<P, O> + Send + Sync>
+where
+    P: IterablePopulation<Individual: Individual<Fitness: Numeric>>,
+    O: IterablePopulation<Individual = P::Individual>,
+{
+    type Output = O;
+    type Error = AdaptiveError;
+
+    fn recombine<Rng>(&self, parents: P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let index = self.choose(rng)?;
+        let best = maximum(parents.iter());
+
+        let offspring = self.operators[index]
+            .recombine(parents, rng)
+            .map_err(AdaptiveError::Operate)?;
+
+        self.reward(index, maximum(offspring.iter()) - best);
+
+        Ok(offspring)
+    }
+}
+
+impl<G> Adaptive<dyn DynEvolver<G> + Send + Sync>
+where
+    G: Generation,
+{
+    pub fn evolver<E>(evolver: E) -> Self
+    where
+        E: Evolver<G, Error: Error + Send + Sync + 'static> + Send + Sync + 'static,
+    {
+        Self::new(Box::new(evolver))
+    }
+
+    pub fn with_evolver<E>(self, evolver: E) -> Self
+    where
+        E: Evolver<G, Error: Error + Send + Sync + 'static> + Send + Sync + 'static,
+    {
+        self.push(Box::new(evolver))
+    }
+}
+
+impl<G> Evolver<G> for Adaptive<dyn DynEvolver<G> + Send + Sync>
+where
+    G: Generation<Population: IterablePopulation<Individual: Individual<Fitness: Numeric>>>,
+{
+    type Error = AdaptiveError;
+
+    fn evolve<Rng>(&self, generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let index = self.choose(rng)?;
+        let best = maximum(generation.population().iter());
+
+        let generation = self.operators[index]
+            .evolve(generation, rng)
+            .map_err(AdaptiveError::Operate)?;
+
+        self.reward(index, maximum(generation.population().iter()) - best);
+
+        Ok(generation)
+    }
+}
+
+fn mean<'a, I, T>(individuals: T) -> f64
+where
+    I: Individual<Fitness: Numeric> + 'a,
+    T: IntoIterator<Item = &'a I>,
+{
+    let (total, count) = individuals
+        .into_iter()
+        .fold((0.0, 0), |(total, count), individual| {
+            (total + individual.fitness().as_f64(), count + 1)
+        });
+
+    match count {
+        0 => 0.0,
+        _ => total / count as f64,
+    }
+}
+
+fn maximum<'a, I, T>(individuals: T) -> f64
+where
+    I: Individual<Fitness: Numeric> + 'a,
+    T: IntoIterator<Item = &'a I>,
+{
+    individuals
+        .into_iter()
+        .map(|individual| individual.fitness().as_f64())
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+#[derive(Debug, Error)]
+pub enum AdaptiveError {
+    #[error("adaptive strategy parameters are out of range")]
+    InvalidStrategy,
+    #[error(transparent)]
+    Operate(Box<dyn Error + Send + Sync>),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evolver::select::Select;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::recombinator::mutate::Mutate;
+    use crate::operator::recombinator::sum::Sum;
+    use crate::operator::recombinator::Recombinator;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::worst::Worst;
+    use crate::operator::selector::Selector;
+
+    use super::{Adaptive, AdaptiveError, Strategy};
+
+    fn strategies() -> [Strategy; 4] {
+        [
+            Strategy::ProbabilityMatching {
+                minimum: 0.05,
+                rate: 0.3,
+            },
+            Strategy::AdaptivePursuit {
+                minimum: 0.05,
+                rate: 0.3,
+                learning: 0.3,
+            },
+            Strategy::Ucb { exploration: 0.1 },
+            Strategy::EpsilonGreedy { epsilon: 0.1 },
+        ]
+    }
+
+    #[test]
+    fn test_select() {
+        let mut rng = rand::rng();
+        let population = [0, 1, 2, 3, 4].map(|genome| Evaluated::new(genome, genome * genome));
+
+        for strategy in strategies() {
+            let selector = Adaptive::selector(Worst)
+                .with_selector(Best)
+                .with_strategy(strategy)
+                .reproduce(Recombinator::<[_; 1]>::evaluate_with(
+                    Mutate::new(Add(1)),
+                    |individual: &Evaluated<i32, i32>| {
+                        Ok::<_, Infallible>(individual.individual * individual.individual)
+                    },
+                ));
+
+            for _ in 0..200 {
+                selector.select(&population, &mut rng).unwrap();
+            }
+
+            let weights = selector.adaptive().weights();
+
+            assert_eq!(weights.len(), 2);
+            assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+            assert!(weights[1] > weights[0]);
+            assert!(selector.adaptive().qualities()[1] > selector.adaptive().qualities()[0]);
+            assert_eq!(selector.adaptive().counts().iter().sum::<usize>(), 200);
+        }
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = rand::rng();
+
+        for strategy in strategies() {
+            let adaptive = Adaptive::mutator(Add(1))
+                .with_mutator(Add(-1))
+                .with_mutator(Add(3))
+                .with_strategy(strategy);
+
+            let mut individual = 0;
+
+            for _ in 0..200 {
+                individual = adaptive.mutate(individual, &mut rng).unwrap();
+            }
+
+            let weights = adaptive.weights();
+
+            assert!(weights[2] > weights[0] && weights[0] >= weights[1]);
+            assert!(individual > 200);
+        }
+    }
+
+    #[test]
+    fn test_recombine() {
+        let mut rng = rand::rng();
+
+        let adaptive = Adaptive::recombinator(Sum).with_recombinator(Sum);
+
+        assert_eq!(adaptive.recombine([1, 2], &mut rng).unwrap(), [3]);
+        assert_eq!(adaptive.counts().iter().sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let adaptive = Adaptive::evolver(Select::fill(Best.mutate(Add(-1))))
+            .with_evolver(Select::fill(Best.mutate(Add(1))))
+            .with_strategy(Strategy::Ucb { exploration: 0.0 });
+
+        let a = adaptive.evolve((0, [0, 1, 2, 3, 4]), &mut rng).unwrap();
+        let b = adaptive.evolve((0, [0, 1, 2, 3, 4]), &mut rng).unwrap();
+        let c = adaptive.evolve((0, [0, 1, 2, 3, 4]), &mut rng).unwrap();
+
+        assert_eq!(a, (1, [3; 5]));
+        assert_eq!(b, (1, [5; 5]));
+        assert_eq!(c, (1, [5; 5]));
+        assert_eq!(adaptive.counts(), [1, 2]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut rng = rand::rng();
+
+        let a = Adaptive::mutator(Add(1))
+            .with_mutator(Add(2))
+            .with_strategy(Strategy::ProbabilityMatching {
+                minimum: 0.6,
+                rate: 0.3,
+            })
+            .mutate(0, &mut rng);
+        let b = Adaptive::mutator(Add(1))
+            .with_strategy(Strategy::EpsilonGreedy { epsilon: 2.0 })
+            .mutate(0, &mut rng);
+
+        assert!(matches!(a, Err(AdaptiveError::InvalidStrategy)));
+        assert!(matches!(b, Err(AdaptiveError::InvalidStrategy)));
+    }
+}
@@ -1,3 +1,4 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use thiserror::Error;
 
 use crate::generation::Generation;
@@ -10,6 +11,7 @@ use super::evaluator::Evaluator;
 use super::evolver::Evolver;
 use super::generator::Generator;
 use super::mutator::Mutator;
+use super::neighbourhood::Neighbourhood;
 use super::recombinator::Recombinator;
 use super::selector::Selector;
 
@@ -166,6 +168,86 @@ where
     }
 }
 
+impl<T, S, I> Neighbourhood<I> for Evaluate<T, S>
+where
+    T: Neighbourhood<I>,
+    S: Evaluator<I>,
+    I: Individual,
+{
+    type Move = T::Move;
+    type Error = EvaluateError<T::Error, S::Error>;
+
+    fn neighbours<Rng>(
+        &self,
+        individual: &I,
+        rng: &mut Rng,
+    ) -> Result<Vec<(Self::Move, I)>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.operator
+            .neighbours(individual, rng)
+            .map_err(EvaluateError::Operate)?
+            .into_iter()
+            .map(|(r#move, neighbour)| {
+                let fitness = self
+                    .evaluator
+                    .evaluate(&neighbour, rng)
+                    .map_err(EvaluateError::Evaluate)?;
+
+                Ok((r#move, neighbour.with_fitness(fitness)))
+            })
+            .collect()
+    }
+}
+
+pub struct ParEvaluate<T, S> {
+    operator: T,
+    evaluator: S,
+}
+
+impl<T, S> ParEvaluate<T, S> {
+    pub fn new(operator: T, evaluator: S) -> Self {
+        Self {
+            operator,
+            evaluator,
+        }
+    }
+}
+
+impl<T, S, I> Neighbourhood<I> for ParEvaluate<T, S>
+where
+    T: Neighbourhood<I, Move: Send>,
+    S: Evaluator<I, Error: Send> + Sync,
+    I: Individual + Send,
+{
+    type Move = T::Move;
+    type Error = EvaluateError<T::Error, S::Error>;
+
+    fn neighbours<Rng>(
+        &self,
+        individual: &I,
+        rng: &mut Rng,
+    ) -> Result<Vec<(Self::Move, I)>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let evaluator = &self.evaluator;
+
+        self.operator
+            .neighbours(individual, rng)
+            .map_err(EvaluateError::Operate)?
+            .into_par_iter()
+            .map_init(rand::rng, |rng, (r#move, neighbour)| {
+                let fitness = evaluator.evaluate(&neighbour, rng)?;
+
+                Ok((r#move, neighbour.with_fitness(fitness)))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(EvaluateError::Evaluate)
+    }
+}
+
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum EvaluateError<O, S> {
     #[error(transparent)]
@@ -0,0 +1,142 @@
+use num_traits::ToPrimitive;
+use thiserror::Error;
+
+use crate::chromosome::Chromosome;
+use crate::grammar::mapper::{Mapper, MappingError};
+use crate::individual::Individual;
+use crate::operator::evaluator::Evaluator;
+
+pub struct Grammatical<F> {
+    mapper: Mapper,
+    evaluator: F,
+}
+
+impl<F> Grammatical<F> {
+    pub fn new(mapper: Mapper, evaluator: F) -> Self {
+        Self { mapper, evaluator }
+    }
+
+    pub fn mapper(&self) -> &Mapper {
+        &self.mapper
+    }
+}
+
+impl<F, I, E> Evaluator<I> for Grammatical<F>
+where
+    F: Fn(&str) -> Result<I::Fitness, E>,
+    I: Individual<Genome: Chromosome<Gene: ToPrimitive>>,
+{
+    type Error = GrammaticalError<E>;
+
+    fn evaluate<Rng>(&self, individual: &I, _: &mut Rng) -> Result<I::Fitness, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let phenotype = self
+            .mapper
+            .map(individual.genome())
+            .map_err(GrammaticalError::Map)?;
+
+        (self.evaluator)(&phenotype).map_err(GrammaticalError::Evaluate)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GrammaticalError<E> {
+    #[error(transparent)]
+    Map(MappingError),
+    #[error(transparent)]
+    Evaluate(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::grammar::mapper::{Mapper, MappingError};
+    use crate::grammar::Grammar;
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+    use crate::operator::evaluator::Evaluator;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::generator::random::Random;
+    use crate::operator::generator::Generator;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::recombinator::point::OnePointCrossover;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::Selector;
+
+    use super::{Grammatical, GrammaticalError};
+
+    fn mapper() -> Mapper {
+        Mapper::new(
+            Grammar::parse(
+                "
+                <word>   ::= <letter> | <letter><word>
+                <letter> ::= a | b | c
+                ",
+            )
+            .unwrap(),
+        )
+        .with_wraps(2)
+    }
+
+    fn count(phenotype: &str) -> Result<usize, Infallible> {
+        Ok(phenotype.chars().filter(|char| *char == 'a').count())
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut rng = rand::rng();
+
+        let evaluator = Grammatical::new(mapper(), count);
+
+        let a = evaluator.evaluate(&[1u8, 0, 1, 0, 0, 1].evaluated(), &mut rng);
+        let b = evaluator.evaluate(&vec![1u32, 2, 0].evaluated(), &mut rng);
+        let c = evaluator.evaluate(&[1u8].evaluated(), &mut rng);
+
+        assert_eq!(a, Ok(2));
+        assert_eq!(b, Ok(0));
+        assert_eq!(c, Err(GrammaticalError::Map(MappingError::WrapLimit)));
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let population: Vec<Evaluated<Vec<u8>, usize>> = Random::from(0..u8::MAX)
+            .populate(8)
+            .populate(20)
+            .generate(&mut rng)
+            .unwrap();
+
+        let (generation, population) = Tournament::binary()
+            .twice()
+            .reproduce(OnePointCrossover)
+            .mutate(Noise::new(1..=16).each_reciprocal_rate())
+            .evaluate(Grammatical::new(
+                Mapper::new(
+                    Grammar::parse(
+                        "
+                        <word>   ::= <letter><letter><letter>
+                        <letter> ::= a | b | c
+                        ",
+                    )
+                    .unwrap(),
+                ),
+                count,
+            ))
+            .fill()
+            .evolver()
+            .repeat(5)
+            .evolve((0, population), &mut rng)
+            .unwrap();
+
+        assert_eq!(generation, 5);
+        assert_eq!(population.len(), 20);
+        assert!(population
+            .iter()
+            .all(|individual| individual.genome().len() == 8 && individual.fitness <= 3));
+    }
+}
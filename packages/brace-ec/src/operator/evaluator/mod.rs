@@ -1,6 +1,8 @@
 pub mod count;
 pub mod function;
+pub mod grammatical;
 pub mod hiff;
+pub mod regression;
 
 use std::error::Error;
 
@@ -30,9 +32,7 @@ where
     T: Evaluator<I, Error: Into<E>>,
 {
     fn dyn_evaluate(&self, individual: &I, rng: &mut dyn rand::RngCore) -> Result<I::Fitness, E> {
-        self.evaluate(individual, rng)
-            .map(Into::into)
-            .map_err(Into::into)
+        self.evaluate(individual, rng).map_err(Into::into)
     }
 }
 
@@ -0,0 +1,193 @@
+use std::convert::Infallible;
+
+use ordered_float::OrderedFloat;
+
+use crate::individual::Individual;
+use crate::linear::instruction::Instruction;
+use crate::linear::interpreter::Interpreter;
+use crate::operator::evaluator::Evaluator;
+
+#[derive(Clone, Debug)]
+pub struct Regression {
+    interpreter: Interpreter,
+    cases: Vec<(Vec<f64>, Vec<f64>)>,
+    outputs: Vec<usize>,
+    penalty: f64,
+}
+
+impl Regression {
+    pub fn new(interpreter: Interpreter) -> Self {
+        Self {
+            interpreter,
+            cases: Vec::new(),
+            outputs: vec![0],
+            penalty: 1e6,
+        }
+    }
+
+    pub fn with_case(mut self, inputs: Vec<f64>, expected: Vec<f64>) -> Self {
+        self.cases.push((inputs, expected));
+        self
+    }
+
+    pub fn with_cases(mut self, cases: impl IntoIterator<Item = (Vec<f64>, Vec<f64>)>) -> Self {
+        self.cases.extend(cases);
+        self
+    }
+
+    pub fn with_outputs(mut self, outputs: Vec<usize>) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
+    pub fn with_penalty(mut self, penalty: f64) -> Self {
+        self.penalty = penalty;
+        self
+    }
+
+    pub fn outputs(&self) -> &[usize] {
+        &self.outputs
+    }
+
+    pub fn errors<'a>(&'a self, program: &'a [Instruction]) -> impl Iterator<Item = f64> + 'a {
+        self.cases.iter().map(move |(inputs, expected)| {
+            match self.interpreter.run(program, inputs) {
+                Ok(registers) => self
+                    .outputs
+                    .iter()
+                    .zip(expected)
+                    .map(|(output, expected)| match registers.get(*output) {
+                        Some(value) => (value - expected).abs(),
+                        None => self.penalty,
+                    })
+                    .sum::<f64>()
+                    .min(self.penalty),
+                Err(_) => self.penalty,
+            }
+        })
+    }
+}
+
+impl<T> Evaluator<T> for Regression
+where
+    T: Individual<Genome: AsRef<[Instruction]>, Fitness: FromIterator<OrderedFloat<f64>>>,
+{
+    type Error = Infallible;
+
+    fn evaluate<Rng>(&self, individual: &T, _: &mut Rng) -> Result<T::Fitness, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        Ok(self
+            .errors(individual.genome().as_ref())
+            .map(|error| OrderedFloat(-error))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use crate::fitness::summed::Summed;
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+    use crate::linear::instruction::{Instruction, InstructionSet, Operand, Operation};
+    use crate::linear::interpreter::Interpreter;
+    use crate::linear::program::Program;
+    use crate::operator::evaluator::Evaluator;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::generator::Generator;
+    use crate::operator::mutator::instruction::{MacroMutation, MicroMutation};
+    use crate::operator::recombinator::linear::LinearCrossover;
+    use crate::operator::selector::lexicase::Lexicase;
+    use crate::operator::selector::Selector;
+
+    use super::Regression;
+
+    type Fitness = Summed<Vec<OrderedFloat<f64>>>;
+
+    fn square() -> Regression {
+        Regression::new(Interpreter::new(2).with_step_limit(64))
+            .with_cases((-3..=3).map(|x| (vec![x as f64], vec![(x * x) as f64])))
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut rng = rand::rng();
+
+        let a = Program::new(vec![Instruction::new(
+            Operation::Mul,
+            0,
+            Operand::Input(0),
+            Operand::Input(0),
+        )])
+        .evaluated::<Fitness>();
+        let b = Program::new(vec![Instruction::new(
+            Operation::Add,
+            0,
+            Operand::Input(0),
+            Operand::Input(0),
+        )])
+        .evaluated::<Fitness>();
+
+        let a = square().evaluate(&a, &mut rng).unwrap();
+        let b = square().evaluate(&b, &mut rng).unwrap();
+
+        assert_eq!(a.value().len(), 7);
+        assert_eq!(a.total(), &OrderedFloat(0.0));
+        assert_eq!(b.value()[0], OrderedFloat(-15.0));
+        assert_eq!(b.total(), &OrderedFloat(-30.0));
+        assert!(a > b);
+    }
+
+    #[test]
+    fn test_evaluate_penalty() {
+        let mut rng = rand::rng();
+
+        let a = Program::new(vec![Instruction::new(
+            Operation::Mul,
+            5,
+            Operand::Input(0),
+            Operand::Input(0),
+        )])
+        .evaluated::<Fitness>();
+
+        let b = square().evaluate(&a, &mut rng).unwrap();
+        let a = square().with_penalty(100.0).evaluate(&a, &mut rng).unwrap();
+
+        assert_eq!(a.total(), &OrderedFloat(-700.0));
+        assert_eq!(b.total(), &OrderedFloat(-7e6));
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let set = InstructionSet::new(2, 1).with_constants(-1.0..1.0, 0.1);
+        let population: Vec<Evaluated<Program, Fitness>> = set
+            .clone()
+            .populate(8)
+            .evaluate(square())
+            .populate(50)
+            .generate(&mut rng)
+            .unwrap();
+
+        let (_, population) = Lexicase
+            .twice()
+            .reproduce(LinearCrossover::new(32))
+            .mutate(MicroMutation::new(set.clone()))
+            .mutate(MacroMutation::new(set, 1..=32))
+            .evaluate(square())
+            .fill()
+            .evolver()
+            .repeat(10)
+            .evolve((0, population), &mut rng)
+            .unwrap();
+
+        assert_eq!(population.len(), 50);
+        assert!(population
+            .iter()
+            .all(|individual| (1..=32).contains(&individual.individual.len())));
+    }
+}
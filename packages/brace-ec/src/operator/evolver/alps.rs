@@ -0,0 +1,354 @@
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::aged::Aged;
+use crate::individual::Individual;
+use crate::operator::generator::Generator;
+use crate::operator::selector::Selector;
+use crate::population::Population;
+
+use super::Evolver;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layers<I> {
+    layers: Vec<Vec<Aged<I>>>,
+    generation: usize,
+}
+
+impl<I> Layers<I> {
+    pub fn new() -> Self {
+        Self {
+            layers: Vec::new(),
+            generation: 0,
+        }
+    }
+
+    pub fn layers(&self) -> &[Vec<Aged<I>>] {
+        &self.layers
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+impl<I> Layers<I>
+where
+    I: Individual,
+{
+    pub fn best(&self) -> Option<&Aged<I>> {
+        self.layers
+            .iter()
+            .flatten()
+            .max_by_key(|individual| individual.fitness())
+    }
+}
+
+impl<I> Default for Layers<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I> Population for Layers<I>
+where
+    I: Individual,
+{
+    type Individual = Aged<I>;
+
+    fn len(&self) -> usize {
+        self.layers.iter().map(Vec::len).sum()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Aging {
+    Linear,
+    #[default]
+    Polynomial,
+    Exponential,
+    Fibonacci,
+}
+
+impl Aging {
+    pub fn limit(&self, gap: usize, layer: usize) -> usize {
+        let n = layer + 1;
+
+        gap * match self {
+            Self::Linear => n,
+            Self::Polynomial => match n {
+                1 => 1,
+                2 => 2,
+                _ => (n - 1) * (n - 1),
+            },
+            Self::Exponential => 1 << layer,
+            Self::Fibonacci => (0..n).fold((1, 1), |(a, b), _| (b, a + b)).0,
+        }
+    }
+}
+
+pub struct Alps<S, R> {
+    selector: S,
+    generator: R,
+    size: usize,
+    layers: usize,
+    gap: usize,
+    aging: Aging,
+    elitism: usize,
+}
+
+impl<S, R> Alps<S, R> {
+    pub fn new(selector: S, generator: R, size: usize) -> Self {
+        Self {
+            selector,
+            generator,
+            size,
+            layers: 5,
+            gap: 10,
+            aging: Aging::default(),
+            elitism: 1,
+        }
+    }
+
+    pub fn with_layers(mut self, layers: usize) -> Self {
+        self.layers = layers;
+        self
+    }
+
+    pub fn with_gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn with_aging(mut self, aging: Aging) -> Self {
+        self.aging = aging;
+        self
+    }
+
+    pub fn with_elitism(mut self, elitism: usize) -> Self {
+        self.elitism = elitism;
+        self
+    }
+
+    pub fn limit(&self, layer: usize) -> usize {
+        match layer + 1 < self.layers {
+            true => self.aging.limit(self.gap, layer),
+            false => usize::MAX,
+        }
+    }
+}
+
+impl<G, I, S, R> Evolver<G> for Alps<S, R>
+where
+    G: Generation<Population = Layers<I>>,
+    I: Individual + Clone,
+    S: Selector<[Aged<I>], Output: IntoIterator<Item = Aged<I>>>,
+    R: Generator<I>,
+{
+    type Error = AlpsError<S::Error, R::Error>;
+
+    fn evolve<Rng>(&self, mut generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.size == 0 || self.elitism >= self.size {
+            return Err(AlpsError::InvalidSize);
+        }
+
+        if self.layers == 0 || self.gap == 0 {
+            return Err(AlpsError::InvalidLayers);
+        }
+
+        let population = generation.population_mut();
+        let now = population.generation;
+
+        if population.layers.is_empty() || now.is_multiple_of(self.gap) {
+            let seeded = (0..self.size)
+                .map(|_| {
+                    self.generator
+                        .generate(rng)
+                        .map(|individual| Aged::new(individual, now))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(AlpsError::Generate)?;
+
+            match population.layers.first_mut() {
+                Some(layer) => *layer = seeded,
+                None => population.layers.push(seeded),
+            }
+        }
+
+        let mut layers = Vec::with_capacity(population.layers.len() + 1);
+
+        for (index, layer) in population.layers.iter().enumerate() {
+            let mut pool = layer.clone();
+
+            if let Some(below) = index.checked_sub(1).map(|below| &population.layers[below]) {
+                pool.extend(below.iter().cloned());
+            }
+
+            let mut next = elites(layer, self.elitism);
+
+            while !pool.is_empty() && next.len() < self.size {
+                let offspring = self
+                    .selector
+                    .select(&pool, rng)
+                    .map_err(AlpsError::Select)?;
+
+                next.extend(offspring);
+            }
+
+            next.truncate(self.size);
+            layers.push(next);
+        }
+
+        for index in 0..layers.len() {
+            let limit = self.limit(index);
+
+            let (young, old) = std::mem::take(&mut layers[index])
+                .into_iter()
+                .partition::<Vec<_>, _>(|individual| individual.age(now + 1) < limit);
+
+            layers[index] = young;
+
+            if old.is_empty() {
+                continue;
+            }
+
+            if index + 1 == layers.len() {
+                layers.push(Vec::with_capacity(self.size));
+            }
+
+            let above = &mut layers[index + 1];
+
+            above.extend(old);
+
+            if above.len() > self.size {
+                above.sort_by(|a, b| b.fitness().cmp(a.fitness()));
+                above.truncate(self.size);
+            }
+        }
+
+        population.layers = layers;
+        population.generation += 1;
+
+        Ok(generation.advanced())
+    }
+}
+
+fn elites<I>(layer: &[Aged<I>], count: usize) -> Vec<Aged<I>>
+where
+    I: Individual + Clone,
+{
+    let mut sorted = layer.iter().collect::<Vec<_>>();
+
+    sorted.sort_by(|a, b| b.fitness().cmp(a.fitness()));
+    sorted.into_iter().take(count).cloned().collect()
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AlpsError<S, G> {
+    #[error("layer size must be greater than the number of elites")]
+    InvalidSize,
+    #[error("layer count and age gap must be greater than zero")]
+    InvalidLayers,
+    #[error(transparent)]
+    Select(S),
+    #[error(transparent)]
+    Generate(G),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::aged::Aged;
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::generator::Generator;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::Selector;
+    use crate::population::Population;
+
+    use super::{Aging, Alps, AlpsError, Layers};
+
+    struct Zero;
+
+    impl Generator<Evaluated<i32, i32>> for Zero {
+        type Error = Infallible;
+
+        fn generate<Rng>(&self, _: &mut Rng) -> Result<Evaluated<i32, i32>, Self::Error>
+        where
+            Rng: rand::Rng + ?Sized,
+        {
+            Ok(Evaluated::new(0, -100))
+        }
+    }
+
+    fn evaluate(individual: &Aged<Evaluated<i32, i32>>) -> Result<i32, Infallible> {
+        Ok(-(individual.individual.individual - 100).abs())
+    }
+
+    #[test]
+    fn test_limit() {
+        let limits = |aging: Aging| {
+            (0..5)
+                .map(|layer| aging.limit(10, layer))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(limits(Aging::Linear), [10, 20, 30, 40, 50]);
+        assert_eq!(limits(Aging::Polynomial), [10, 20, 40, 90, 160]);
+        assert_eq!(limits(Aging::Exponential), [10, 20, 40, 80, 160]);
+        assert_eq!(limits(Aging::Fibonacci), [10, 20, 30, 50, 80]);
+        assert_eq!(Alps::new((), (), 10).with_layers(3).limit(2), usize::MAX);
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let evolver = Alps::new(
+            Tournament::binary()
+                .mutate(Noise::new(-2..=4))
+                .evaluate_with(evaluate),
+            Zero,
+            10,
+        )
+        .with_layers(4)
+        .with_gap(5);
+
+        let mut generation = (0, Layers::new());
+
+        for _ in 0..200 {
+            generation = evolver.evolve(generation, &mut rng).unwrap();
+        }
+
+        let (id, layers) = generation;
+
+        assert_eq!(id, 200);
+        assert_eq!(layers.generation(), 200);
+        assert!(layers.layers().len() > 1 && layers.layers().len() <= 4);
+        assert!(layers.layers().iter().all(|layer| layer.len() <= 10));
+        assert!(layers.layers()[0]
+            .iter()
+            .all(|individual| individual.age(200) < 5));
+        assert!(layers.len() > 10);
+        assert!(layers.best().unwrap().individual.fitness > -5);
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a =
+            Alps::new(Tournament::binary().fill(), Zero, 1).evolve((0, Layers::new()), &mut rng);
+        let b = Alps::new(Tournament::binary().fill(), Zero, 10)
+            .with_gap(0)
+            .evolve((0, Layers::new()), &mut rng);
+
+        assert!(matches!(a, Err(AlpsError::InvalidSize)));
+        assert!(matches!(b, Err(AlpsError::InvalidLayers)));
+    }
+}
@@ -0,0 +1,222 @@
+use rand::seq::SliceRandom;
+
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::selector::Selector;
+use crate::population::grid::{Adjacency, Grid};
+use crate::population::Population;
+
+use super::Evolver;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Update {
+    #[default]
+    Synchronous,
+    LineSweep,
+    RandomSweep,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Replacement {
+    Always,
+    #[default]
+    IfNotWorse,
+}
+
+pub struct Cellular<S> {
+    selector: S,
+    adjacency: Adjacency,
+    update: Update,
+    replacement: Replacement,
+}
+
+impl<S> Cellular<S> {
+    pub fn new(selector: S, adjacency: Adjacency) -> Self {
+        Self {
+            selector,
+            adjacency,
+            update: Update::default(),
+            replacement: Replacement::default(),
+        }
+    }
+
+    pub fn with_update(mut self, update: Update) -> Self {
+        self.update = update;
+        self
+    }
+
+    pub fn with_replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    fn replace<T>(&self, cell: &mut T, offspring: Option<T>)
+    where
+        T: Individual,
+    {
+        if let Some(offspring) = offspring {
+            if self.replacement == Replacement::Always || offspring.fitness() >= cell.fitness() {
+                *cell = offspring;
+            }
+        }
+    }
+}
+
+impl<G, S, T> Evolver<G> for Cellular<S>
+where
+    G: Generation<Population = Grid<T>>,
+    S: Selector<[T], Output: IntoIterator<Item = T>>,
+    T: Individual + Clone,
+{
+    type Error = S::Error;
+
+    fn evolve<Rng>(&self, mut generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let grid = generation.population_mut();
+
+        match self.update {
+            Update::Synchronous => {
+                let offspring = (0..grid.len())
+                    .map(|index| {
+                        self.selector
+                            .select(&grid.neighbourhood(index, self.adjacency), rng)
+                            .map(|output| output.into_iter().next())
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                for (cell, offspring) in grid.cells_mut().iter_mut().zip(offspring) {
+                    self.replace(cell, offspring);
+                }
+            }
+            Update::LineSweep | Update::RandomSweep => {
+                let mut order = (0..grid.len()).collect::<Vec<_>>();
+
+                if self.update == Update::RandomSweep {
+                    order.shuffle(rng);
+                }
+
+                for index in order {
+                    let offspring = self
+                        .selector
+                        .select(&grid.neighbourhood(index, self.adjacency), rng)?
+                        .into_iter()
+                        .next();
+
+                    self.replace(&mut grid.cells_mut()[index], offspring);
+                }
+            }
+        }
+
+        Ok(generation.advanced())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::repeat::Repeat;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::worst::Worst;
+    use crate::operator::selector::Selector;
+    use crate::population::grid::{Adjacency, Grid};
+    use crate::population::Population;
+
+    use super::{Cellular, Replacement, Update};
+
+    fn grid() -> (usize, Grid<i32>) {
+        (0, Grid::new(3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap())
+    }
+
+    fn row() -> (usize, Grid<i32>) {
+        (0, Grid::new(5, vec![1, 2, 3, 4, 5]).unwrap())
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let a = Cellular::new(Best, Adjacency::von_neumann())
+            .evolve(grid(), &mut rng)
+            .unwrap();
+        let b = Cellular::new(Best, Adjacency::von_neumann())
+            .evolve(row(), &mut rng)
+            .unwrap();
+        let c = Cellular::new(Best, Adjacency::von_neumann())
+            .with_update(Update::LineSweep)
+            .evolve(row(), &mut rng)
+            .unwrap();
+        let d = Cellular::new(Best, Adjacency::von_neumann())
+            .with_update(Update::RandomSweep)
+            .evolve(row(), &mut rng)
+            .unwrap();
+
+        assert_eq!(a.0, 1);
+        assert_eq!(a.1.cells(), [7, 8, 9, 7, 8, 9, 9, 9, 9]);
+        assert_eq!(b.1.cells(), [5, 3, 4, 5, 5]);
+        assert_eq!(c.1.cells(), [5, 5, 5, 5, 5]);
+        assert!(d.1.cells().iter().zip(b.1.cells()).all(|(d, b)| d >= b));
+    }
+
+    #[test]
+    fn test_evolve_replacement() {
+        let mut rng = rand::rng();
+
+        let a = Cellular::new(Worst, Adjacency::moore())
+            .evolve(grid(), &mut rng)
+            .unwrap();
+        let b = Cellular::new(Worst, Adjacency::moore())
+            .with_replacement(Replacement::Always)
+            .evolve(grid(), &mut rng)
+            .unwrap();
+        let c = Cellular::new(Worst.mutate(Add(1)), Adjacency::VonNeumann(0))
+            .evolve(grid(), &mut rng)
+            .unwrap();
+
+        assert_eq!(a.1, grid().1);
+        assert_eq!(b.1.cells(), [1; 9]);
+        assert_eq!(c.1.cells(), [2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_evolve_repeat() {
+        let mut rng = rand::rng();
+
+        let cells = (-32..32)
+            .map(|genome: i32| Evaluated::new(genome, -genome.abs()))
+            .collect::<Vec<_>>();
+
+        let evolver = Cellular::new(
+            Tournament::binary()
+                .mutate(Noise::new(1..=3).rate(0.5))
+                .evaluate_with(|individual: &Evaluated<i32, i32>| {
+                    Ok::<_, Infallible>(-individual.genome().abs())
+                }),
+            Adjacency::moore(),
+        )
+        .with_update(Update::RandomSweep);
+
+        let (generation, grid) = Repeat::new(evolver, 20)
+            .evolve((0, Grid::new(8, cells.clone()).unwrap()), &mut rng)
+            .unwrap();
+
+        assert_eq!(generation, 20);
+        assert_eq!(grid.len(), 64);
+        assert!(grid
+            .cells()
+            .iter()
+            .zip(cells)
+            .all(
+                |(next, prev)| next.fitness == -next.genome().abs() && next.fitness >= prev.fitness
+            ));
+    }
+}
@@ -0,0 +1,78 @@
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::schedule::Clock;
+
+use super::Evolver;
+
+pub struct Clocked<E> {
+    evolver: E,
+    clock: Clock,
+}
+
+impl<E> Clocked<E> {
+    pub fn new(evolver: E, clock: Clock) -> Self {
+        Self { evolver, clock }
+    }
+}
+
+impl<G, E> Evolver<G> for Clocked<E>
+where
+    G: Generation<Id: Clone + TryInto<u64>>,
+    E: Evolver<G>,
+{
+    type Error = ClockedError<E::Error>;
+
+    fn evolve<Rng>(&self, generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let step = generation
+            .id()
+            .clone()
+            .try_into()
+            .map_err(|_| ClockedError::InvalidId)?;
+
+        self.clock.set(step);
+        self.evolver
+            .evolve(generation, rng)
+            .map_err(ClockedError::Evolve)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ClockedError<E> {
+    #[error("generation id does not fit the clock")]
+    InvalidId,
+    #[error(transparent)]
+    Evolve(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::evolver::Evolver;
+    use crate::operator::selector::first::First;
+    use crate::operator::selector::Selector;
+    use crate::schedule::Clock;
+
+    use super::ClockedError;
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+        let clock = Clock::new();
+
+        let evolver = First.fill().evolver().clocked(clock.clone()).repeat(4);
+
+        let (id, population) = evolver.evolve((0, [1, 2]), &mut rng).unwrap();
+
+        assert_eq!(id, 4);
+        assert_eq!(population, [1, 1]);
+        assert_eq!(clock.get(), 3);
+        assert!(matches!(
+            evolver.evolve((-1, [0, 0]), &mut rng),
+            Err(ClockedError::InvalidId)
+        ));
+        assert_eq!(clock.get(), 3);
+    }
+}
@@ -0,0 +1,707 @@
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::evaluator::Evaluator;
+use crate::util::random::normal;
+
+use super::Evolver;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Distribution {
+    mean: Vec<f64>,
+    sigma: f64,
+    covariance: Vec<Vec<f64>>,
+    basis: Vec<Vec<f64>>,
+    scales: Vec<f64>,
+    path_sigma: Vec<f64>,
+    path_covariance: Vec<f64>,
+}
+
+impl Distribution {
+    pub fn new(mean: Vec<f64>, sigma: f64) -> Self {
+        let len = mean.len();
+
+        Self {
+            mean,
+            sigma,
+            covariance: identity(len),
+            basis: identity(len),
+            scales: vec![1.0; len],
+            path_sigma: vec![0.0; len],
+            path_covariance: vec![0.0; len],
+        }
+    }
+
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    pub fn covariance(&self) -> &[Vec<f64>] {
+        &self.covariance
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.mean.len()
+    }
+
+    pub fn condition(&self) -> f64 {
+        let max = self.scales.iter().copied().fold(0.0, f64::max);
+        let min = self.scales.iter().copied().fold(f64::INFINITY, f64::min);
+
+        (max / min).powi(2)
+    }
+
+    fn sample<Rng>(&self, rng: &mut Rng) -> Vec<f64>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let scaled = self
+            .scales
+            .iter()
+            .map(|scale| scale * normal(rng, 0.0, 1.0))
+            .collect::<Vec<_>>();
+
+        self.basis
+            .iter()
+            .map(|row| row.iter().zip(&scaled).map(|(b, z)| b * z).sum::<f64>())
+            .collect()
+    }
+
+    fn whiten(&self, step: &[f64]) -> Vec<f64> {
+        let projected = (0..step.len())
+            .map(|column| {
+                let value = self
+                    .basis
+                    .iter()
+                    .zip(step)
+                    .map(|(row, y)| row[column] * y)
+                    .sum::<f64>();
+
+                value / self.scales[column]
+            })
+            .collect::<Vec<_>>();
+
+        self.basis
+            .iter()
+            .map(|row| row.iter().zip(&projected).map(|(b, z)| b * z).sum::<f64>())
+            .collect()
+    }
+
+    fn decompose(&mut self) {
+        let (values, vectors) = eigen(&self.covariance);
+        let floor = values.iter().copied().fold(0.0, f64::max) * f64::EPSILON;
+
+        self.scales = values
+            .into_iter()
+            .map(|value| value.max(floor).max(f64::MIN_POSITIVE).sqrt())
+            .collect();
+        self.basis = vectors;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CmaGeneration<I> {
+    id: usize,
+    population: Vec<I>,
+    distribution: Distribution,
+    origin: Vec<f64>,
+    initial: f64,
+    iteration: usize,
+    restarts: usize,
+    best: Option<I>,
+}
+
+impl<I> CmaGeneration<I> {
+    pub fn new(mean: Vec<f64>, sigma: f64) -> Self {
+        Self {
+            id: 0,
+            population: Vec::new(),
+            distribution: Distribution::new(mean.clone(), sigma),
+            origin: mean,
+            initial: sigma,
+            iteration: 0,
+            restarts: 0,
+            best: None,
+        }
+    }
+
+    pub fn distribution(&self) -> &Distribution {
+        &self.distribution
+    }
+
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    pub fn best(&self) -> Option<&I> {
+        self.best.as_ref()
+    }
+}
+
+impl<I> Generation for CmaGeneration<I>
+where
+    I: Individual,
+{
+    type Id = usize;
+    type Population = Vec<I>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn population(&self) -> &Self::Population {
+        &self.population
+    }
+
+    fn population_mut(&mut self) -> &mut Self::Population {
+        &mut self.population
+    }
+
+    fn advance(&mut self) {
+        self.id += 1;
+    }
+}
+
+pub struct CmaEs<E> {
+    evaluator: E,
+    bounds: Option<Vec<RangeInclusive<f64>>>,
+    size: Option<usize>,
+    restarts: usize,
+    growth: usize,
+    tolerance: f64,
+    condition: f64,
+}
+
+impl<E> CmaEs<E> {
+    pub fn new(evaluator: E) -> Self {
+        Self {
+            evaluator,
+            bounds: None,
+            size: None,
+            restarts: 0,
+            growth: 2,
+            tolerance: 1e-12,
+            condition: 1e14,
+        }
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<RangeInclusive<f64>>) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    pub fn with_population_size(mut self, size: usize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn with_restarts(mut self, restarts: usize) -> Self {
+        self.restarts = restarts;
+        self
+    }
+
+    pub fn with_population_growth(mut self, growth: usize) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn with_condition_limit(mut self, condition: f64) -> Self {
+        self.condition = condition;
+        self
+    }
+
+    pub fn population_size(&self, dimension: usize, restarts: usize) -> usize {
+        let size = self
+            .size
+            .unwrap_or_else(|| 4 + (3.0 * (dimension as f64).ln()).floor() as usize);
+
+        size.saturating_mul(self.growth.saturating_pow(restarts as u32))
+    }
+
+    fn validate<T>(&self, distribution: &Distribution) -> Result<(), CmaError<T>> {
+        if distribution.mean.is_empty() {
+            return Err(CmaError::InvalidDimension);
+        }
+
+        if !distribution.sigma.is_finite() || distribution.sigma <= 0.0 {
+            return Err(CmaError::InvalidSigma);
+        }
+
+        if matches!(self.size, Some(size) if size < 2) {
+            return Err(CmaError::NotEnough);
+        }
+
+        if let Some(bounds) = &self.bounds {
+            if bounds.len() != distribution.mean.len() {
+                return Err(CmaError::InvalidDimension);
+            }
+
+            if bounds.iter().any(|bound| bound.is_empty()) {
+                return Err(CmaError::InvalidBounds);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clamp(&self, point: &mut [f64]) {
+        if let Some(bounds) = &self.bounds {
+            for (value, bound) in point.iter_mut().zip(bounds) {
+                *value = value.clamp(*bound.start(), *bound.end());
+            }
+        }
+    }
+
+    fn restart<I, Rng>(&self, generation: &mut CmaGeneration<I>, rng: &mut Rng)
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let mean = match &self.bounds {
+            Some(bounds) => bounds
+                .iter()
+                .map(|bound| rng.random_range(bound.clone()))
+                .collect(),
+            None => generation.origin.clone(),
+        };
+
+        generation.distribution = Distribution::new(mean, generation.initial);
+        generation.iteration = 0;
+        generation.restarts += 1;
+    }
+}
+
+impl<I, E> Evolver<CmaGeneration<I>> for CmaEs<E>
+where
+    I: Individual + From<Vec<f64>> + Clone,
+    E: Evaluator<I>,
+{
+    type Error = CmaError<E::Error>;
+
+    fn evolve<Rng>(
+        &self,
+        mut generation: CmaGeneration<I>,
+        rng: &mut Rng,
+    ) -> Result<CmaGeneration<I>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.validate(&generation.distribution)?;
+
+        let dimension = generation.distribution.dimension();
+        let size = self.population_size(dimension, generation.restarts);
+        let parents = size / 2;
+
+        let weights = (0..parents)
+            .map(|rank| (parents as f64 + 0.5).ln() - (rank as f64 + 1.0).ln())
+            .collect::<Vec<_>>();
+        let total = weights.iter().sum::<f64>();
+        let weights = weights
+            .iter()
+            .map(|weight| weight / total)
+            .collect::<Vec<_>>();
+        let effective = 1.0 / weights.iter().map(|weight| weight * weight).sum::<f64>();
+
+        let n = dimension as f64;
+        let cc = (4.0 + effective / n) / (n + 4.0 + 2.0 * effective / n);
+        let cs = (effective + 2.0) / (n + effective + 5.0);
+        let c1 = 2.0 / ((n + 1.3).powi(2) + effective);
+        let cmu = (1.0 - c1)
+            .min(2.0 * (effective - 2.0 + 1.0 / effective) / ((n + 2.0).powi(2) + effective));
+        let damps = 1.0 + 2.0 * (((effective - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        let distribution = &generation.distribution;
+        let mut offspring = Vec::with_capacity(size);
+
+        for _ in 0..size {
+            let mut point = distribution
+                .mean
+                .iter()
+                .zip(distribution.sample(rng))
+                .map(|(mean, step)| mean + distribution.sigma * step)
+                .collect::<Vec<_>>();
+
+            self.clamp(&mut point);
+
+            let mut individual = I::from(point.clone());
+
+            *individual.fitness_mut() = self
+                .evaluator
+                .evaluate(&individual, rng)
+                .map_err(CmaError::Evaluate)?;
+
+            offspring.push((point, individual));
+        }
+
+        offspring.sort_by(|(_, a), (_, b)| b.fitness().cmp(a.fitness()));
+
+        let distribution = &mut generation.distribution;
+        let previous = distribution.mean.clone();
+        let sigma = distribution.sigma;
+
+        let steps = offspring
+            .iter()
+            .take(parents)
+            .map(|(point, _)| {
+                point
+                    .iter()
+                    .zip(&previous)
+                    .map(|(x, m)| (x - m) / sigma)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let step = (0..dimension)
+            .map(|index| {
+                steps
+                    .iter()
+                    .zip(&weights)
+                    .map(|(step, weight)| weight * step[index])
+                    .sum::<f64>()
+            })
+            .collect::<Vec<_>>();
+
+        distribution.mean = previous
+            .iter()
+            .zip(&step)
+            .map(|(mean, step)| mean + sigma * step)
+            .collect();
+
+        self.clamp(&mut distribution.mean);
+
+        let whitened = distribution.whiten(&step);
+        let scale = (cs * (2.0 - cs) * effective).sqrt();
+
+        for (path, value) in distribution.path_sigma.iter_mut().zip(&whitened) {
+            *path = (1.0 - cs) * *path + scale * value;
+        }
+
+        let norm = distribution
+            .path_sigma
+            .iter()
+            .map(|x| x * x)
+            .sum::<f64>()
+            .sqrt();
+        let decay = 1.0 - (1.0 - cs).powi(2 * (generation.iteration as i32 + 1));
+        let stalled = norm / decay.sqrt() / chi >= 1.4 + 2.0 / (n + 1.0);
+        let hsig = if stalled { 0.0 } else { 1.0 };
+        let scale = hsig * (cc * (2.0 - cc) * effective).sqrt();
+
+        for (path, value) in distribution.path_covariance.iter_mut().zip(&step) {
+            *path = (1.0 - cc) * *path + scale * value;
+        }
+
+        let correction = (1.0 - hsig) * cc * (2.0 - cc);
+        let path = &distribution.path_covariance;
+
+        for (row, values) in distribution.covariance.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                let rank_one = path[row] * path[column] + correction * *value;
+                let rank_mu = steps
+                    .iter()
+                    .zip(&weights)
+                    .map(|(step, weight)| weight * step[row] * step[column])
+                    .sum::<f64>();
+
+                *value = (1.0 - c1 - cmu) * *value + c1 * rank_one + cmu * rank_mu;
+            }
+        }
+
+        distribution.sigma *= ((cs / damps) * (norm / chi - 1.0)).exp();
+        distribution.decompose();
+
+        generation.iteration += 1;
+
+        let [(_, first), ..] = offspring.as_slice() else {
+            return Err(CmaError::NotEnough);
+        };
+
+        if !matches!(&generation.best, Some(best) if best.fitness() >= first.fitness()) {
+            generation.best = Some(first.clone());
+        }
+
+        let flat = offspring[0].1.fitness() == offspring[(size * 7).div_ceil(10) - 1].1.fitness();
+        let distribution = &generation.distribution;
+        let spread = distribution.sigma * distribution.scales.iter().copied().fold(0.0, f64::max);
+
+        if generation.restarts < self.restarts
+            && (flat
+                || !spread.is_finite()
+                || spread < self.tolerance
+                || distribution.condition() > self.condition)
+        {
+            self.restart(&mut generation, rng);
+        }
+
+        Ok(generation.advanced_with(
+            offspring
+                .into_iter()
+                .map(|(_, individual)| individual)
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+fn identity(len: usize) -> Vec<Vec<f64>> {
+    (0..len)
+        .map(|row| {
+            (0..len)
+                .map(|column| (row == column) as u8 as f64)
+                .collect()
+        })
+        .collect()
+}
+
+fn eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let len = matrix.len();
+    let mut a = (0..len)
+        .map(|row| {
+            (0..len)
+                .map(|column| (matrix[row][column] + matrix[column][row]) / 2.0)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut v = identity(len);
+
+    for _ in 0..64 {
+        let off = (0..len)
+            .flat_map(|row| (0..len).map(move |column| (row, column)))
+            .filter(|(row, column)| row != column)
+            .map(|(row, column)| a[row][column].powi(2))
+            .sum::<f64>();
+
+        if off < f64::MIN_POSITIVE {
+            break;
+        }
+
+        for p in 0..len {
+            for q in p + 1..len {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+
+                let (upper, lower) = a.split_at_mut(q);
+
+                for (pk, qk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    (*pk, *qk) = (c * *pk - s * *qk, s * *pk + c * *qk);
+                }
+
+                for row in v.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+            }
+        }
+    }
+
+    ((0..len).map(|index| a[index][index]).collect(), v)
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CmaError<E> {
+    #[error("not enough individuals")]
+    NotEnough,
+    #[error("invalid dimension")]
+    InvalidDimension,
+    #[error("invalid step size")]
+    InvalidSigma,
+    #[error("invalid bounds")]
+    InvalidBounds,
+    #[error(transparent)]
+    Evaluate(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+    use std::convert::Infallible;
+
+    use ordered_float::OrderedFloat;
+
+    use crate::generation::Generation;
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+    use crate::operator::evaluator::function::Function;
+    use crate::operator::evolver::Evolver;
+
+    use super::{eigen, CmaError, CmaEs, CmaGeneration, Distribution};
+
+    type Point = Evaluated<Vec<f64>, Reverse<OrderedFloat<f64>>>;
+
+    fn sphere(individual: &Point) -> Result<Reverse<OrderedFloat<f64>>, Infallible> {
+        Ok(Reverse(OrderedFloat(
+            individual.genome().iter().map(|x| (x - 1.0).powi(2)).sum(),
+        )))
+    }
+
+    fn ellipsoid(individual: &Point) -> Result<Reverse<OrderedFloat<f64>>, Infallible> {
+        Ok(Reverse(OrderedFloat(
+            individual
+                .genome()
+                .iter()
+                .enumerate()
+                .map(|(index, x)| 1000f64.powi(index as i32) * x * x)
+                .sum(),
+        )))
+    }
+
+    #[test]
+    fn test_whiten_degenerate() {
+        let mut distribution = Distribution::new(vec![0.0; 2], 1.0);
+
+        distribution.covariance = vec![vec![1.0, 1.0], vec![1.0, 1.0]];
+        distribution.decompose();
+
+        assert!(distribution.scales.iter().all(|scale| *scale > 0.0));
+        assert!(distribution
+            .whiten(&[1.0, -1.0])
+            .iter()
+            .all(|value| value.is_finite()));
+    }
+
+    fn run<E>(evolver: E, generation: CmaGeneration<Point>, count: usize) -> CmaGeneration<Point>
+    where
+        E: Evolver<CmaGeneration<Point>, Error: std::fmt::Debug>,
+    {
+        let mut rng = rand::rng();
+
+        (0..count).fold(generation, |generation, _| {
+            evolver.evolve(generation, &mut rng).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_eigen() {
+        let (values, vectors) = eigen(&[vec![2.0, 1.0], vec![1.0, 2.0]]);
+
+        let mut sorted = values.clone();
+
+        sorted.sort_by(f64::total_cmp);
+
+        assert!((sorted[0] - 1.0).abs() < 1e-12);
+        assert!((sorted[1] - 3.0).abs() < 1e-12);
+
+        for (index, value) in values.iter().enumerate() {
+            let x = vectors[0][index];
+            let y = vectors[1][index];
+
+            assert!((2.0 * x + y - value * x).abs() < 1e-12);
+            assert!((x + 2.0 * y - value * y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_evolve() {
+        let generation = run(
+            CmaEs::new(Function::new(sphere)),
+            CmaGeneration::new(vec![5.0; 5], 2.0),
+            300,
+        );
+
+        assert_eq!(generation.id(), &300);
+        assert_eq!(generation.population().len(), 8);
+        assert!(generation.best().unwrap().fitness.0 .0 < 1e-10);
+        assert!(generation
+            .distribution()
+            .mean()
+            .iter()
+            .all(|x| (x - 1.0).abs() < 1e-4));
+    }
+
+    #[test]
+    fn test_evolve_ill_conditioned() {
+        let generation = run(
+            CmaEs::new(Function::new(ellipsoid)),
+            CmaGeneration::new(vec![1.0; 4], 1.0),
+            1000,
+        );
+
+        assert!(generation.best().unwrap().fitness.0 .0 < 1e-8);
+        assert!(generation.distribution().condition() > 1e4);
+    }
+
+    #[test]
+    fn test_evolve_bounds() {
+        let generation = run(
+            CmaEs::new(Function::new(sphere)).with_bounds(vec![-2.0..=0.5, 2.0..=3.0]),
+            CmaGeneration::new(vec![0.0, 2.5], 1.0),
+            200,
+        );
+
+        assert!(generation.population().iter().all(|individual| {
+            (-2.0..=0.5).contains(&individual.genome()[0])
+                && (2.0..=3.0).contains(&individual.genome()[1])
+        }));
+
+        let best = generation.best().unwrap().genome();
+
+        assert!((best[0] - 0.5).abs() < 1e-6);
+        assert!((best[1] - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_evolve_restarts() {
+        let generation = run(
+            CmaEs::new(Function::new(sphere))
+                .with_restarts(2)
+                .with_tolerance(1e-3),
+            CmaGeneration::new(vec![0.0; 2], 1.0),
+            500,
+        );
+
+        assert_eq!(generation.restarts(), 2);
+        assert_eq!(generation.population().len(), 24);
+        assert!(generation.best().unwrap().fitness.0 .0 < 1e-6);
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let evolver = CmaEs::new(Function::new(sphere));
+
+        let a = evolver.evolve(CmaGeneration::<Point>::new(vec![], 1.0), &mut rng);
+        let b = evolver.evolve(CmaGeneration::<Point>::new(vec![0.0], 0.0), &mut rng);
+        let c = CmaEs::new(Function::new(sphere))
+            .with_bounds(vec![0.0..=1.0])
+            .evolve(CmaGeneration::<Point>::new(vec![0.0, 0.0], 1.0), &mut rng);
+        let d = CmaEs::new(Function::new(sphere))
+            .with_bounds(vec![1.0..=0.0])
+            .evolve(CmaGeneration::<Point>::new(vec![0.0], 1.0), &mut rng);
+        let e = CmaEs::new(Function::new(sphere))
+            .with_population_size(1)
+            .evolve(CmaGeneration::<Point>::new(vec![0.0], 1.0), &mut rng);
+
+        assert_eq!(a.err(), Some(CmaError::InvalidDimension));
+        assert_eq!(b.err(), Some(CmaError::InvalidSigma));
+        assert_eq!(c.err(), Some(CmaError::InvalidDimension));
+        assert_eq!(d.err(), Some(CmaError::InvalidBounds));
+        assert_eq!(e.err(), Some(CmaError::NotEnough));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::eda::{EdaError, EdaGeneration};
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::evaluator::Evaluator;
+use crate::util::iter::TryFromIterator;
+
+use super::Evolver;
+
+pub struct Compact<E> {
+    evaluator: E,
+    size: usize,
+}
+
+impl<E> Compact<E> {
+    pub fn new(evaluator: E, size: usize) -> Self {
+        Self { evaluator, size }
+    }
+}
+
+impl<I, E> Evolver<EdaGeneration<I>> for Compact<E>
+where
+    I: Individual<Genome: AsRef<[bool]>> + TryFromIterator<bool> + Clone,
+    E: Evaluator<I>,
+{
+    type Error = EdaError<E::Error>;
+
+    fn evolve<Rng>(
+        &self,
+        mut generation: EdaGeneration<I>,
+        rng: &mut Rng,
+    ) -> Result<EdaGeneration<I>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if generation.model().is_empty() {
+            return Err(EdaError::Empty);
+        }
+
+        if self.size == 0 {
+            return Err(EdaError::InvalidSize);
+        }
+
+        let mut population = generation.sample(&self.evaluator, 2, rng)?;
+
+        if population[1].fitness() > population[0].fitness() {
+            population.swap(0, 1);
+        }
+
+        let model = generation.model_mut();
+        let winner = population[0].genome().as_ref();
+        let loser = population[1].genome().as_ref();
+
+        for (index, (winner, loser)) in winner.iter().zip(loser).enumerate() {
+            if winner != loser {
+                model.step(index, *winner, 1.0 / self.size as f64);
+            }
+        }
+
+        generation.record(population);
+
+        Ok(generation.advanced())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eda::{EdaError, EdaGeneration, Model};
+    use crate::generation::Generation;
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evaluator::count::Count;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::repeat::Repeat;
+
+    use super::Compact;
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let generation = Repeat::new(Compact::new(Count::new::<usize>(true), 50), 2000)
+            .evolve(
+                EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(16)),
+                &mut rng,
+            )
+            .unwrap();
+
+        assert_eq!(generation.id(), &2000);
+        assert_eq!(generation.population().len(), 2);
+        assert_eq!(generation.best().unwrap().fitness, 16);
+        assert!(generation.model().is_converged());
+        assert_eq!(generation.model().mode(), [true; 16]);
+    }
+
+    #[test]
+    fn test_evolve_step() {
+        let mut rng = rand::rng();
+
+        let generation = Compact::new(Count::new::<usize>(true), 4)
+            .evolve(
+                EdaGeneration::<Evaluated<[bool; 4], usize>>::new(Model::new(4)),
+                &mut rng,
+            )
+            .unwrap();
+
+        let [winner, loser] = [&generation.population()[0], &generation.population()[1]];
+
+        assert!(winner.fitness >= loser.fitness);
+
+        for (index, probability) in generation.model().probabilities().iter().enumerate() {
+            match (winner.individual[index], loser.individual[index]) {
+                (true, false) => assert_eq!(*probability, 0.75),
+                (false, true) => assert_eq!(*probability, 0.25),
+                _ => assert_eq!(*probability, 0.5),
+            }
+        }
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = Compact::new(Count::new::<usize>(true), 0).evolve(
+            EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(4)),
+            &mut rng,
+        );
+        let b = Compact::new(Count::new::<usize>(true), 10).evolve(
+            EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(0)),
+            &mut rng,
+        );
+
+        assert!(matches!(a, Err(EdaError::InvalidSize)));
+        assert!(matches!(b, Err(EdaError::Empty)));
+    }
+}
@@ -0,0 +1,504 @@
+use rand::seq::IndexedRandom;
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::selector::Selector;
+use crate::population::Population;
+
+use super::Evolver;
+
+pub trait Contest<H, P> {
+    type Error;
+
+    fn compete(&self, host: &H, parasite: &P) -> Result<f64, Self::Error>;
+}
+
+impl<H, P, F, E> Contest<H, P> for F
+where
+    F: Fn(&H, &P) -> Result<f64, E>,
+{
+    type Error = E;
+
+    fn compete(&self, host: &H, parasite: &P) -> Result<f64, Self::Error> {
+        self(host, parasite)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Sampling {
+    #[default]
+    RoundRobin,
+    Random(usize),
+    Shared(usize),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Arena<H, P> {
+    hosts: Vec<H>,
+    parasites: Vec<P>,
+    host_fame: Vec<H>,
+    parasite_fame: Vec<P>,
+    host_samples: Vec<H>,
+    parasite_samples: Vec<P>,
+}
+
+impl<H, P> Arena<H, P> {
+    pub fn new(hosts: Vec<H>, parasites: Vec<P>) -> Self {
+        Self {
+            hosts,
+            parasites,
+            host_fame: Vec::new(),
+            parasite_fame: Vec::new(),
+            host_samples: Vec::new(),
+            parasite_samples: Vec::new(),
+        }
+    }
+
+    pub fn hosts(&self) -> &[H] {
+        &self.hosts
+    }
+
+    pub fn parasites(&self) -> &[P] {
+        &self.parasites
+    }
+
+    pub fn host_hall_of_fame(&self) -> &[H] {
+        &self.host_fame
+    }
+
+    pub fn parasite_hall_of_fame(&self) -> &[P] {
+        &self.parasite_fame
+    }
+}
+
+impl<H, P> Population for Arena<H, P>
+where
+    H: Individual,
+{
+    type Individual = H;
+
+    fn len(&self) -> usize {
+        self.hosts.len()
+    }
+}
+
+pub struct Competitive<A, B, C> {
+    hosts: A,
+    parasites: B,
+    contest: C,
+    sampling: Sampling,
+    fame: usize,
+    fame_sample: usize,
+}
+
+impl<A, B, C> Competitive<A, B, C> {
+    pub fn new(hosts: A, parasites: B, contest: C) -> Self {
+        Self {
+            hosts,
+            parasites,
+            contest,
+            sampling: Sampling::default(),
+            fame: 0,
+            fame_sample: 0,
+        }
+    }
+
+    pub fn with_sampling(mut self, sampling: Sampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    pub fn with_hall_of_fame(mut self, capacity: usize, sample: usize) -> Self {
+        self.fame = capacity;
+        self.fame_sample = sample;
+        self
+    }
+
+    pub fn compete<H, P, Rng>(&self, arena: &mut Arena<H, P>, rng: &mut Rng) -> Result<(), C::Error>
+    where
+        H: Individual<Fitness: From<f64>> + Clone,
+        P: Individual<Fitness: From<f64>> + Clone,
+        C: Contest<H, P>,
+        Rng: rand::Rng + ?Sized,
+    {
+        let host_fame = sample(&arena.host_fame, self.fame_sample, rng);
+        let parasite_fame = sample(&arena.parasite_fame, self.fame_sample, rng);
+
+        let mut host_scores = vec![Vec::new(); arena.hosts.len()];
+        let mut parasite_scores = vec![Vec::new(); arena.parasites.len()];
+        let mut beaten = vec![Vec::new(); arena.parasites.len()];
+        let mut defeated = vec![Vec::new(); arena.hosts.len()];
+        let mut opponents = (arena.hosts.len(), arena.parasites.len());
+
+        match self.sampling {
+            Sampling::RoundRobin => {
+                for (h, host) in arena.hosts.iter().enumerate() {
+                    for (p, parasite) in arena.parasites.iter().enumerate() {
+                        let score = self.contest.compete(host, parasite)?;
+
+                        host_scores[h].push(score);
+                        parasite_scores[p].push(-score);
+                    }
+                }
+            }
+            Sampling::Random(count) | Sampling::Shared(count) => {
+                let shared = matches!(self.sampling, Sampling::Shared(_));
+
+                let parasites = match shared && !arena.parasite_samples.is_empty() {
+                    true => arena.parasite_samples.clone(),
+                    false => sample(&arena.parasites, count, rng),
+                };
+                let hosts = match shared && !arena.host_samples.is_empty() {
+                    true => arena.host_samples.clone(),
+                    false => sample(&arena.hosts, count, rng),
+                };
+
+                for (h, host) in arena.hosts.iter().enumerate() {
+                    for (p, parasite) in parasites.iter().enumerate() {
+                        let score = self.contest.compete(host, parasite)?;
+
+                        host_scores[h].push(score);
+
+                        if score > 0.0 {
+                            defeated[h].push(p);
+                        }
+                    }
+                }
+
+                for (p, parasite) in arena.parasites.iter().enumerate() {
+                    for (h, host) in hosts.iter().enumerate() {
+                        let score = self.contest.compete(host, parasite)?;
+
+                        parasite_scores[p].push(-score);
+
+                        if score < 0.0 {
+                            beaten[p].push(h);
+                        }
+                    }
+                }
+
+                opponents = (hosts.len(), parasites.len());
+            }
+        }
+
+        for (h, host) in arena.hosts.iter().enumerate() {
+            for parasite in &parasite_fame {
+                host_scores[h].push(self.contest.compete(host, parasite)?);
+            }
+        }
+
+        for (p, parasite) in arena.parasites.iter().enumerate() {
+            for host in &host_fame {
+                parasite_scores[p].push(-self.contest.compete(host, parasite)?);
+            }
+        }
+
+        for (host, scores) in arena.hosts.iter_mut().zip(host_scores) {
+            *host.fitness_mut() = mean(&scores).into();
+        }
+
+        for (parasite, scores) in arena.parasites.iter_mut().zip(parasite_scores) {
+            *parasite.fitness_mut() = mean(&scores).into();
+        }
+
+        if let Sampling::Shared(count) = self.sampling {
+            arena.parasite_samples = shared(&beaten, opponents.0, count)
+                .into_iter()
+                .map(|p| arena.parasites[p].clone())
+                .collect();
+            arena.host_samples = shared(&defeated, opponents.1, count)
+                .into_iter()
+                .map(|h| arena.hosts[h].clone())
+                .collect();
+        }
+
+        if self.fame > 0 {
+            remember(&mut arena.host_fame, &arena.hosts, self.fame);
+            remember(&mut arena.parasite_fame, &arena.parasites, self.fame);
+        }
+
+        Ok(())
+    }
+}
+
+impl<G, H, P, A, B, C> Evolver<G> for Competitive<A, B, C>
+where
+    G: Generation<Population = Arena<H, P>>,
+    H: Individual<Fitness: From<f64>> + Clone,
+    P: Individual<Fitness: From<f64>> + Clone,
+    A: Selector<[H], Output: IntoIterator<Item = H>>,
+    B: Selector<[P], Output: IntoIterator<Item = P>>,
+    C: Contest<H, P>,
+{
+    type Error = CompetitiveError<A::Error, B::Error, C::Error>;
+
+    fn evolve<Rng>(&self, mut generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let arena = generation.population_mut();
+
+        if arena.hosts.is_empty() || arena.parasites.is_empty() {
+            return Err(CompetitiveError::Empty);
+        }
+
+        if matches!(self.sampling, Sampling::Random(0) | Sampling::Shared(0)) {
+            return Err(CompetitiveError::InvalidSample);
+        }
+
+        self.compete(arena, rng)
+            .map_err(CompetitiveError::Contest)?;
+
+        let hosts = self
+            .hosts
+            .select(&arena.hosts, rng)
+            .map_err(CompetitiveError::SelectHosts)?
+            .into_iter()
+            .collect();
+        let parasites = self
+            .parasites
+            .select(&arena.parasites, rng)
+            .map_err(CompetitiveError::SelectParasites)?
+            .into_iter()
+            .collect();
+
+        arena.hosts = hosts;
+        arena.parasites = parasites;
+
+        Ok(generation.advanced())
+    }
+}
+
+fn sample<T, Rng>(population: &[T], count: usize, rng: &mut Rng) -> Vec<T>
+where
+    T: Clone,
+    Rng: rand::Rng + ?Sized,
+{
+    population
+        .choose_multiple(rng, count)
+        .cloned()
+        .collect::<Vec<_>>()
+}
+
+fn mean(scores: &[f64]) -> f64 {
+    if scores.is_empty() {
+        return 0.0;
+    }
+
+    scores.iter().sum::<f64>() / scores.len() as f64
+}
+
+fn shared(beaten: &[Vec<usize>], opponents: usize, count: usize) -> Vec<usize> {
+    let mut counts = vec![0; opponents];
+    let mut candidates = (0..beaten.len()).collect::<Vec<_>>();
+    let mut selected = Vec::with_capacity(count);
+
+    while selected.len() < count && !candidates.is_empty() {
+        let (position, _) = candidates
+            .iter()
+            .enumerate()
+            .map(|(position, candidate)| {
+                let score = beaten[*candidate]
+                    .iter()
+                    .map(|opponent| 1.0 / (1.0 + counts[*opponent] as f64))
+                    .sum::<f64>();
+
+                (position, score)
+            })
+            .fold((0, f64::NEG_INFINITY), |best, next| {
+                if next.1 > best.1 {
+                    next
+                } else {
+                    best
+                }
+            });
+
+        let candidate = candidates.remove(position);
+
+        for opponent in &beaten[candidate] {
+            counts[*opponent] += 1;
+        }
+
+        selected.push(candidate);
+    }
+
+    selected
+}
+
+fn remember<T>(fame: &mut Vec<T>, population: &[T], capacity: usize)
+where
+    T: Individual + Clone,
+{
+    if let Some(best) = population
+        .iter()
+        .max_by_key(|individual| individual.fitness())
+    {
+        if fame.len() >= capacity {
+            fame.remove(0);
+        }
+
+        fame.push(best.clone());
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CompetitiveError<A, B, C> {
+    #[error("hosts and parasites must both be non-empty")]
+    Empty,
+    #[error("opponent sample must contain at least one individual")]
+    InvalidSample,
+    #[error(transparent)]
+    SelectHosts(A),
+    #[error(transparent)]
+    SelectParasites(B),
+    #[error(transparent)]
+    Contest(C),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use ordered_float::OrderedFloat;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::Selector;
+
+    use super::{Arena, Competitive, CompetitiveError, Sampling};
+
+    type Player = Evaluated<i32, OrderedFloat<f64>>;
+
+    fn contest(host: &Player, parasite: &Player) -> Result<f64, Infallible> {
+        Ok((host.individual - parasite.individual).signum() as f64)
+    }
+
+    fn player(value: i32) -> Player {
+        Evaluated::new(value, OrderedFloat(0.0))
+    }
+
+    fn mean(players: &[Player]) -> f64 {
+        players
+            .iter()
+            .map(|player| player.individual as f64)
+            .sum::<f64>()
+            / players.len() as f64
+    }
+
+    #[test]
+    fn test_compete() {
+        let mut rng = rand::rng();
+
+        let mut arena = Arena::new(
+            vec![player(0), player(1), player(2)],
+            vec![player(1), player(3)],
+        );
+
+        Competitive::new((), (), contest)
+            .with_hall_of_fame(4, 0)
+            .compete(&mut arena, &mut rng)
+            .unwrap();
+
+        assert_eq!(arena.hosts()[0].fitness, OrderedFloat(-1.0));
+        assert_eq!(arena.hosts()[1].fitness, OrderedFloat(-0.5));
+        assert_eq!(arena.hosts()[2].fitness, OrderedFloat(0.0));
+        assert_eq!(arena.parasites()[0].fitness, OrderedFloat(0.0));
+        assert_eq!(arena.parasites()[1].fitness, OrderedFloat(1.0));
+        assert_eq!(
+            arena.host_hall_of_fame(),
+            [Evaluated::new(2, OrderedFloat(0.0))]
+        );
+        assert_eq!(
+            arena.parasite_hall_of_fame(),
+            [Evaluated::new(3, OrderedFloat(1.0))]
+        );
+    }
+
+    #[test]
+    fn test_compete_shared() {
+        let mut rng = rand::rng();
+
+        let mut arena = Arena::new(
+            vec![player(0), player(5), player(10)],
+            vec![player(4), player(6), player(11)],
+        );
+
+        let evolver = Competitive::new((), (), contest).with_sampling(Sampling::Shared(3));
+
+        evolver.compete(&mut arena, &mut rng).unwrap();
+
+        assert_eq!(
+            arena.parasite_samples[0],
+            Evaluated::new(11, OrderedFloat(1.0))
+        );
+        assert_eq!(
+            arena.host_samples[0],
+            Evaluated::new(10, OrderedFloat(1.0 / 3.0))
+        );
+        assert_eq!(arena.parasite_samples[1].individual, 6);
+        assert_eq!(arena.parasite_samples.len(), 3);
+        assert_eq!(arena.host_samples.len(), 3);
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        for sampling in [
+            Sampling::RoundRobin,
+            Sampling::Random(4),
+            Sampling::Shared(4),
+        ] {
+            let evolver = Competitive::new(
+                Tournament::binary().mutate(Noise::new(-1..=3)).fill(),
+                Tournament::binary().mutate(Noise::new(-1..=3)).fill(),
+                contest,
+            )
+            .with_sampling(sampling)
+            .with_hall_of_fame(10, 2);
+
+            let mut generation = (0, Arena::new(vec![player(0); 10], vec![player(0); 10]));
+
+            for _ in 0..50 {
+                generation = evolver.evolve(generation, &mut rng).unwrap();
+            }
+
+            let (id, arena) = generation;
+
+            assert_eq!(id, 50);
+            assert_eq!(arena.hosts().len(), 10);
+            assert_eq!(arena.parasites().len(), 10);
+            assert_eq!(arena.host_hall_of_fame().len(), 10);
+            assert!(mean(arena.hosts()) > 10.0);
+            assert!(mean(arena.parasites()) > 10.0);
+        }
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = Competitive::new(
+            Tournament::binary().fill(),
+            Tournament::binary().fill(),
+            contest,
+        )
+        .evolve((0, Arena::new(vec![player(0); 2], Vec::new())), &mut rng);
+        let b = Competitive::new(
+            Tournament::binary().fill(),
+            Tournament::binary().fill(),
+            contest,
+        )
+        .with_sampling(Sampling::Random(0))
+        .evolve(
+            (0, Arena::new(vec![player(0); 2], vec![player(0); 2])),
+            &mut rng,
+        );
+
+        assert!(matches!(a, Err(CompetitiveError::Empty)));
+        assert!(matches!(b, Err(CompetitiveError::InvalidSample)));
+    }
+}
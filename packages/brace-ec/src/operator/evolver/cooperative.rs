@@ -0,0 +1,412 @@
+use rand::seq::IndexedRandom;
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::selector::Selector;
+use crate::population::Population;
+
+use super::Evolver;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Species<I> {
+    populations: Vec<Vec<I>>,
+    representatives: Vec<I>,
+}
+
+impl<I> Species<I> {
+    pub fn new(populations: Vec<Vec<I>>) -> Self {
+        Self {
+            populations,
+            representatives: Vec::new(),
+        }
+    }
+
+    pub fn populations(&self) -> &[Vec<I>] {
+        &self.populations
+    }
+
+    pub fn representatives(&self) -> &[I] {
+        &self.representatives
+    }
+
+    pub fn components(&self) -> usize {
+        self.populations.len()
+    }
+
+    pub fn solution<T>(&self) -> Vec<T>
+    where
+        I: Individual<Genome: AsRef<[T]>>,
+        T: Clone,
+    {
+        assemble(&self.representatives.iter().collect::<Vec<_>>())
+    }
+}
+
+impl<I> Population for Species<I>
+where
+    I: Individual,
+{
+    type Individual = I;
+
+    fn len(&self) -> usize {
+        self.populations.iter().map(Vec::len).sum()
+    }
+}
+
+impl<I> From<Vec<Vec<I>>> for Species<I> {
+    fn from(populations: Vec<Vec<I>>) -> Self {
+        Self::new(populations)
+    }
+}
+
+pub fn assemble<I, T>(components: &[&I]) -> Vec<T>
+where
+    I: Individual<Genome: AsRef<[T]>>,
+    T: Clone,
+{
+    components
+        .iter()
+        .flat_map(|component| component.genome().as_ref().iter().cloned())
+        .collect()
+}
+
+pub trait Collaborative<I>
+where
+    I: Individual,
+{
+    type Error;
+
+    fn evaluate(&self, components: &[&I]) -> Result<I::Fitness, Self::Error>;
+}
+
+impl<I, F, E> Collaborative<I> for F
+where
+    I: Individual,
+    F: Fn(&[&I]) -> Result<I::Fitness, E>,
+{
+    type Error = E;
+
+    fn evaluate(&self, components: &[&I]) -> Result<I::Fitness, Self::Error> {
+        self(components)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Collaboration {
+    #[default]
+    Best,
+    Random,
+    Multiple(usize),
+}
+
+pub struct Cooperative<S, C> {
+    selector: S,
+    evaluator: C,
+    collaboration: Collaboration,
+}
+
+impl<S, C> Cooperative<S, C> {
+    pub fn new(selector: S, evaluator: C) -> Self {
+        Self {
+            selector,
+            evaluator,
+            collaboration: Collaboration::default(),
+        }
+    }
+
+    pub fn with_collaboration(mut self, collaboration: Collaboration) -> Self {
+        self.collaboration = collaboration;
+        self
+    }
+
+    fn collaborators<'a, I, Rng>(&self, species: &'a Species<I>, rng: &mut Rng) -> Vec<Vec<&'a I>>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let random = |rng: &mut Rng| {
+            species
+                .populations
+                .iter()
+                .zip(&species.representatives)
+                .map(|(population, representative)| {
+                    population.choose(rng).unwrap_or(representative)
+                })
+                .collect()
+        };
+
+        match self.collaboration {
+            Collaboration::Best => vec![species.representatives.iter().collect()],
+            Collaboration::Random => vec![random(rng)],
+            Collaboration::Multiple(count) => {
+                let mut sets = vec![species.representatives.iter().collect()];
+
+                sets.extend((1..count).map(|_| random(rng)));
+                sets
+            }
+        }
+    }
+}
+
+impl<G, I, S, C> Evolver<G> for Cooperative<S, C>
+where
+    G: Generation<Population = Species<I>>,
+    I: Individual<Fitness: Clone> + Clone,
+    S: Selector<[I], Output: IntoIterator<Item = I>>,
+    C: Collaborative<I>,
+{
+    type Error = CooperativeError<S::Error, C::Error>;
+
+    fn evolve<Rng>(&self, mut generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let species = generation.population_mut();
+
+        if species.populations.is_empty() || species.populations.iter().any(Vec::is_empty) {
+            return Err(CooperativeError::Empty);
+        }
+
+        if matches!(self.collaboration, Collaboration::Multiple(0)) {
+            return Err(CooperativeError::InvalidCollaboration);
+        }
+
+        if species.representatives.len() != species.populations.len() {
+            species.representatives = species
+                .populations
+                .iter()
+                .filter_map(|population| population.choose(rng).cloned())
+                .collect();
+
+            let fitness = self
+                .evaluator
+                .evaluate(&species.representatives.iter().collect::<Vec<_>>())
+                .map_err(CooperativeError::Evaluate)?;
+
+            for representative in &mut species.representatives {
+                *representative.fitness_mut() = fitness.clone();
+            }
+        }
+
+        let mut populations = Vec::with_capacity(species.populations.len());
+
+        for (component, population) in species.populations.iter().enumerate() {
+            let mut offspring = self
+                .selector
+                .select(population, rng)
+                .map_err(CooperativeError::Select)?
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            for individual in &mut offspring {
+                let mut fitness: Option<I::Fitness> = None;
+
+                for mut collaborators in self.collaborators(species, rng) {
+                    collaborators[component] = individual;
+
+                    let trial = self
+                        .evaluator
+                        .evaluate(&collaborators)
+                        .map_err(CooperativeError::Evaluate)?;
+
+                    if fitness.as_ref().is_none_or(|fitness| trial > *fitness) {
+                        fitness = Some(trial);
+                    }
+                }
+
+                if let Some(fitness) = fitness {
+                    *individual.fitness_mut() = fitness;
+                }
+            }
+
+            populations.push(offspring);
+        }
+
+        for ((population, offspring), representative) in species
+            .populations
+            .iter_mut()
+            .zip(populations)
+            .zip(&mut species.representatives)
+        {
+            if let Some(best) = offspring
+                .iter()
+                .max_by_key(|individual| individual.fitness())
+                .filter(|best| best.fitness() > representative.fitness())
+            {
+                *representative = best.clone();
+            }
+
+            *population = offspring;
+        }
+
+        Ok(generation.advanced())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CooperativeError<S, E> {
+    #[error("every species must contain at least one individual")]
+    Empty,
+    #[error("collaboration requires at least one collaborator set")]
+    InvalidCollaboration,
+    #[error(transparent)]
+    Select(S),
+    #[error(transparent)]
+    Evaluate(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::Selector;
+    use crate::population::Population;
+
+    use super::{assemble, Collaboration, Cooperative, CooperativeError, Species};
+
+    type Component = Evaluated<i32, i32>;
+
+    fn evaluate(components: &[&Component]) -> Result<i32, Infallible> {
+        Ok(-components
+            .iter()
+            .enumerate()
+            .map(|(index, component)| (component.individual - index as i32 * 10).abs())
+            .sum::<i32>())
+    }
+
+    fn species() -> Species<Component> {
+        Species::new(vec![vec![Evaluated::new(0, 0); 10]; 3])
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        for collaboration in [
+            Collaboration::Best,
+            Collaboration::Random,
+            Collaboration::Multiple(3),
+        ] {
+            let evolver = Cooperative::new(
+                Tournament::binary().mutate(Noise::new(-2..=2)).fill(),
+                evaluate,
+            )
+            .with_collaboration(collaboration);
+
+            let mut generation = (0, species());
+
+            for _ in 0..100 {
+                generation = evolver.evolve(generation, &mut rng).unwrap();
+            }
+
+            let (id, species) = generation;
+
+            let representatives = species.representatives().iter().collect::<Vec<_>>();
+
+            assert_eq!(id, 100);
+            assert_eq!(species.components(), 3);
+            assert_eq!(species.len(), 30);
+            assert!(evaluate(&representatives).unwrap() > -10);
+        }
+    }
+
+    #[test]
+    fn test_evolve_fitness() {
+        let mut rng = rand::rng();
+
+        let evolver = Cooperative::new(Tournament::binary().fill(), evaluate);
+
+        let (_, species) = evolver
+            .evolve(
+                (
+                    0,
+                    Species::new(vec![
+                        vec![Evaluated::new(0, -99); 2],
+                        vec![Evaluated::new(10, -99); 2],
+                    ]),
+                ),
+                &mut rng,
+            )
+            .unwrap();
+
+        assert_eq!(species.populations()[0], [Evaluated::new(0, 0); 2]);
+        assert_eq!(species.populations()[1], [Evaluated::new(10, 0); 2]);
+        assert_eq!(species.representatives()[1], Evaluated::new(10, 0));
+    }
+
+    #[test]
+    fn test_evolve_representatives() {
+        let mut rng = rand::rng();
+
+        let evolver = Cooperative::new(
+            Tournament::binary().mutate(Noise::new(5..=5)).fill(),
+            evaluate,
+        );
+
+        let (_, species) = evolver
+            .evolve(
+                (
+                    0,
+                    Species::new(vec![
+                        vec![Evaluated::new(0, 0); 2],
+                        vec![Evaluated::new(10, 0); 2],
+                    ]),
+                ),
+                &mut rng,
+            )
+            .unwrap();
+
+        assert_eq!(
+            species.representatives(),
+            [Evaluated::new(0, 0), Evaluated::new(10, 0)]
+        );
+        assert_eq!(species.populations()[0][0].fitness, -5);
+    }
+
+    #[test]
+    fn test_assemble() {
+        let mut rng = rand::rng();
+
+        let a = Evaluated::new(vec![1, 2], 0);
+        let b = Evaluated::new(vec![3], 0);
+
+        let (_, species) = Cooperative::new(
+            Tournament::binary().fill(),
+            |components: &[&Evaluated<Vec<i32>, i32>]| {
+                Ok::<_, Infallible>(assemble(components).into_iter().sum())
+            },
+        )
+        .evolve(
+            (
+                0,
+                Species::new(vec![vec![a.clone(); 2], vec![b.clone(); 2]]),
+            ),
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(assemble(&[&a, &b]), [1, 2, 3]);
+        assert_eq!(species.solution(), [1, 2, 3]);
+        assert_eq!(species.representatives()[0].fitness, 6);
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = Cooperative::new(Tournament::binary().fill(), evaluate).evolve(
+            (0, Species::new(vec![vec![Evaluated::new(0, 0)], vec![]])),
+            &mut rng,
+        );
+        let b = Cooperative::new(Tournament::binary().fill(), evaluate)
+            .with_collaboration(Collaboration::Multiple(0))
+            .evolve((0, species()), &mut rng);
+
+        assert!(matches!(a, Err(CooperativeError::Empty)));
+        assert!(matches!(b, Err(CooperativeError::InvalidCollaboration)));
+    }
+}
@@ -0,0 +1,293 @@
+use rand::seq::SliceRandom;
+use thiserror::Error;
+
+use crate::distance::{Genotypic, Metric};
+use crate::fitness::numeric::Numeric;
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::recombinator::Recombinator;
+use crate::population::Population;
+
+use super::Evolver;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Replacement {
+    #[default]
+    Deterministic,
+    Probabilistic,
+}
+
+pub struct Crowding<R, D = Genotypic> {
+    recombinator: R,
+    replacement: Replacement,
+    metric: D,
+}
+
+impl<R> Crowding<R> {
+    pub fn new(recombinator: R) -> Self {
+        Self {
+            recombinator,
+            replacement: Replacement::default(),
+            metric: Genotypic,
+        }
+    }
+}
+
+impl<R, D> Crowding<R, D> {
+    pub fn with_replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    pub fn with_distance<T>(self, metric: T) -> Crowding<R, T> {
+        Crowding {
+            recombinator: self.recombinator,
+            replacement: self.replacement,
+            metric,
+        }
+    }
+
+    fn replaces<I, Rng>(&self, child: &I, parent: &I, rng: &mut Rng) -> bool
+    where
+        I: Individual<Fitness: Numeric>,
+        Rng: rand::Rng + ?Sized,
+    {
+        match self.replacement {
+            Replacement::Deterministic => child.fitness() >= parent.fitness(),
+            Replacement::Probabilistic => {
+                let child = child.fitness().as_f64();
+                let parent = parent.fitness().as_f64();
+                let shift = child.min(parent).min(0.0);
+                let total = (child - shift) + (parent - shift);
+
+                let probability = if total > 0.0 {
+                    (child - shift) / total
+                } else {
+                    0.5
+                };
+
+                rng.random_bool(probability.clamp(0.0, 1.0))
+            }
+        }
+    }
+}
+
+impl<G, P, I, R, D> Evolver<G> for Crowding<R, D>
+where
+    G: Generation<Population = P>,
+    P: Population<Individual = I> + AsMut<[I]>,
+    I: Individual<Fitness: Numeric> + Clone,
+    R: Recombinator<[I; 2], Output: IntoIterator<Item = I>>,
+    D: Metric<I>,
+{
+    type Error = CrowdingError<R::Error>;
+
+    fn evolve<Rng>(&self, mut generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let population = generation.population_mut().as_mut();
+        let mut order = (0..population.len()).collect::<Vec<_>>();
+
+        order.shuffle(rng);
+
+        for pair in order.chunks_exact(2) {
+            let (a, b) = (pair[0], pair[1]);
+
+            let mut offspring = self
+                .recombinator
+                .recombine([population[a].clone(), population[b].clone()], rng)
+                .map_err(CrowdingError::Recombine)?
+                .into_iter();
+
+            let (Some(mut x), Some(mut y)) = (offspring.next(), offspring.next()) else {
+                return Err(CrowdingError::NotEnough);
+            };
+
+            let straight =
+                self.metric.measure(&population[a], &x) + self.metric.measure(&population[b], &y);
+            let crossed =
+                self.metric.measure(&population[a], &y) + self.metric.measure(&population[b], &x);
+
+            if crossed < straight {
+                std::mem::swap(&mut x, &mut y);
+            }
+
+            if self.replaces(&x, &population[a], rng) {
+                population[a] = x;
+            }
+
+            if self.replaces(&y, &population[b], rng) {
+                population[b] = y;
+            }
+        }
+
+        Ok(generation.advanced())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CrowdingError<R> {
+    #[error("recombinator produced fewer than two offspring")]
+    NotEnough,
+    #[error(transparent)]
+    Recombine(R),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use ordered_float::OrderedFloat;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::recombinator::mutate::Mutate;
+    use crate::operator::recombinator::Recombinator;
+    use crate::operator::repeat::Repeat;
+
+    use super::{Crowding, CrowdingError, Replacement};
+
+    type Fitness = OrderedFloat<f64>;
+
+    struct Shift(f64);
+
+    impl Mutator<Evaluated<f64, Fitness>> for Shift {
+        type Error = Infallible;
+
+        fn mutate<Rng>(
+            &self,
+            mut individual: Evaluated<f64, Fitness>,
+            rng: &mut Rng,
+        ) -> Result<Evaluated<f64, Fitness>, Self::Error>
+        where
+            Rng: rand::Rng + ?Sized,
+        {
+            individual.individual += rng.random_range(-self.0..=self.0);
+
+            Ok(individual)
+        }
+    }
+
+    struct Single;
+
+    impl Recombinator<[Evaluated<f64, Fitness>; 2]> for Single {
+        type Output = [Evaluated<f64, Fitness>; 1];
+        type Error = Infallible;
+
+        fn recombine<Rng>(
+            &self,
+            [parent, _]: [Evaluated<f64, Fitness>; 2],
+            _: &mut Rng,
+        ) -> Result<Self::Output, Self::Error>
+        where
+            Rng: rand::Rng + ?Sized,
+        {
+            Ok([parent])
+        }
+    }
+
+    struct Step;
+
+    impl Recombinator<[Evaluated<f64, Fitness>; 2]> for Step {
+        type Output = [Evaluated<f64, Fitness>; 2];
+        type Error = Infallible;
+
+        fn recombine<Rng>(
+            &self,
+            [a, b]: [Evaluated<f64, Fitness>; 2],
+            _: &mut Rng,
+        ) -> Result<Self::Output, Self::Error>
+        where
+            Rng: rand::Rng + ?Sized,
+        {
+            Ok([
+                evaluated(b.individual + 0.05),
+                evaluated(a.individual + 0.05),
+            ])
+        }
+    }
+
+    fn peaks(individual: &Evaluated<f64, Fitness>) -> Result<Fitness, Infallible> {
+        Ok(OrderedFloat(
+            (individual.genome() * std::f64::consts::PI).sin().powi(2),
+        ))
+    }
+
+    fn evaluated(genome: f64) -> Evaluated<f64, Fitness> {
+        let individual = Evaluated::new(genome, OrderedFloat(0.0));
+        let fitness = peaks(&individual).unwrap();
+
+        individual.with_fitness(fitness)
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let a = Crowding::new(Mutate::new(Shift(0.0)))
+            .evolve((0, vec![evaluated(0.5), evaluated(0.3)]), &mut rng)
+            .unwrap();
+        let b = Crowding::new(Step)
+            .evolve((0, vec![evaluated(0.5), evaluated(0.3)]), &mut rng)
+            .unwrap();
+        let c = Crowding::new(Step)
+            .evolve(
+                (0, vec![evaluated(0.5), evaluated(0.3), evaluated(0.1)]),
+                &mut rng,
+            )
+            .unwrap();
+
+        assert_eq!(a, (1, vec![evaluated(0.5), evaluated(0.3)]));
+        assert_eq!(b, (1, vec![evaluated(0.5), evaluated(0.3 + 0.05)]));
+        assert_eq!(c.1.len(), 3);
+        assert_eq!(c.1[0], evaluated(0.5));
+        assert_eq!(
+            c.1.iter()
+                .filter(|individual| **individual != evaluated(0.5))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_evolve_niches() {
+        let mut rng = rand::rng();
+
+        let population = (0..40)
+            .map(|index| evaluated(index as f64 / 10.0))
+            .collect::<Vec<_>>();
+
+        for replacement in [Replacement::Deterministic, Replacement::Probabilistic] {
+            let evolver = Crowding::new(Mutate::new(Shift(0.05).evaluate_with(peaks)))
+                .with_replacement(replacement);
+
+            let (generation, population) = Repeat::new(evolver, 50)
+                .evolve((0, population.clone()), &mut rng)
+                .unwrap();
+
+            let niches = (0..4)
+                .filter(|niche| {
+                    population
+                        .iter()
+                        .any(|individual| individual.individual.floor() as i32 == *niche)
+                })
+                .count();
+
+            assert_eq!(generation, 50);
+            assert_eq!(population.len(), 40);
+            assert_eq!(niches, 4);
+        }
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = Crowding::new(Single).evolve((0, vec![evaluated(0.5), evaluated(0.4)]), &mut rng);
+
+        assert_eq!(a, Err(CrowdingError::NotEnough));
+    }
+}
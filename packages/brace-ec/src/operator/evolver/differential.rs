@@ -0,0 +1,704 @@
+use thiserror::Error;
+
+use crate::chromosome::Chromosome;
+use crate::fitness::numeric::Numeric;
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::evaluator::Evaluator;
+use crate::population::{IterableMutPopulation, Population};
+use crate::util::random::{cauchy, normal};
+
+use super::Evolver;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    #[default]
+    Rand1,
+    Best1,
+    CurrentToBest1,
+    CurrentToPBest1,
+    Rand2,
+}
+
+impl Strategy {
+    fn vectors(&self) -> usize {
+        match self {
+            Self::Rand1 => 3,
+            Self::Best1 => 2,
+            Self::CurrentToBest1 => 2,
+            Self::CurrentToPBest1 => 2,
+            Self::Rand2 => 5,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Crossover {
+    #[default]
+    Binomial,
+    Exponential,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Control {
+    Fixed { weight: f64, rate: f64 },
+    Jde { tau_weight: f64, tau_rate: f64 },
+    Shade { memory: usize },
+}
+
+impl Control {
+    pub fn fixed(weight: f64, rate: f64) -> Self {
+        Self::Fixed { weight, rate }
+    }
+
+    pub fn jde() -> Self {
+        Self::Jde {
+            tau_weight: 0.1,
+            tau_rate: 0.1,
+        }
+    }
+
+    pub fn shade(memory: usize) -> Self {
+        Self::Shade { memory }
+    }
+}
+
+impl Default for Control {
+    fn default() -> Self {
+        Self::fixed(0.5, 0.9)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DifferentialGeneration<P>
+where
+    P: Population,
+{
+    id: usize,
+    population: P,
+    parameters: Vec<(f64, f64)>,
+    memory: Vec<(f64, f64)>,
+    index: usize,
+    archive: Vec<P::Individual>,
+}
+
+impl<P> DifferentialGeneration<P>
+where
+    P: Population,
+{
+    pub fn new(population: P) -> Self {
+        Self {
+            id: 0,
+            population,
+            parameters: Vec::new(),
+            memory: Vec::new(),
+            index: 0,
+            archive: Vec::new(),
+        }
+    }
+
+    pub fn parameters(&self) -> &[(f64, f64)] {
+        &self.parameters
+    }
+
+    pub fn memory(&self) -> &[(f64, f64)] {
+        &self.memory
+    }
+
+    pub fn archive(&self) -> &[P::Individual] {
+        &self.archive
+    }
+}
+
+impl<P> Generation for DifferentialGeneration<P>
+where
+    P: Population,
+{
+    type Id = usize;
+    type Population = P;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn population(&self) -> &Self::Population {
+        &self.population
+    }
+
+    fn population_mut(&mut self) -> &mut Self::Population {
+        &mut self.population
+    }
+
+    fn advance(&mut self) {
+        self.id += 1;
+    }
+}
+
+pub struct DifferentialEvolution<E> {
+    evaluator: E,
+    strategy: Strategy,
+    crossover: Crossover,
+    control: Control,
+}
+
+impl<E> DifferentialEvolution<E> {
+    pub fn new(evaluator: E) -> Self {
+        Self {
+            evaluator,
+            strategy: Strategy::default(),
+            crossover: Crossover::default(),
+            control: Control::default(),
+        }
+    }
+
+    pub fn shade(evaluator: E, memory: usize) -> Self {
+        Self::new(evaluator)
+            .with_strategy(Strategy::CurrentToPBest1)
+            .with_control(Control::shade(memory))
+    }
+
+    pub fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn with_crossover(mut self, crossover: Crossover) -> Self {
+        self.crossover = crossover;
+        self
+    }
+
+    pub fn with_control(mut self, control: Control) -> Self {
+        self.control = control;
+        self
+    }
+
+    pub fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    pub fn crossover(&self) -> Crossover {
+        self.crossover
+    }
+
+    pub fn control(&self) -> Control {
+        self.control
+    }
+
+    fn validate<T>(&self) -> Result<(), DifferentialEvolutionError<T>> {
+        match self.control {
+            Control::Fixed { weight, .. } if !(0.0..=2.0).contains(&weight) => {
+                Err(DifferentialEvolutionError::InvalidWeight)
+            }
+            Control::Fixed { rate, .. } if !(0.0..=1.0).contains(&rate) => {
+                Err(DifferentialEvolutionError::InvalidRate)
+            }
+            Control::Jde {
+                tau_weight,
+                tau_rate,
+            } if !(0.0..=1.0).contains(&tau_weight) || !(0.0..=1.0).contains(&tau_rate) => {
+                Err(DifferentialEvolutionError::InvalidRate)
+            }
+            Control::Shade { memory: 0 } => Err(DifferentialEvolutionError::InvalidMemory),
+            _ => Ok(()),
+        }
+    }
+
+    fn parameters<Rng>(
+        &self,
+        parameters: &[(f64, f64)],
+        memory: &[(f64, f64)],
+        index: usize,
+        rng: &mut Rng,
+    ) -> (f64, f64)
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        match self.control {
+            Control::Fixed { weight, rate } => (weight, rate),
+            Control::Jde {
+                tau_weight,
+                tau_rate,
+            } => {
+                let (weight, rate) = parameters[index];
+
+                (
+                    match rng.random_bool(tau_weight) {
+                        true => rng.random_range(0.1..1.0),
+                        false => weight,
+                    },
+                    match rng.random_bool(tau_rate) {
+                        true => rng.random_range(0.0..=1.0),
+                        false => rate,
+                    },
+                )
+            }
+            Control::Shade { .. } => {
+                let (weight, rate) = memory[rng.random_range(0..memory.len())];
+                let rate = normal(rng, rate, 0.1).clamp(0.0, 1.0);
+
+                loop {
+                    let weight = cauchy(rng, weight, 0.1);
+
+                    if weight > 0.0 {
+                        break (weight.min(1.0), rate);
+                    }
+                }
+            }
+        }
+    }
+
+    fn positions<Rng>(&self, len: usize, rate: f64, rng: &mut Rng) -> Vec<usize>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        match self.crossover {
+            Crossover::Binomial => {
+                let forced = rng.random_range(0..len);
+
+                (0..len)
+                    .filter(|index| *index == forced || rng.random_bool(rate))
+                    .collect()
+            }
+            Crossover::Exponential => {
+                let start = rng.random_range(0..len);
+                let mut count = 1;
+
+                while count < len && rng.random_bool(rate) {
+                    count += 1;
+                }
+
+                (0..count).map(|offset| (start + offset) % len).collect()
+            }
+        }
+    }
+}
+
+impl<P, I, E> Evolver<DifferentialGeneration<P>> for DifferentialEvolution<E>
+where
+    P: IterableMutPopulation<Individual = I> + Clone,
+    I: Individual<Genome: Chromosome<Gene = f64>, Fitness: Numeric> + Clone,
+    E: Evaluator<I>,
+{
+    type Error = DifferentialEvolutionError<E::Error>;
+
+    fn evolve<Rng>(
+        &self,
+        mut generation: DifferentialGeneration<P>,
+        rng: &mut Rng,
+    ) -> Result<DifferentialGeneration<P>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.validate()?;
+
+        let individuals = generation.population.iter().collect::<Vec<_>>();
+        let vectors = self.strategy.vectors();
+        let size = individuals.len();
+
+        if size <= vectors {
+            return Err(DifferentialEvolutionError::NotEnough);
+        }
+
+        let len = individuals[0].genome().len();
+
+        if len == 0
+            || individuals
+                .iter()
+                .copied()
+                .chain(&generation.archive)
+                .any(|individual| individual.genome().len() != len)
+        {
+            return Err(DifferentialEvolutionError::InvalidDimension);
+        }
+
+        let mut ranking = (0..size).collect::<Vec<_>>();
+
+        ranking.sort_by(|a, b| individuals[*b].fitness().cmp(individuals[*a].fitness()));
+
+        let best = ranking[0];
+
+        match self.control {
+            Control::Jde { .. } if generation.parameters.len() != size => {
+                generation.parameters = vec![(0.5, 0.9); size];
+            }
+            Control::Shade { memory } if generation.memory.len() != memory => {
+                generation.memory = vec![(0.5, 0.5); memory];
+                generation.index = 0;
+            }
+            _ => {}
+        }
+
+        let archive = &generation.archive;
+        let vector = |index: usize| match index < size {
+            true => individuals[index],
+            false => &archive[index - size],
+        };
+
+        let mut parameters = generation.parameters.clone();
+        let mut trials = Vec::with_capacity(size);
+        let mut successes = Vec::new();
+        let mut archived = Vec::new();
+
+        for (index, target) in individuals.iter().enumerate() {
+            let (weight, rate) = self.parameters(&parameters, &generation.memory, index, rng);
+
+            let donors = match self.strategy {
+                Strategy::CurrentToPBest1 => {
+                    let minimum = 2.0 / size as f64;
+                    let fraction = match minimum < 0.2 {
+                        true => rng.random_range(minimum..0.2),
+                        false => minimum,
+                    };
+                    let count = ((fraction * size as f64).ceil() as usize).clamp(1, size);
+                    let pbest = ranking[rng.random_range(0..count)];
+
+                    let first = loop {
+                        let donor = rng.random_range(0..size);
+
+                        if donor != index {
+                            break donor;
+                        }
+                    };
+
+                    let second = loop {
+                        let donor = rng.random_range(0..size + archive.len());
+
+                        if donor != index && donor != first {
+                            break donor;
+                        }
+                    };
+
+                    vec![pbest, first, second]
+                }
+                _ => rand::seq::index::sample(rng, size, vectors + 1)
+                    .into_iter()
+                    .filter(|donor| *donor != index)
+                    .take(vectors)
+                    .collect::<Vec<_>>(),
+            };
+
+            let gene = |individual: usize, position: usize| {
+                vector(individual)
+                    .genome()
+                    .gene(position)
+                    .copied()
+                    .ok_or(DifferentialEvolutionError::InvalidDimension)
+            };
+
+            let mut trial = (*target).clone();
+
+            for position in self.positions(len, rate, rng) {
+                let value = match self.strategy {
+                    Strategy::Rand1 => {
+                        gene(donors[0], position)?
+                            + weight * (gene(donors[1], position)? - gene(donors[2], position)?)
+                    }
+                    Strategy::Best1 => {
+                        gene(best, position)?
+                            + weight * (gene(donors[0], position)? - gene(donors[1], position)?)
+                    }
+                    Strategy::CurrentToBest1 => {
+                        gene(index, position)?
+                            + weight * (gene(best, position)? - gene(index, position)?)
+                            + weight * (gene(donors[0], position)? - gene(donors[1], position)?)
+                    }
+                    Strategy::CurrentToPBest1 => {
+                        gene(index, position)?
+                            + weight * (gene(donors[0], position)? - gene(index, position)?)
+                            + weight * (gene(donors[1], position)? - gene(donors[2], position)?)
+                    }
+                    Strategy::Rand2 => {
+                        gene(donors[0], position)?
+                            + weight * (gene(donors[1], position)? - gene(donors[2], position)?)
+                            + weight * (gene(donors[3], position)? - gene(donors[4], position)?)
+                    }
+                };
+
+                *trial
+                    .genome_mut()
+                    .gene_mut(position)
+                    .ok_or(DifferentialEvolutionError::InvalidDimension)? = value;
+            }
+
+            *trial.fitness_mut() = self
+                .evaluator
+                .evaluate(&trial, rng)
+                .map_err(DifferentialEvolutionError::Evaluate)?;
+
+            if trial.fitness() < target.fitness() {
+                trials.push(None);
+
+                continue;
+            }
+
+            if trial.fitness() > target.fitness() {
+                let improvement = (trial.fitness().as_f64() - target.fitness().as_f64()).abs();
+
+                successes.push((weight, rate, improvement));
+
+                if self.strategy == Strategy::CurrentToPBest1 {
+                    archived.push((*target).clone());
+                }
+            }
+
+            if let Control::Jde { .. } = self.control {
+                parameters[index] = (weight, rate);
+            }
+
+            trials.push(Some(trial));
+        }
+
+        if let (Control::Shade { .. }, false) = (self.control, successes.is_empty()) {
+            let total = successes
+                .iter()
+                .map(|(_, _, improvement)| improvement)
+                .sum::<f64>();
+            let weights = successes
+                .iter()
+                .map(|(_, _, improvement)| match total.is_normal() {
+                    true => improvement / total,
+                    false => 1.0 / successes.len() as f64,
+                })
+                .collect::<Vec<_>>();
+
+            let squares = successes
+                .iter()
+                .zip(&weights)
+                .map(|((weight, _, _), w)| w * weight * weight)
+                .sum::<f64>();
+            let linear = successes
+                .iter()
+                .zip(&weights)
+                .map(|((weight, _, _), w)| w * weight)
+                .sum::<f64>();
+            let rate = successes
+                .iter()
+                .zip(&weights)
+                .map(|((_, rate, _), w)| w * rate)
+                .sum::<f64>();
+            let index = generation.index;
+
+            generation.memory[index] = (squares / linear, rate);
+            generation.index = (index + 1) % generation.memory.len();
+        }
+
+        let mut population = generation.population.clone();
+
+        for (individual, trial) in population.iter_mut().zip(trials) {
+            if let Some(trial) = trial {
+                *individual = trial;
+            }
+        }
+
+        generation.parameters = parameters;
+        generation.archive.extend(archived);
+
+        while generation.archive.len() > size {
+            let index = rng.random_range(0..generation.archive.len());
+
+            generation.archive.swap_remove(index);
+        }
+
+        Ok(generation.advanced_with(population))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DifferentialEvolutionError<E> {
+    #[error("not enough individuals")]
+    NotEnough,
+    #[error("mismatched genome dimension")]
+    InvalidDimension,
+    #[error("invalid differential weight")]
+    InvalidWeight,
+    #[error("invalid rate")]
+    InvalidRate,
+    #[error("invalid memory size")]
+    InvalidMemory,
+    #[error(transparent)]
+    Evaluate(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+    use std::convert::Infallible;
+
+    use ordered_float::OrderedFloat;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+    use crate::operator::evaluator::function::Function;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::generator::random::Random;
+    use crate::operator::generator::Generator;
+    use crate::operator::repeat::Repeat;
+
+    use crate::generation::Generation;
+
+    use super::{
+        Control, Crossover, DifferentialEvolution, DifferentialEvolutionError,
+        DifferentialGeneration, Strategy,
+    };
+
+    type Sphere = Evaluated<Vec<f64>, Reverse<OrderedFloat<f64>>>;
+
+    fn sphere(individual: &Sphere) -> Result<Reverse<OrderedFloat<f64>>, Infallible> {
+        Ok(Reverse(OrderedFloat(
+            individual.genome().iter().map(|x| x * x).sum(),
+        )))
+    }
+
+    fn population() -> Vec<Sphere> {
+        Random::from(-5.0..5.0)
+            .populate(3)
+            .evaluate_with(sphere)
+            .populate(20)
+            .generate(&mut rand::rng())
+            .unwrap()
+    }
+
+    fn best(population: &[Sphere]) -> f64 {
+        population
+            .iter()
+            .map(|individual| individual.fitness.0 .0)
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        for strategy in [
+            Strategy::Rand1,
+            Strategy::Best1,
+            Strategy::CurrentToBest1,
+            Strategy::CurrentToPBest1,
+            Strategy::Rand2,
+        ] {
+            for crossover in [Crossover::Binomial, Crossover::Exponential] {
+                let population = population();
+                let evolver = DifferentialEvolution::new(Function::new(sphere))
+                    .with_strategy(strategy)
+                    .with_crossover(crossover);
+
+                let generation = Repeat::new(evolver, 300)
+                    .evolve(DifferentialGeneration::new(population.clone()), &mut rng)
+                    .unwrap();
+                let next = generation.population();
+
+                assert_eq!(generation.id(), &300);
+                assert_eq!(next.len(), 20);
+                assert!(best(next) <= best(&population));
+                assert!(best(next) < 1e-2, "{strategy:?} {crossover:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_evolve_adaptive() {
+        let mut rng = rand::rng();
+
+        for evolver in [
+            DifferentialEvolution::new(Function::new(sphere)).with_control(Control::jde()),
+            DifferentialEvolution::new(Function::new(sphere)).with_control(Control::shade(5)),
+            DifferentialEvolution::shade(Function::new(sphere), 5),
+        ] {
+            let control = evolver.control();
+            let generation = Repeat::new(evolver, 300)
+                .evolve(DifferentialGeneration::new(population()), &mut rng)
+                .unwrap();
+
+            assert!(best(generation.population()) < 1e-2, "{control:?}");
+        }
+    }
+
+    #[test]
+    fn test_evolve_state() {
+        let mut rng = rand::rng();
+
+        let jde = DifferentialEvolution::new(Function::new(sphere)).with_control(Control::jde());
+        let shade = DifferentialEvolution::shade(Function::new(sphere), 5);
+
+        let a = jde
+            .evolve(DifferentialGeneration::new(population()), &mut rng)
+            .unwrap();
+        let b = Repeat::new(shade, 20)
+            .evolve(DifferentialGeneration::new(population()), &mut rng)
+            .unwrap();
+        let c = DifferentialEvolution::shade(Function::new(sphere), 5)
+            .evolve(DifferentialGeneration::new(population()), &mut rng)
+            .unwrap();
+
+        assert_eq!(a.parameters().len(), 20);
+        assert!(a.memory().is_empty());
+        assert_eq!(b.memory().len(), 5);
+        assert!(b.memory().iter().any(|memory| *memory != (0.5, 0.5)));
+        assert!(!b.archive().is_empty());
+        assert!(b.archive().len() <= 20);
+        assert!(c.archive().len() <= 20);
+    }
+
+    #[test]
+    fn test_evolve_greedy() {
+        let mut rng = rand::rng();
+
+        let evolver = DifferentialEvolution::new(Function::new(sphere));
+        let mut generation = DifferentialGeneration::new(population());
+
+        for _ in 0..10 {
+            let next = evolver.evolve(generation.clone(), &mut rng).unwrap();
+
+            for (prev, next) in generation.population().iter().zip(next.population()) {
+                assert!(next.fitness >= prev.fitness);
+            }
+
+            generation = next;
+        }
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let population = population();
+
+        let a = DifferentialEvolution::new(Function::new(sphere))
+            .with_strategy(Strategy::Rand2)
+            .evolve(
+                DifferentialGeneration::new(population[..5].to_vec()),
+                &mut rng,
+            );
+        let b = DifferentialEvolution::new(Function::new(sphere))
+            .with_control(Control::fixed(3.0, 0.5))
+            .evolve(DifferentialGeneration::new(population.clone()), &mut rng);
+        let c = DifferentialEvolution::new(Function::new(sphere))
+            .with_control(Control::fixed(0.5, 1.5))
+            .evolve(DifferentialGeneration::new(population.clone()), &mut rng);
+        let d = DifferentialEvolution::new(Function::new(sphere))
+            .with_control(Control::shade(0))
+            .evolve(DifferentialGeneration::new(population.clone()), &mut rng);
+
+        let mut population = population;
+
+        population[3] = Evaluated::new(vec![0.0], Reverse(OrderedFloat(0.0)));
+
+        let e = DifferentialEvolution::new(Function::new(sphere))
+            .with_strategy(Strategy::Best1)
+            .evolve(DifferentialGeneration::new(population), &mut rng);
+        let f = DifferentialEvolution::new(Function::new(sphere)).evolve(
+            DifferentialGeneration::new(vec![
+                Evaluated::new(Vec::new(), Reverse(OrderedFloat(0.0)));
+                10
+            ]),
+            &mut rng,
+        );
+
+        assert_eq!(a.err(), Some(DifferentialEvolutionError::NotEnough));
+        assert_eq!(b.err(), Some(DifferentialEvolutionError::InvalidWeight));
+        assert_eq!(c.err(), Some(DifferentialEvolutionError::InvalidRate));
+        assert_eq!(d.err(), Some(DifferentialEvolutionError::InvalidMemory));
+        assert_eq!(e.err(), Some(DifferentialEvolutionError::InvalidDimension));
+        assert_eq!(f.err(), Some(DifferentialEvolutionError::InvalidDimension));
+    }
+}
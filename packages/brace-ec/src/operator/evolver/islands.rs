@@ -0,0 +1,367 @@
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::selector::Selector;
+use crate::population::{IterableMutPopulation, Population};
+use crate::util::iter::Iterable;
+
+use super::Evolver;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Archipelago<G> {
+    islands: Vec<G>,
+    epoch: usize,
+}
+
+impl<G> Archipelago<G> {
+    pub fn new(islands: Vec<G>) -> Self {
+        Self { islands, epoch: 0 }
+    }
+
+    pub fn islands(&self) -> &[G] {
+        &self.islands
+    }
+
+    pub fn islands_mut(&mut self) -> &mut [G] {
+        &mut self.islands
+    }
+
+    pub fn epoch(&self) -> usize {
+        self.epoch
+    }
+}
+
+impl<G> Population for Archipelago<G>
+where
+    G: Generation,
+{
+    type Individual = <G::Population as Population>::Individual;
+
+    fn len(&self) -> usize {
+        self.islands
+            .iter()
+            .map(|island| island.population().len())
+            .sum()
+    }
+}
+
+impl<G> From<Vec<G>> for Archipelago<G> {
+    fn from(islands: Vec<G>) -> Self {
+        Self::new(islands)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Topology {
+    #[default]
+    Ring,
+    Full,
+    Random,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Replacement {
+    #[default]
+    Worst,
+    Random,
+}
+
+pub struct Islands<E, S> {
+    evolvers: Vec<E>,
+    selector: S,
+    interval: usize,
+    topology: Topology,
+    replacement: Replacement,
+}
+
+impl<E, S> Islands<E, S> {
+    pub fn new(evolvers: Vec<E>, selector: S) -> Self {
+        Self {
+            evolvers,
+            selector,
+            interval: 10,
+            topology: Topology::default(),
+            replacement: Replacement::default(),
+        }
+    }
+
+    pub fn with_interval(mut self, interval: usize) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn with_replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    fn routes<Rng>(&self, len: usize, rng: &mut Rng) -> Vec<(usize, usize)>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        match self.topology {
+            Topology::Ring => (0..len).map(|from| (from, (from + 1) % len)).collect(),
+            Topology::Full => (0..len)
+                .flat_map(|from| (0..len).map(move |to| (from, to)))
+                .filter(|(from, to)| from != to)
+                .collect(),
+            Topology::Random => (0..len)
+                .map(|from| {
+                    let to = rng.random_range(0..len - 1);
+
+                    (from, if to >= from { to + 1 } else { to })
+                })
+                .collect(),
+        }
+    }
+
+    fn replace<P, Rng>(&self, population: &mut P, immigrants: &[P::Individual], rng: &mut Rng)
+    where
+        P: IterableMutPopulation<Individual: Clone>,
+        Rng: rand::Rng + ?Sized,
+    {
+        let count = immigrants.len().min(population.len());
+
+        let targets = match self.replacement {
+            Replacement::Worst => {
+                let mut indices = population.iter().enumerate().collect::<Vec<_>>();
+
+                indices.sort_by(|(_, a), (_, b)| a.fitness().cmp(b.fitness()));
+                indices
+                    .into_iter()
+                    .take(count)
+                    .map(|(index, _)| index)
+                    .collect()
+            }
+            Replacement::Random => {
+                rand::seq::index::sample(rng, population.len(), count).into_vec()
+            }
+        };
+
+        let mut slots = vec![None; population.len()];
+
+        for (target, immigrant) in targets.into_iter().zip(immigrants) {
+            slots[target] = Some(immigrant);
+        }
+
+        for (individual, slot) in population.iter_mut().zip(slots) {
+            if let Some(immigrant) = slot {
+                *individual = immigrant.clone();
+            }
+        }
+    }
+}
+
+impl<T, G, E, S> Evolver<T> for Islands<E, S>
+where
+    T: Generation<Population = Archipelago<G>>,
+    G: Generation<Population: IterableMutPopulation<Individual: Clone>> + Send,
+    E: Evolver<G, Error: Send> + Sync,
+    S: Selector<
+        G::Population,
+        Output: IntoIterator<Item = <G::Population as Population>::Individual>,
+    >,
+{
+    type Error = IslandsError<E::Error, S::Error>;
+
+    fn evolve<Rng>(&self, mut generation: T, rng: &mut Rng) -> Result<T, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.evolvers.is_empty() {
+            return Err(IslandsError::Empty);
+        }
+
+        let archipelago = generation.population_mut();
+        let evolvers = &self.evolvers;
+
+        let mut islands = std::mem::take(&mut archipelago.islands)
+            .into_par_iter()
+            .enumerate()
+            .map_init(rand::rng, |rng, (index, island)| {
+                evolvers[index % evolvers.len()].evolve(island, rng)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(IslandsError::Evolve)?;
+
+        archipelago.epoch += 1;
+
+        if islands.len() > 1 && self.interval > 0 && archipelago.epoch.is_multiple_of(self.interval)
+        {
+            let emigrants = islands
+                .iter()
+                .map(|island| {
+                    self.selector
+                        .select(island.population(), rng)
+                        .map(|selection| selection.into_iter().collect::<Vec<_>>())
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(IslandsError::Select)?;
+
+            for (from, to) in self.routes(islands.len(), rng) {
+                self.replace(islands[to].population_mut(), &emigrants[from], rng);
+            }
+        }
+
+        archipelago.islands = islands;
+
+        Ok(generation.advanced())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum IslandsError<E, S> {
+    #[error("no island evolvers")]
+    Empty,
+    #[error(transparent)]
+    Evolve(E),
+    #[error(transparent)]
+    Select(S),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::generation::Generation;
+    use crate::operator::evolver::{DynEvolver, Evolver};
+    use crate::operator::mutator::add::Add;
+    use crate::operator::repeat::Repeat;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::worst::Worst;
+    use crate::operator::selector::Selector;
+    use crate::population::Population;
+
+    use super::{Archipelago, Islands, IslandsError, Replacement, Topology};
+
+    struct Noop;
+
+    impl<G> Evolver<G> for Noop
+    where
+        G: Generation,
+    {
+        type Error = Infallible;
+
+        fn evolve<Rng>(&self, generation: G, _: &mut Rng) -> Result<G, Self::Error>
+        where
+            Rng: rand::Rng + ?Sized,
+        {
+            Ok(generation.advanced())
+        }
+    }
+
+    fn archipelago() -> (usize, Archipelago<(usize, [i32; 3])>) {
+        (
+            0,
+            Archipelago::new(vec![
+                (0, [1, 2, 3]),
+                (0, [4, 5, 6]),
+                (0, [7, 8, 9]),
+                (0, [10, 11, 12]),
+            ]),
+        )
+    }
+
+    fn populations(generation: &(usize, Archipelago<(usize, [i32; 3])>)) -> Vec<[i32; 3]> {
+        generation
+            .1
+            .islands()
+            .iter()
+            .map(|island| island.1)
+            .collect()
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let a = Islands::new(vec![Noop], Best)
+            .with_interval(1)
+            .evolve(archipelago(), &mut rng)
+            .unwrap();
+        let b = Islands::new(vec![Noop], Best)
+            .with_interval(1)
+            .with_topology(Topology::Full)
+            .evolve(archipelago(), &mut rng)
+            .unwrap();
+        let c = Islands::new(vec![Noop], Worst)
+            .with_interval(1)
+            .with_replacement(Replacement::Random)
+            .evolve(archipelago(), &mut rng)
+            .unwrap();
+
+        assert_eq!(a.0, 1);
+        assert_eq!(a.1.epoch(), 1);
+        assert_eq!(a.1.len(), 12);
+        assert!(a.1.islands().iter().all(|island| island.0 == 1));
+        assert_eq!(
+            populations(&a),
+            [[12, 2, 3], [3, 5, 6], [6, 8, 9], [9, 11, 12]]
+        );
+        assert_eq!(
+            populations(&b),
+            [[6, 9, 12], [9, 12, 6], [12, 8, 9], [9, 11, 12]]
+        );
+        assert!(populations(&c)
+            .iter()
+            .zip([10, 1, 4, 7])
+            .all(|(population, immigrant)| population.contains(&immigrant)));
+    }
+
+    #[test]
+    fn test_evolve_interval() {
+        let mut rng = rand::rng();
+
+        let evolver = Islands::new(vec![Noop], Best)
+            .with_interval(3)
+            .with_topology(Topology::Random);
+
+        let a = evolver.evolve(archipelago(), &mut rng).unwrap();
+        let b = evolver.evolve(a.clone(), &mut rng).unwrap();
+        let b = evolver.evolve(b, &mut rng).unwrap();
+
+        assert_eq!(populations(&a), populations(&archipelago()));
+        assert_eq!(b.1.epoch(), 3);
+        assert_ne!(populations(&b), populations(&a));
+        assert_eq!(b.1.len(), 12);
+    }
+
+    #[test]
+    fn test_evolve_heterogeneous() {
+        let mut rng = rand::rng();
+
+        let evolvers: Vec<Box<dyn DynEvolver<(usize, [i32; 3])> + Send + Sync>> = vec![
+            Box::new(Best.fill().evolver()),
+            Box::new(Tournament::binary().mutate(Add(1)).fill().evolver()),
+        ];
+
+        let generation = Repeat::new(Islands::new(evolvers, Best).with_interval(0), 5)
+            .evolve(archipelago(), &mut rng)
+            .unwrap();
+
+        assert_eq!(generation.0, 5);
+        let populations = populations(&generation);
+
+        assert_eq!(populations[0], [3, 3, 3]);
+        assert_eq!(populations[2], [9, 9, 9]);
+        assert!(populations[1].iter().all(|i| *i >= 9));
+        assert!(populations[3].iter().all(|i| *i >= 15));
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = Islands::<Noop, _>::new(Vec::new(), Best).evolve(archipelago(), &mut rng);
+
+        assert_eq!(a.err(), Some(IslandsError::Empty));
+    }
+}
@@ -0,0 +1,175 @@
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::selector::Selector;
+use crate::population::elites::{Descriptor, Elites};
+use crate::population::Population;
+
+use super::Evolver;
+
+pub struct MapElites<S, D> {
+    selector: S,
+    descriptor: D,
+    batch: usize,
+}
+
+impl<S, D> MapElites<S, D> {
+    pub fn new(selector: S, descriptor: D) -> Self {
+        Self {
+            selector,
+            descriptor,
+            batch: 1,
+        }
+    }
+
+    pub fn with_batch(mut self, batch: usize) -> Self {
+        self.batch = batch;
+        self
+    }
+
+    pub fn insert<I>(&self, elites: &mut Elites<I>, individual: I) -> bool
+    where
+        I: Individual,
+        D: Descriptor<I>,
+    {
+        let descriptor = self.descriptor.describe(&individual);
+
+        elites.insert(individual, &descriptor)
+    }
+}
+
+impl<G, I, S, D> Evolver<G> for MapElites<S, D>
+where
+    G: Generation<Population = Elites<I>>,
+    I: Individual,
+    S: Selector<Elites<I>, Output: IntoIterator<Item = I>>,
+    D: Descriptor<I>,
+{
+    type Error = MapElitesError<S::Error>;
+
+    fn evolve<Rng>(&self, mut generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let elites = generation.population_mut();
+
+        if elites.is_empty() {
+            return Err(MapElitesError::Empty);
+        }
+
+        for _ in 0..self.batch {
+            let offspring = self
+                .selector
+                .select(elites, rng)
+                .map_err(MapElitesError::Select)?;
+
+            for individual in offspring {
+                self.insert(elites, individual);
+            }
+        }
+
+        Ok(generation.advanced())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MapElitesError<S> {
+    #[error("archive must contain at least one elite")]
+    Empty,
+    #[error(transparent)]
+    Select(S),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::recombinator::sum::Sum;
+    use crate::operator::recombinator::Recombinator;
+    use crate::operator::selector::random::Random;
+    use crate::operator::selector::Selector;
+    use crate::population::elites::{Dimension, Elites, Tessellation};
+    use crate::population::Population;
+
+    use super::{MapElites, MapElitesError};
+
+    type Elite = Evaluated<i32, i32>;
+
+    fn evaluate(individual: &Elite) -> Result<i32, Infallible> {
+        Ok(-(individual.individual % 7).abs())
+    }
+
+    fn describe(individual: &Elite) -> [f64; 1] {
+        [individual.individual as f64]
+    }
+
+    fn elites() -> Elites<Elite> {
+        Elites::new(Tessellation::grid([
+            Dimension::new(-50.0..=50.0, 20).unwrap()
+        ]))
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let evolver = MapElites::new(
+            Random.mutate(Noise::new(-5..=5).evaluate_with(evaluate)),
+            describe,
+        )
+        .with_batch(10);
+
+        let mut elites = elites();
+
+        assert!(evolver.insert(&mut elites, Evaluated::new(0, 0)));
+        assert!(!evolver.insert(&mut elites, Evaluated::new(1, -1)));
+
+        let mut generation = (0, elites);
+
+        for _ in 0..100 {
+            generation = evolver.evolve(generation, &mut rng).unwrap();
+        }
+
+        let (id, elites) = generation;
+
+        assert_eq!(id, 100);
+        assert!(elites.len() > 5);
+        assert!(elites.coverage() > 0.25);
+        assert!(elites.qd_score() <= 0.0);
+        assert_eq!(elites.best().map(|elite| elite.fitness), Some(0));
+    }
+
+    #[test]
+    fn test_evolve_recombine() {
+        let mut rng = rand::rng();
+
+        let evolver = MapElites::new(
+            Random.and(Random).recombine(Sum.evaluate_with(evaluate)),
+            describe,
+        );
+
+        let mut elites = elites();
+
+        evolver.insert(&mut elites, Evaluated::new(10, -3));
+        evolver.insert(&mut elites, Evaluated::new(-10, -3));
+
+        let (_, elites) = evolver.evolve((0, elites), &mut rng).unwrap();
+
+        assert!(elites.len() >= 2);
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = MapElites::new(Random.mutate(Noise::new(-5..=5)), describe)
+            .evolve((0, elites()), &mut rng);
+
+        assert!(matches!(a, Err(MapElitesError::Empty)));
+    }
+}
@@ -1,12 +1,32 @@
+pub mod alps;
+pub mod cellular;
+pub mod clocked;
+pub mod cma;
+pub mod compact;
+pub mod competitive;
+pub mod cooperative;
+pub mod crowding;
+pub mod differential;
+pub mod islands;
 pub mod limit;
+pub mod map_elites;
+pub mod novelty;
+pub mod pbil;
+pub mod restart;
 pub mod select;
+pub mod steady_state;
+pub mod strategy;
+pub mod swarm;
+pub mod umda;
 
 use std::error::Error;
 
 use crate::generation::Generation;
 use crate::individual::Individual;
 use crate::population::Population;
+use crate::schedule::Clock;
 
+use self::clocked::Clocked;
 use self::limit::Limit;
 
 use super::evaluate::Evaluate;
@@ -67,6 +87,10 @@ where
         Limit::new(self, generation)
     }
 
+    fn clocked(self, clock: Clock) -> Clocked<Self> {
+        Clocked::new(self, clock)
+    }
+
     fn inspect<F>(self, inspector: F) -> Inspect<Self, F>
     where
         F: Fn(&G),
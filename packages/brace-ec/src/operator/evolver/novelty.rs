@@ -0,0 +1,327 @@
+use thiserror::Error;
+
+use crate::fitness::numeric::Numeric;
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::novelty::archive::Archive;
+use crate::novelty::{Behaviour, Behavioural};
+use crate::population::IterableMutPopulation;
+use crate::util::iter::Iterable;
+
+use super::Evolver;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Objective {
+    #[default]
+    Novelty,
+    Weighted(f64),
+}
+
+#[derive(Clone, Debug)]
+pub struct NoveltyGeneration<G, B> {
+    generation: G,
+    archive: Archive<B>,
+}
+
+impl<G, B> NoveltyGeneration<G, B> {
+    pub fn new(generation: G, archive: Archive<B>) -> Self {
+        Self {
+            generation,
+            archive,
+        }
+    }
+
+    pub fn generation(&self) -> &G {
+        &self.generation
+    }
+
+    pub fn archive(&self) -> &Archive<B> {
+        &self.archive
+    }
+
+    pub fn into_inner(self) -> (G, Archive<B>) {
+        (self.generation, self.archive)
+    }
+}
+
+impl<G, B> Generation for NoveltyGeneration<G, B>
+where
+    G: Generation,
+{
+    type Id = G::Id;
+    type Population = G::Population;
+
+    fn id(&self) -> &Self::Id {
+        self.generation.id()
+    }
+
+    fn population(&self) -> &Self::Population {
+        self.generation.population()
+    }
+
+    fn population_mut(&mut self) -> &mut Self::Population {
+        self.generation.population_mut()
+    }
+
+    fn advance(&mut self) {
+        self.generation.advance();
+    }
+}
+
+pub struct NoveltySearch<E> {
+    evolver: E,
+    neighbours: usize,
+    objective: Objective,
+}
+
+impl<E> NoveltySearch<E> {
+    pub fn new(evolver: E) -> Self {
+        Self {
+            evolver,
+            neighbours: 15,
+            objective: Objective::default(),
+        }
+    }
+
+    pub fn with_neighbours(mut self, neighbours: usize) -> Self {
+        self.neighbours = neighbours;
+        self
+    }
+
+    pub fn with_objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    pub fn score<P, I, F, B, Rng>(
+        &self,
+        population: &mut P,
+        archive: &mut Archive<B>,
+        rng: &mut Rng,
+    ) where
+        P: IterableMutPopulation<Individual = I> + ?Sized,
+        I: Individual<Fitness = Behavioural<F, B>>,
+        F: Numeric,
+        B: Behaviour,
+        Rng: rand::Rng + ?Sized,
+    {
+        let behaviours = population
+            .iter()
+            .map(|individual| individual.fitness().behaviour().clone())
+            .collect::<Vec<_>>();
+
+        let novelty = (0..behaviours.len())
+            .map(|index| archive.novelty(index, &behaviours, self.neighbours))
+            .collect::<Vec<_>>();
+
+        for (individual, novelty) in population.iter_mut().zip(&novelty) {
+            let fitness = individual.fitness().fitness().as_f64();
+
+            let score = match self.objective {
+                Objective::Novelty => *novelty,
+                Objective::Weighted(weight) => (1.0 - weight) * novelty + weight * fitness,
+            };
+
+            individual
+                .fitness_mut()
+                .set_novelty(*novelty, fitness, score);
+        }
+
+        for (behaviour, novelty) in behaviours.iter().zip(novelty) {
+            archive.insert(behaviour, novelty, rng);
+        }
+    }
+}
+
+impl<G, P, I, F, B, E> Evolver<NoveltyGeneration<G, B>> for NoveltySearch<E>
+where
+    G: Generation<Population = P>,
+    P: IterableMutPopulation<Individual = I>,
+    I: Individual<Fitness = Behavioural<F, B>>,
+    F: Numeric,
+    B: Behaviour,
+    E: Evolver<G>,
+{
+    type Error = NoveltySearchError<E::Error>;
+
+    fn evolve<Rng>(
+        &self,
+        generation: NoveltyGeneration<G, B>,
+        rng: &mut Rng,
+    ) -> Result<NoveltyGeneration<G, B>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.neighbours == 0 {
+            return Err(NoveltySearchError::InvalidNeighbours);
+        }
+
+        let NoveltyGeneration {
+            mut generation,
+            mut archive,
+        } = generation;
+
+        self.score(generation.population_mut(), &mut archive, rng);
+
+        let generation = self
+            .evolver
+            .evolve(generation, rng)
+            .map_err(NoveltySearchError::Evolve)?;
+
+        Ok(NoveltyGeneration::new(generation, archive))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NoveltySearchError<E> {
+    #[error("novelty requires at least one neighbour")]
+    InvalidNeighbours,
+    #[error(transparent)]
+    Evolve(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::generation::Generation;
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+    use crate::novelty::archive::{Archive, Insertion};
+    use crate::novelty::Behavioural;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::selector::lexicase::Lexicase;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::Selector;
+
+    use super::{NoveltyGeneration, NoveltySearch, NoveltySearchError, Objective};
+
+    type Novel = Evaluated<i32, Behavioural<i32, f64>>;
+
+    struct Noop;
+
+    impl<G> Evolver<G> for Noop
+    where
+        G: Generation,
+    {
+        type Error = Infallible;
+
+        fn evolve<Rng>(&self, generation: G, _: &mut Rng) -> Result<G, Self::Error>
+        where
+            Rng: rand::Rng + ?Sized,
+        {
+            Ok(generation.advanced())
+        }
+    }
+
+    fn evaluate(individual: &Novel) -> Result<Behavioural<i32, f64>, Infallible> {
+        let genome = *individual.genome();
+
+        Ok(Behavioural::new(-genome.abs(), genome as f64))
+    }
+
+    fn novel(genome: i32) -> Novel {
+        let individual = Evaluated::new(genome, Behavioural::new(0, 0.0));
+        let fitness = evaluate(&individual).unwrap();
+
+        individual.with_fitness(fitness)
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let a = NoveltySearch::new(Noop).with_neighbours(2);
+        let b = NoveltySearch::new(Noop)
+            .with_neighbours(2)
+            .with_objective(Objective::Weighted(0.5));
+
+        let initial = || {
+            NoveltyGeneration::new(
+                (0, vec![novel(0), novel(1), novel(3)]),
+                Archive::new(Insertion::Threshold(2.0)),
+            )
+        };
+
+        let (generation, archive) = a.evolve(initial(), &mut rng).unwrap().into_inner();
+        let (_, weighted) = b.evolve(initial(), &mut rng).unwrap().into_inner().0;
+        let (id, population) = generation;
+
+        assert_eq!(id, 1);
+        assert_eq!(
+            population
+                .iter()
+                .map(|individual| individual.fitness.novelty())
+                .collect::<Vec<_>>(),
+            [2.0, 1.5, 2.5]
+        );
+        assert_eq!(population[2].fitness.objectives(), [2.5, -3.0]);
+        assert_eq!(archive.behaviours(), [0.0, 3.0]);
+        assert_eq!(
+            weighted
+                .iter()
+                .map(|individual| individual.fitness.score())
+                .collect::<Vec<_>>(),
+            [1.0, 0.25, -0.25]
+        );
+    }
+
+    #[test]
+    fn test_evolve_search() {
+        let mut rng = rand::rng();
+
+        let population = (0..20).map(|_| novel(0)).collect::<Vec<_>>();
+
+        let a = NoveltySearch::new(
+            Tournament::binary()
+                .mutate(Noise::new(1..=3).evaluate_with(evaluate))
+                .fill()
+                .evolver(),
+        )
+        .with_neighbours(5);
+        let b = NoveltySearch::new(
+            Lexicase
+                .mutate(Noise::new(1..=3).evaluate_with(evaluate))
+                .fill()
+                .evolver(),
+        )
+        .with_neighbours(5);
+
+        let mut novelty = NoveltyGeneration::new(
+            (0, population.clone()),
+            Archive::new(Insertion::Random(0.1)),
+        );
+        let mut objectives =
+            NoveltyGeneration::new((0, population), Archive::new(Insertion::Threshold(1.0)));
+
+        for _ in 0..30 {
+            novelty = a.evolve(novelty, &mut rng).unwrap();
+            objectives = b.evolve(objectives, &mut rng).unwrap();
+        }
+
+        let spread = |population: &[Novel]| {
+            population.iter().map(|i| i.individual).max().unwrap()
+                - population.iter().map(|i| i.individual).min().unwrap()
+        };
+
+        assert!(spread(novelty.population()) > 5);
+        assert!(!novelty.archive().is_empty());
+        assert!(!objectives.archive().is_empty());
+        assert_eq!(objectives.population().len(), 20);
+        assert_eq!(objectives.id(), &30);
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = NoveltySearch::new(Noop).with_neighbours(0).evolve(
+            NoveltyGeneration::new((0, vec![novel(0)]), Archive::new(Insertion::Threshold(2.0))),
+            &mut rng,
+        );
+
+        assert_eq!(a.err(), Some(NoveltySearchError::InvalidNeighbours));
+    }
+}
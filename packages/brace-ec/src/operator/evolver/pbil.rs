@@ -0,0 +1,179 @@
+use crate::eda::{EdaError, EdaGeneration};
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::evaluator::Evaluator;
+use crate::util::iter::TryFromIterator;
+
+use super::Evolver;
+
+pub struct Pbil<E> {
+    evaluator: E,
+    size: usize,
+    rate: f64,
+    negative: f64,
+    mutation: f64,
+    shift: f64,
+}
+
+impl<E> Pbil<E> {
+    pub fn new(evaluator: E, size: usize) -> Self {
+        Self {
+            evaluator,
+            size,
+            rate: 0.1,
+            negative: 0.0,
+            mutation: 0.02,
+            shift: 0.05,
+        }
+    }
+
+    pub fn with_rate(mut self, rate: f64) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    pub fn with_negative_rate(mut self, negative: f64) -> Self {
+        self.negative = negative;
+        self
+    }
+
+    pub fn with_mutation(mut self, mutation: f64, shift: f64) -> Self {
+        self.mutation = mutation;
+        self.shift = shift;
+        self
+    }
+}
+
+impl<I, E> Evolver<EdaGeneration<I>> for Pbil<E>
+where
+    I: Individual<Genome: AsRef<[bool]>> + TryFromIterator<bool> + Clone,
+    E: Evaluator<I>,
+{
+    type Error = EdaError<E::Error>;
+
+    fn evolve<Rng>(
+        &self,
+        mut generation: EdaGeneration<I>,
+        rng: &mut Rng,
+    ) -> Result<EdaGeneration<I>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if generation.model().is_empty() {
+            return Err(EdaError::Empty);
+        }
+
+        if self.size < 2 {
+            return Err(EdaError::InvalidSize);
+        }
+
+        if [self.rate, self.negative, self.mutation, self.shift]
+            .iter()
+            .any(|rate| !(0.0..=1.0).contains(rate))
+        {
+            return Err(EdaError::InvalidRate);
+        }
+
+        let population = generation.sample(&self.evaluator, self.size, rng)?;
+
+        let best = population
+            .iter()
+            .max_by_key(|individual| individual.fitness())
+            .map(|individual| individual.genome().as_ref())
+            .unwrap_or_default();
+        let worst = population
+            .iter()
+            .min_by_key(|individual| individual.fitness())
+            .map(|individual| individual.genome().as_ref())
+            .unwrap_or_default();
+
+        let model = generation.model_mut();
+
+        for (index, bit) in best.iter().enumerate() {
+            model.shift(index, *bit, self.rate);
+
+            if self.negative > 0.0 && worst.get(index) != Some(bit) {
+                model.shift(index, *bit, self.negative);
+            }
+        }
+
+        for index in 0..model.len() {
+            if rng.random_bool(self.mutation) {
+                model.shift(index, rng.random_bool(0.5), self.shift);
+            }
+        }
+
+        generation.record(population);
+
+        Ok(generation.advanced())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eda::{EdaError, EdaGeneration, Model};
+    use crate::generation::Generation;
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evaluator::count::Count;
+    use crate::operator::evaluator::hiff::Hiff;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::repeat::Repeat;
+
+    use super::Pbil;
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let generation = Repeat::new(
+            Pbil::new(Count::new::<usize>(true), 20)
+                .with_rate(0.2)
+                .with_negative_rate(0.075),
+            100,
+        )
+        .evolve(
+            EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(16)),
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(generation.id(), &100);
+        assert_eq!(generation.population().len(), 20);
+        assert_eq!(generation.best().unwrap().fitness, 16);
+        assert_eq!(generation.model().mode(), [true; 16]);
+    }
+
+    #[test]
+    fn test_evolve_hiff() {
+        let mut rng = rand::rng();
+
+        let generation = Repeat::new(Pbil::new(Hiff, 50).with_mutation(0.0, 0.0), 100)
+            .evolve(
+                EdaGeneration::<Evaluated<[bool; 8], usize>>::new(Model::new(8)),
+                &mut rng,
+            )
+            .unwrap();
+
+        assert!(generation.best().unwrap().fitness >= 24);
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let generation = || EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(4));
+
+        let a = Pbil::new(Count::new::<usize>(true), 1).evolve(generation(), &mut rng);
+        let b = Pbil::new(Count::new::<usize>(true), 4)
+            .with_rate(1.5)
+            .evolve(generation(), &mut rng);
+        let c = Pbil::new(Count::new::<usize>(true), 4).evolve(
+            EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(0)),
+            &mut rng,
+        );
+
+        assert!(matches!(a, Err(EdaError::InvalidSize)));
+        assert!(matches!(b, Err(EdaError::InvalidRate)));
+        assert!(matches!(c, Err(EdaError::Empty)));
+    }
+}
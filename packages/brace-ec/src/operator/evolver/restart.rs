@@ -0,0 +1,395 @@
+use thiserror::Error;
+
+use crate::distance::{Genotypic, Metric};
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::generator::Generator;
+use crate::population::{IterablePopulation, Population};
+use crate::util::iter::TryFromIterator;
+
+use super::Evolver;
+
+#[derive(Clone, Debug)]
+pub struct RestartGeneration<G, I> {
+    generation: G,
+    best: Option<I>,
+    incumbent: Option<I>,
+    stagnation: usize,
+    restarts: usize,
+    size: Option<usize>,
+}
+
+impl<G, I> RestartGeneration<G, I>
+where
+    G: Generation<Population: Population<Individual = I>>,
+{
+    pub fn new(generation: G) -> Self {
+        Self {
+            generation,
+            best: None,
+            incumbent: None,
+            stagnation: 0,
+            restarts: 0,
+            size: None,
+        }
+    }
+
+    pub fn generation(&self) -> &G {
+        &self.generation
+    }
+
+    pub fn into_inner(self) -> G {
+        self.generation
+    }
+
+    pub fn best(&self) -> Option<&I> {
+        self.best.as_ref()
+    }
+
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    pub fn stagnation(&self) -> usize {
+        self.stagnation
+    }
+}
+
+impl<G, I> Generation for RestartGeneration<G, I>
+where
+    G: Generation<Population: Population<Individual = I>>,
+{
+    type Id = G::Id;
+    type Population = G::Population;
+
+    fn id(&self) -> &Self::Id {
+        self.generation.id()
+    }
+
+    fn population(&self) -> &Self::Population {
+        self.generation.population()
+    }
+
+    fn population_mut(&mut self) -> &mut Self::Population {
+        self.generation.population_mut()
+    }
+
+    fn advance(&mut self) {
+        self.generation.advance();
+    }
+}
+
+pub struct Restart<E, R, D = Genotypic> {
+    evolver: E,
+    generator: R,
+    stagnation: Option<usize>,
+    diversity: Option<f64>,
+    metric: D,
+    elitism: usize,
+    growth: f64,
+}
+
+impl<E, R> Restart<E, R> {
+    pub fn new(evolver: E, generator: R) -> Self {
+        Self {
+            evolver,
+            generator,
+            stagnation: None,
+            diversity: None,
+            metric: Genotypic,
+            elitism: 0,
+            growth: 1.0,
+        }
+    }
+}
+
+impl<E, R, D> Restart<E, R, D> {
+    pub fn with_stagnation(mut self, generations: usize) -> Self {
+        self.stagnation = Some(generations);
+        self
+    }
+
+    pub fn with_diversity(mut self, threshold: f64) -> Self {
+        self.diversity = Some(threshold);
+        self
+    }
+
+    pub fn with_distance<T>(self, metric: T) -> Restart<E, R, T> {
+        Restart {
+            evolver: self.evolver,
+            generator: self.generator,
+            stagnation: self.stagnation,
+            diversity: self.diversity,
+            metric,
+            elitism: self.elitism,
+            growth: self.growth,
+        }
+    }
+
+    pub fn with_elitism(mut self, elitism: usize) -> Self {
+        self.elitism = elitism;
+        self
+    }
+
+    pub fn with_growth(mut self, growth: f64) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    pub fn diversity<P, I>(&self, population: &P) -> f64
+    where
+        P: IterablePopulation<Individual = I> + ?Sized,
+        D: Metric<I>,
+    {
+        let individuals = population.iter().collect::<Vec<_>>();
+        let pairs = individuals.len() * individuals.len().saturating_sub(1) / 2;
+
+        if pairs == 0 {
+            return 0.0;
+        }
+
+        let total = individuals
+            .iter()
+            .enumerate()
+            .flat_map(|(index, lhs)| {
+                individuals[index + 1..]
+                    .iter()
+                    .map(|rhs| self.metric.measure(lhs, rhs))
+            })
+            .sum::<f64>();
+
+        total / pairs as f64
+    }
+}
+
+impl<G, P, I, E, R, D> Evolver<RestartGeneration<G, I>> for Restart<E, R, D>
+where
+    G: Generation<Population = P>,
+    P: IterablePopulation<Individual = I> + TryFromIterator<I>,
+    I: Individual + Clone,
+    E: Evolver<G>,
+    R: Generator<I>,
+    D: Metric<I>,
+{
+    type Error = RestartError<E::Error, R::Error, P::Error>;
+
+    fn evolve<Rng>(
+        &self,
+        mut generation: RestartGeneration<G, I>,
+        rng: &mut Rng,
+    ) -> Result<RestartGeneration<G, I>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.growth < 1.0 {
+            return Err(RestartError::InvalidGrowth);
+        }
+
+        generation.generation = self
+            .evolver
+            .evolve(generation.generation, rng)
+            .map_err(RestartError::Evolve)?;
+
+        let population = generation.generation.population();
+
+        let size = *generation.size.get_or_insert(population.len());
+
+        if let Some(fittest) = population
+            .iter()
+            .max_by_key(|individual| individual.fitness())
+        {
+            if generation
+                .best
+                .as_ref()
+                .is_none_or(|best| fittest.fitness() > best.fitness())
+            {
+                generation.best = Some(fittest.clone());
+            }
+
+            if generation
+                .incumbent
+                .as_ref()
+                .is_none_or(|incumbent| fittest.fitness() > incumbent.fitness())
+            {
+                generation.incumbent = Some(fittest.clone());
+                generation.stagnation = 0;
+            } else {
+                generation.stagnation += 1;
+            }
+        }
+
+        let stagnated = self
+            .stagnation
+            .is_some_and(|generations| generation.stagnation >= generations);
+        let converged = self
+            .diversity
+            .is_some_and(|threshold| self.diversity(population) < threshold);
+
+        if !stagnated && !converged {
+            return Ok(generation);
+        }
+
+        let size = (size as f64 * self.growth).ceil() as usize;
+
+        let mut elites = population.iter().collect::<Vec<_>>();
+
+        elites.sort_by(|a, b| b.fitness().cmp(a.fitness()));
+
+        let elites = elites
+            .into_iter()
+            .take(self.elitism.min(size))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let fresh = (elites.len()..size)
+            .map(|_| self.generator.generate(rng))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(RestartError::Generate)?;
+
+        *generation.generation.population_mut() =
+            P::try_from_iter(elites.into_iter().chain(fresh)).map_err(RestartError::Collect)?;
+
+        generation.size = Some(size);
+        generation.incumbent = None;
+        generation.stagnation = 0;
+        generation.restarts += 1;
+
+        Ok(generation)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RestartError<E, G, C> {
+    #[error("population growth factor must be at least one")]
+    InvalidGrowth,
+    #[error(transparent)]
+    Evolve(E),
+    #[error(transparent)]
+    Generate(G),
+    #[error(transparent)]
+    Collect(C),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::generation::Generation;
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::generator::Generator;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::Selector;
+
+    use super::{Restart, RestartError, RestartGeneration};
+
+    struct Constant(i32);
+
+    impl Generator<Evaluated<f64, i32>> for Constant {
+        type Error = Infallible;
+
+        fn generate<Rng>(&self, _: &mut Rng) -> Result<Evaluated<f64, i32>, Self::Error>
+        where
+            Rng: rand::Rng + ?Sized,
+        {
+            Ok(Evaluated::new(self.0 as f64, self.0))
+        }
+    }
+
+    fn population() -> Vec<Evaluated<f64, i32>> {
+        vec![
+            Evaluated::new(1.0, 1),
+            Evaluated::new(5.0, 5),
+            Evaluated::new(3.0, 3),
+        ]
+    }
+
+    #[test]
+    fn test_evolve_stagnation() {
+        let mut rng = rand::rng();
+
+        let evolver = Restart::new(Best.fill().evolver(), Constant(0))
+            .with_stagnation(2)
+            .with_elitism(1);
+
+        let mut generation = RestartGeneration::new((0, population()));
+
+        for _ in 0..3 {
+            generation = evolver.evolve(generation, &mut rng).unwrap();
+        }
+
+        assert_eq!(generation.restarts(), 1);
+        assert_eq!(generation.best(), Some(&Evaluated::new(5.0, 5)));
+        assert_eq!(
+            generation.into_inner(),
+            (
+                3,
+                vec![
+                    Evaluated::new(5.0, 5),
+                    Evaluated::new(0.0, 0),
+                    Evaluated::new(0.0, 0)
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_evolve_reuse() {
+        let mut rng = rand::rng();
+
+        let evolver = Restart::new(Best.fill().evolver(), Constant(9))
+            .with_diversity(0.5)
+            .with_growth(2.0);
+
+        let a = evolver
+            .evolve(RestartGeneration::new((0, population())), &mut rng)
+            .unwrap();
+        let b = evolver
+            .evolve(RestartGeneration::new((0, population())), &mut rng)
+            .unwrap();
+
+        assert_eq!(a.population().len(), 6);
+        assert_eq!(b.population().len(), 6);
+        assert_eq!(a.restarts(), 1);
+        assert_eq!(b.restarts(), 1);
+    }
+
+    #[test]
+    fn test_evolve_diversity() {
+        let mut rng = rand::rng();
+
+        let evolver = Restart::new(Best.fill().evolver(), Constant(9))
+            .with_diversity(0.5)
+            .with_growth(2.0);
+
+        let a = evolver
+            .evolve(RestartGeneration::new((0, population())), &mut rng)
+            .unwrap();
+        let b = evolver.evolve(a.clone(), &mut rng).unwrap();
+
+        assert_eq!(a.population().len(), 6);
+        assert_eq!(b.population().len(), 12);
+        assert_eq!(b.id(), &2);
+        assert_eq!(b.restarts(), 2);
+        assert_eq!(evolver.diversity(&population()), 8.0 / 3.0);
+        assert_eq!(b.best(), Some(&Evaluated::new(9.0, 9)));
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = Restart::new(Best.fill().evolver(), Constant(0))
+            .with_growth(0.5)
+            .evolve(RestartGeneration::new((0, population())), &mut rng);
+        let b = Restart::new(Best.fill().evolver(), Constant(0))
+            .with_diversity(1.0)
+            .evolve(
+                RestartGeneration::new((0, [Evaluated::new(0.0, 0); 2])),
+                &mut rng,
+            );
+
+        assert!(matches!(a, Err(RestartError::InvalidGrowth)));
+        assert_eq!(b.unwrap().into_inner(), (1, [Evaluated::new(0.0, 0); 2]));
+    }
+}
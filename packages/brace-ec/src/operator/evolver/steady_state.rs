@@ -0,0 +1,483 @@
+use rand::seq::index::sample;
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::aged::Aged;
+use crate::individual::Individual;
+use crate::operator::recombinator::Recombinator;
+use crate::operator::selector::Selector;
+use crate::population::IterablePopulation;
+use crate::util::iter::Iterable;
+
+use super::Evolver;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Replacement {
+    #[default]
+    Worst,
+    Random,
+    Oldest,
+    Parent,
+    InverseTournament(usize),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Advance {
+    #[default]
+    Step,
+    Population,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SteadyStateGeneration<I> {
+    id: usize,
+    population: Vec<Aged<I>>,
+    births: usize,
+}
+
+impl<I> SteadyStateGeneration<I> {
+    pub fn new(population: impl IntoIterator<Item = I>) -> Self {
+        let population = population
+            .into_iter()
+            .enumerate()
+            .map(|(birth, individual)| Aged::new(individual, birth))
+            .collect::<Vec<_>>();
+
+        Self {
+            id: 0,
+            births: population.len(),
+            population,
+        }
+    }
+
+    pub fn births(&self) -> usize {
+        self.births
+    }
+
+    pub fn individuals(&self) -> impl Iterator<Item = &I> {
+        self.population
+            .iter()
+            .map(|individual| &individual.individual)
+    }
+
+    pub fn into_individuals(self) -> Vec<I> {
+        self.population
+            .into_iter()
+            .map(|individual| individual.individual)
+            .collect()
+    }
+
+    fn insert(&mut self, index: usize, individual: I) {
+        self.population[index] = Aged::new(individual, self.births);
+        self.births += 1;
+    }
+}
+
+impl<I> Generation for SteadyStateGeneration<I>
+where
+    I: Individual,
+{
+    type Id = usize;
+    type Population = Vec<Aged<I>>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn population(&self) -> &Self::Population {
+        &self.population
+    }
+
+    fn population_mut(&mut self) -> &mut Self::Population {
+        &mut self.population
+    }
+
+    fn advance(&mut self) {
+        self.id += 1;
+    }
+}
+
+pub struct SteadyState<S, R> {
+    selector: S,
+    recombinator: R,
+    replacement: Replacement,
+    advance: Advance,
+    duplicates: bool,
+}
+
+impl<S, R> SteadyState<S, R> {
+    pub fn new(selector: S, recombinator: R) -> Self {
+        Self {
+            selector,
+            recombinator,
+            replacement: Replacement::default(),
+            advance: Advance::default(),
+            duplicates: true,
+        }
+    }
+
+    pub fn with_replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    pub fn with_advance(mut self, advance: Advance) -> Self {
+        self.advance = advance;
+        self
+    }
+
+    pub fn with_duplicates(mut self, duplicates: bool) -> Self {
+        self.duplicates = duplicates;
+        self
+    }
+
+    fn step<I, Rng>(
+        &self,
+        generation: &mut SteadyStateGeneration<I>,
+        rng: &mut Rng,
+    ) -> Result<usize, SteadyStateError<S::Error, R::Error>>
+    where
+        S: Selector<Vec<Aged<I>>, Output: IterablePopulation<Individual = Aged<I>>>,
+        R: Recombinator<S::Output, Output: IntoIterator<Item = Aged<I>>>,
+        I: Individual<Genome: PartialEq>,
+        Rng: rand::Rng + ?Sized,
+    {
+        let parents = self
+            .selector
+            .select(&generation.population, rng)
+            .map_err(SteadyStateError::Select)?;
+
+        let claimed = parents
+            .iter()
+            .map(|parent| parent.birth)
+            .collect::<Vec<_>>();
+
+        let offspring = self
+            .recombinator
+            .recombine(parents, rng)
+            .map_err(SteadyStateError::Recombine)?
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        if offspring.is_empty() {
+            return Err(SteadyStateError::NoOffspring);
+        }
+
+        let count = offspring.len();
+
+        for (position, child) in offspring.into_iter().enumerate() {
+            let cells = &generation.population;
+
+            if !self.duplicates
+                && cells
+                    .iter()
+                    .any(|individual| individual.genome() == child.genome())
+            {
+                continue;
+            }
+
+            let index = match self.replacement {
+                Replacement::Worst => worst(cells, 0..cells.len()),
+                Replacement::Random => Some(rng.random_range(0..cells.len())),
+                Replacement::Oldest => cells
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, individual)| individual.birth)
+                    .map(|(index, _)| index),
+                Replacement::Parent => claimed
+                    .get(position % claimed.len().max(1))
+                    .and_then(|birth| {
+                        cells
+                            .iter()
+                            .position(|individual| individual.birth == *birth)
+                    })
+                    .filter(|index| child.fitness() >= cells[*index].fitness()),
+                Replacement::InverseTournament(size) => {
+                    worst(cells, sample(rng, cells.len(), size.min(cells.len())))
+                }
+            };
+
+            if let Some(index) = index {
+                generation.insert(index, child.individual);
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+fn worst<I>(individuals: &[I], candidates: impl IntoIterator<Item = usize>) -> Option<usize>
+where
+    I: Individual,
+{
+    candidates
+        .into_iter()
+        .min_by(|a, b| individuals[*a].fitness().cmp(individuals[*b].fitness()))
+}
+
+impl<I, S, R> Evolver<SteadyStateGeneration<I>> for SteadyState<S, R>
+where
+    S: Selector<Vec<Aged<I>>, Output: IterablePopulation<Individual = Aged<I>>>,
+    R: Recombinator<S::Output, Output: IntoIterator<Item = Aged<I>>>,
+    I: Individual<Genome: PartialEq>,
+{
+    type Error = SteadyStateError<S::Error, R::Error>;
+
+    fn evolve<Rng>(
+        &self,
+        mut generation: SteadyStateGeneration<I>,
+        rng: &mut Rng,
+    ) -> Result<SteadyStateGeneration<I>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if generation.population.is_empty() {
+            return Err(SteadyStateError::Empty);
+        }
+
+        if self.replacement == Replacement::InverseTournament(0) {
+            return Err(SteadyStateError::InvalidTournament);
+        }
+
+        let target = match self.advance {
+            Advance::Step => 1,
+            Advance::Population => generation.population.len(),
+        };
+
+        let mut produced = 0;
+
+        while produced < target {
+            produced += self.step(&mut generation, rng)?;
+        }
+
+        Ok(generation.advanced())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SteadyStateError<S, R> {
+    #[error("empty population")]
+    Empty,
+    #[error("no offspring produced")]
+    NoOffspring,
+    #[error("inverse tournament size must be greater than zero")]
+    InvalidTournament,
+    #[error(transparent)]
+    Select(S),
+    #[error(transparent)]
+    Recombine(R),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::generation::Generation;
+    use crate::individual::aged::Aged;
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::Individual;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::recombinator::mutate::Mutate;
+    use crate::operator::recombinator::sum::Sum;
+    use crate::operator::repeat::Repeat;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::first::First;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::worst::Worst;
+    use crate::operator::selector::Selector;
+
+    use super::{Advance, Replacement, SteadyState, SteadyStateError, SteadyStateGeneration};
+
+    fn individuals<I>(generation: &SteadyStateGeneration<I>) -> Vec<I>
+    where
+        I: Clone,
+    {
+        generation.individuals().cloned().collect()
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let a = SteadyState::new(Best, Mutate::new(Add(1)))
+            .evolve(SteadyStateGeneration::new([1, 2, 3, 4]), &mut rng)
+            .unwrap();
+        let b = SteadyState::new(Best.and(Worst), Sum)
+            .evolve(SteadyStateGeneration::new([1, 2, 3, 4]), &mut rng)
+            .unwrap();
+        let c = SteadyState::new(Worst, Mutate::new(Add(1)))
+            .with_replacement(Replacement::Random)
+            .evolve(SteadyStateGeneration::new([1, 1, 1, 1]), &mut rng)
+            .unwrap();
+
+        assert_eq!(a.id(), &1);
+        assert_eq!(individuals(&a), [5, 2, 3, 4]);
+        assert_eq!(a.births(), 5);
+        assert_eq!(individuals(&b), [5, 2, 3, 4]);
+        assert_eq!(c.individuals().filter(|i| **i == 2).count(), 1);
+    }
+
+    #[test]
+    fn test_evolve_oldest() {
+        let mut rng = rand::rng();
+
+        let evolver =
+            SteadyState::new(First, Mutate::new(Add(10))).with_replacement(Replacement::Oldest);
+
+        let a = evolver
+            .evolve(SteadyStateGeneration::new([1, 2, 3]), &mut rng)
+            .unwrap();
+        let mut b = evolver.evolve(a.clone(), &mut rng).unwrap();
+
+        b.population_mut().reverse();
+
+        let c = evolver.evolve(b.clone(), &mut rng).unwrap();
+
+        assert_eq!(individuals(&a), [11, 2, 3]);
+        assert_eq!(individuals(&b), [3, 21, 11]);
+        assert_eq!(individuals(&c), [13, 21, 11]);
+        assert_eq!(c.population()[0].birth, 5);
+    }
+
+    #[test]
+    fn test_evolve_parent() {
+        let mut rng = rand::rng();
+
+        let population = [
+            Evaluated::new(1, 5),
+            Evaluated::new(2, 1),
+            Evaluated::new(3, 3),
+        ];
+
+        let a = SteadyState::new(Worst, Mutate::new(Add(10).evaluate_with(fitness)))
+            .with_replacement(Replacement::Parent)
+            .evolve(SteadyStateGeneration::new(population), &mut rng)
+            .unwrap();
+        let b = SteadyState::new(
+            Best,
+            Mutate::new(Add(10).evaluate_with(|_: &_| Ok::<_, Infallible>(0))),
+        )
+        .with_replacement(Replacement::Parent)
+        .evolve(SteadyStateGeneration::new(population), &mut rng)
+        .unwrap();
+        let c = SteadyState::new(
+            Worst.mutate(Add(10).evaluate_with(fitness)),
+            Mutate::new(Add(1).evaluate_with(fitness)),
+        )
+        .with_replacement(Replacement::Parent)
+        .evolve(SteadyStateGeneration::new(population), &mut rng)
+        .unwrap();
+
+        assert_eq!(individuals(&a)[1], Evaluated::new(12, 12));
+        assert_eq!(individuals(&b), population);
+        assert_eq!(
+            individuals(&c),
+            [
+                Evaluated::new(1, 5),
+                Evaluated::new(13, 13),
+                Evaluated::new(3, 3)
+            ]
+        );
+    }
+
+    fn fitness(individual: &Aged<Evaluated<i32, i32>>) -> Result<i32, Infallible> {
+        Ok(*individual.genome())
+    }
+
+    #[test]
+    fn test_evolve_inverse_tournament() {
+        let mut rng = rand::rng();
+
+        let a = SteadyState::new(Best, Mutate::new(Add(1)))
+            .with_replacement(Replacement::InverseTournament(4))
+            .evolve(SteadyStateGeneration::new([1, 2, 3, 4]), &mut rng)
+            .unwrap();
+        let b = SteadyState::new(Best, Mutate::new(Add(1)))
+            .with_replacement(Replacement::InverseTournament(2))
+            .evolve(SteadyStateGeneration::new([1, 2, 3, 4]), &mut rng)
+            .unwrap();
+        let c = SteadyState::new(Best, Mutate::new(Add(1)))
+            .with_replacement(Replacement::InverseTournament(0))
+            .evolve(SteadyStateGeneration::new([1, 2, 3, 4]), &mut rng);
+
+        assert_eq!(individuals(&a), [5, 2, 3, 4]);
+        assert!(individuals(&b).contains(&5) && individuals(&b).contains(&4));
+        assert_eq!(c, Err(SteadyStateError::InvalidTournament));
+    }
+
+    #[test]
+    fn test_evolve_duplicates() {
+        let mut rng = rand::rng();
+
+        let a = SteadyState::new(Best, Mutate::new(Add(0)))
+            .evolve(SteadyStateGeneration::new([1, 2, 3]), &mut rng)
+            .unwrap();
+        let b = SteadyState::new(Best, Mutate::new(Add(0)))
+            .with_duplicates(false)
+            .evolve(SteadyStateGeneration::new([1, 2, 3]), &mut rng)
+            .unwrap();
+
+        assert_eq!(individuals(&a), [3, 2, 3]);
+        assert_eq!(individuals(&b), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_evolve_advance() {
+        let mut rng = rand::rng();
+
+        let a = SteadyState::new(Best, Mutate::new(Add(1)))
+            .with_advance(Advance::Population)
+            .evolve(SteadyStateGeneration::new([1, 2, 3, 4]), &mut rng)
+            .unwrap();
+        let b = SteadyState::new(Best.and(Worst), Mutate::new(Add(1)))
+            .with_advance(Advance::Population)
+            .evolve(SteadyStateGeneration::new([1, 2, 3, 4, 5]), &mut rng)
+            .unwrap();
+
+        assert_eq!(a.id(), &1);
+        assert_eq!(individuals(&a), [5, 6, 7, 8]);
+        assert_eq!(b.id(), &1);
+        assert_eq!(b.individuals().max(), Some(&8));
+    }
+
+    #[test]
+    fn test_evolve_repeat() {
+        let mut rng = rand::rng();
+
+        let population = (0..20).map(|genome| Evaluated::new(genome, -genome));
+
+        let evolver = SteadyState::new(
+            Tournament::binary(),
+            Mutate::new(Noise::new(1..=2).evaluate_with(
+                |individual: &Aged<Evaluated<i32, i32>>| {
+                    Ok::<_, Infallible>(-individual.genome().abs())
+                },
+            )),
+        )
+        .with_replacement(Replacement::InverseTournament(2))
+        .with_advance(Advance::Population);
+
+        let generation = Repeat::new(evolver, 10)
+            .evolve(SteadyStateGeneration::new(population), &mut rng)
+            .unwrap();
+
+        assert_eq!(generation.id(), &10);
+        assert_eq!(generation.population().len(), 20);
+        assert!(generation
+            .individuals()
+            .all(|individual| individual.fitness == -individual.genome().abs()));
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = SteadyState::new(Best, Mutate::new(Add(1)))
+            .evolve(SteadyStateGeneration::new(Vec::<i32>::new()), &mut rng);
+
+        assert_eq!(a, Err(SteadyStateError::Empty));
+    }
+}
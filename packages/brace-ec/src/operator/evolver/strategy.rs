@@ -0,0 +1,256 @@
+use rand::seq::IteratorRandom;
+use thiserror::Error;
+
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::mutator::Mutator;
+use crate::operator::recombinator::Recombinator;
+use crate::operator::selector::truncation::{Truncation, TruncationError};
+use crate::operator::selector::Selector;
+use crate::population::IterablePopulation;
+use crate::util::iter::TryFromIterator;
+
+use super::Evolver;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Survival {
+    #[default]
+    Comma,
+    Plus,
+}
+
+pub struct EvolutionStrategy<R, M> {
+    recombinator: R,
+    mutator: M,
+    parents: usize,
+    offspring: Option<usize>,
+    survival: Survival,
+}
+
+impl<R, M> EvolutionStrategy<R, M> {
+    pub fn new(recombinator: R, mutator: M) -> Self {
+        Self {
+            recombinator,
+            mutator,
+            parents: 2,
+            offspring: None,
+            survival: Survival::default(),
+        }
+    }
+
+    pub fn with_parents(mut self, parents: usize) -> Self {
+        self.parents = parents;
+        self
+    }
+
+    pub fn with_offspring(mut self, offspring: usize) -> Self {
+        self.offspring = Some(offspring);
+        self
+    }
+
+    pub fn with_survival(mut self, survival: Survival) -> Self {
+        self.survival = survival;
+        self
+    }
+
+    pub fn offspring(&self, size: usize) -> usize {
+        self.offspring.unwrap_or(7 * size)
+    }
+}
+
+impl<G, P, I, R, M> Evolver<G> for EvolutionStrategy<R, M>
+where
+    G: Generation<Population = P>,
+    P: IterablePopulation<Individual = I> + TryFromIterator<I>,
+    I: Individual + Clone,
+    R: Recombinator<Vec<I>, Output: IntoIterator<Item = I>>,
+    M: Mutator<I>,
+{
+    type Error = EvolutionStrategyError<R::Error, M::Error, P::Error>;
+
+    fn evolve<Rng>(&self, generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let population = generation.population();
+        let size = population.len();
+        let count = self.offspring(size);
+
+        if size == 0 {
+            return Err(EvolutionStrategyError::Empty);
+        }
+
+        if self.parents == 0 || self.parents > size {
+            return Err(EvolutionStrategyError::InvalidParents);
+        }
+
+        if self.survival == Survival::Comma && count < size {
+            return Err(EvolutionStrategyError::InvalidOffspring);
+        }
+
+        let mut offspring = Vec::with_capacity(count);
+
+        while offspring.len() < count {
+            let parents = population
+                .iter()
+                .cloned()
+                .choose_multiple(rng, self.parents);
+            let children = self
+                .recombinator
+                .recombine(parents, rng)
+                .map_err(EvolutionStrategyError::Recombine)?
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            if children.is_empty() {
+                return Err(EvolutionStrategyError::NoOffspring);
+            }
+
+            for child in children.into_iter().take(count - offspring.len()) {
+                offspring.push(
+                    self.mutator
+                        .mutate(child, rng)
+                        .map_err(EvolutionStrategyError::Mutate)?,
+                );
+            }
+        }
+
+        if self.survival == Survival::Plus {
+            offspring.extend(population.iter().cloned());
+        }
+
+        let survivors = Truncation::new(size)
+            .select(&offspring, rng)
+            .map_err(EvolutionStrategyError::Truncate)?;
+
+        let population = P::try_from_iter(survivors).map_err(EvolutionStrategyError::Collect)?;
+
+        Ok(generation.advanced_with(population))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EvolutionStrategyError<R, M, C> {
+    #[error("empty population")]
+    Empty,
+    #[error("number of parents must be between one and the population size")]
+    InvalidParents,
+    #[error("comma selection requires at least as many offspring as parents")]
+    InvalidOffspring,
+    #[error("recombinator produced no offspring")]
+    NoOffspring,
+    #[error(transparent)]
+    Recombine(R),
+    #[error(transparent)]
+    Mutate(M),
+    #[error(transparent)]
+    Truncate(TruncationError),
+    #[error(transparent)]
+    Collect(C),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use ordered_float::OrderedFloat;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::self_adaptive::{Parameters, SelfAdaptive};
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::log_normal::LogNormal;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::recombinator::self_adaptive::StrategyCrossover;
+
+    use super::{EvolutionStrategy, EvolutionStrategyError, Survival};
+
+    type Solution = SelfAdaptive<Evaluated<Vec<f64>, OrderedFloat<f64>>>;
+
+    fn sphere(individual: &Solution) -> Result<OrderedFloat<f64>, Infallible> {
+        Ok(OrderedFloat(
+            -individual
+                .individual
+                .individual
+                .iter()
+                .map(|gene| gene * gene)
+                .sum::<f64>(),
+        ))
+    }
+
+    fn population(parameters: Parameters) -> Vec<Solution> {
+        (0..5)
+            .map(|index| {
+                let individual = SelfAdaptive::new(
+                    Evaluated::new(vec![index as f64 + 1.0; 3], OrderedFloat(0.0)),
+                    parameters.clone(),
+                );
+                let fitness = sphere(&individual).unwrap();
+
+                SelfAdaptive::new(
+                    Evaluated::new(individual.individual.individual, fitness),
+                    individual.parameters,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        for survival in [Survival::Comma, Survival::Plus] {
+            for parameters in [
+                Parameters::single(1.0),
+                Parameters::genewise(3, 1.0),
+                Parameters::correlated(3, 1.0),
+            ] {
+                let population = population(parameters);
+                let initial = population[0].individual.fitness;
+                let evolver = EvolutionStrategy::new(
+                    StrategyCrossover::default(),
+                    LogNormal::new().evaluate_with(sphere),
+                )
+                .with_survival(survival);
+
+                let mut generation = (0, population);
+
+                for _ in 0..50 {
+                    generation = evolver.evolve(generation, &mut rng).unwrap();
+                }
+
+                let (id, population) = generation;
+                let best = population
+                    .iter()
+                    .map(|individual| individual.individual.fitness)
+                    .max()
+                    .unwrap();
+
+                assert_eq!(id, 50);
+                assert_eq!(population.len(), 5);
+                assert!(best > initial);
+                assert!(best > OrderedFloat(-0.1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_evolve_error() {
+        let mut rng = rand::rng();
+        let population = population(Parameters::single(1.0));
+
+        let a = EvolutionStrategy::new(StrategyCrossover::default(), LogNormal::new())
+            .with_parents(6)
+            .evolve((0, population.clone()), &mut rng);
+        let b = EvolutionStrategy::new(StrategyCrossover::default(), LogNormal::new())
+            .with_offspring(4)
+            .evolve((0, population.clone()), &mut rng);
+        let c = EvolutionStrategy::new(StrategyCrossover::default(), LogNormal::new())
+            .with_offspring(4)
+            .with_survival(Survival::Plus)
+            .evolve((0, population), &mut rng);
+
+        assert_eq!(a, Err(EvolutionStrategyError::InvalidParents));
+        assert_eq!(b, Err(EvolutionStrategyError::InvalidOffspring));
+        assert!(c.is_ok());
+    }
+}
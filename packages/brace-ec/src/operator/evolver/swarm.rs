@@ -0,0 +1,354 @@
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+
+use crate::fitness::Fitness;
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::evaluator::Evaluator;
+use crate::swarm::particle::Particle;
+use crate::swarm::topology::Topology;
+use crate::swarm::Swarm;
+
+use super::Evolver;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Velocity {
+    Inertia {
+        inertia: f64,
+        cognitive: f64,
+        social: f64,
+    },
+    Constriction {
+        cognitive: f64,
+        social: f64,
+    },
+}
+
+impl Velocity {
+    pub fn inertia(inertia: f64, cognitive: f64, social: f64) -> Self {
+        Self::Inertia {
+            inertia,
+            cognitive,
+            social,
+        }
+    }
+
+    pub fn constriction(cognitive: f64, social: f64) -> Self {
+        Self::Constriction { cognitive, social }
+    }
+
+    fn coefficients(&self) -> Option<(f64, f64, f64, f64)> {
+        match *self {
+            Self::Inertia {
+                inertia,
+                cognitive,
+                social,
+            } => Some((1.0, inertia, cognitive, social)),
+            Self::Constriction { cognitive, social } => {
+                let phi = cognitive + social;
+
+                if phi <= 4.0 {
+                    return None;
+                }
+
+                let chi = 2.0 / (2.0 - phi - (phi * phi - 4.0 * phi).sqrt()).abs();
+
+                Some((chi, 1.0, cognitive, social))
+            }
+        }
+    }
+}
+
+impl Default for Velocity {
+    fn default() -> Self {
+        Self::inertia(0.7298, 1.49618, 1.49618)
+    }
+}
+
+pub struct ParticleSwarm<E> {
+    evaluator: E,
+    topology: Topology,
+    velocity: Velocity,
+    bounds: Option<Vec<RangeInclusive<f64>>>,
+    limit: Option<f64>,
+}
+
+impl<E> ParticleSwarm<E> {
+    pub fn new(evaluator: E) -> Self {
+        Self {
+            evaluator,
+            topology: Topology::default(),
+            velocity: Velocity::default(),
+            bounds: None,
+            limit: None,
+        }
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn with_velocity(mut self, velocity: Velocity) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<RangeInclusive<f64>>) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    pub fn with_velocity_limit(mut self, limit: f64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn evaluate<F, Rng>(
+        &self,
+        particle: &mut Particle<F>,
+        rng: &mut Rng,
+    ) -> Result<(), ParticleSwarmError<E::Error>>
+    where
+        F: Fitness + Clone,
+        E: Evaluator<Particle<F>>,
+        Rng: rand::Rng + ?Sized,
+    {
+        *particle.fitness_mut() = self
+            .evaluator
+            .evaluate(particle, rng)
+            .map_err(ParticleSwarmError::Evaluate)?;
+
+        particle.remember();
+
+        Ok(())
+    }
+}
+
+impl<F, E> Evolver<Swarm<F>> for ParticleSwarm<E>
+where
+    F: Fitness + Clone,
+    E: Evaluator<Particle<F>>,
+{
+    type Error = ParticleSwarmError<E::Error>;
+
+    fn evolve<Rng>(&self, mut generation: Swarm<F>, rng: &mut Rng) -> Result<Swarm<F>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let (scale, inertia, cognitive, social) = self
+            .velocity
+            .coefficients()
+            .ok_or(ParticleSwarmError::InvalidVelocity)?;
+
+        let particles = generation.population_mut();
+        let dimension = particles
+            .first()
+            .ok_or(ParticleSwarmError::Empty)?
+            .position()
+            .len();
+
+        if particles.iter().any(|particle| {
+            particle.position().len() != dimension || particle.velocity().len() != dimension
+        }) {
+            return Err(ParticleSwarmError::InvalidDimension);
+        }
+
+        if let Some(bounds) = &self.bounds {
+            if bounds.len() != dimension {
+                return Err(ParticleSwarmError::InvalidDimension);
+            }
+
+            if bounds.iter().any(|bound| bound.is_empty()) {
+                return Err(ParticleSwarmError::InvalidBounds);
+            }
+        }
+
+        for particle in particles.iter_mut() {
+            if particle.best().is_none() {
+                if let Some(bounds) = &self.bounds {
+                    for (x, bound) in particle.genome_mut().iter_mut().zip(bounds) {
+                        *x = x.clamp(*bound.start(), *bound.end());
+                    }
+                }
+
+                self.evaluate(particle, rng)?;
+            }
+        }
+
+        let guides = (0..particles.len())
+            .map(|index| {
+                self.topology
+                    .neighbours(index, particles.len())
+                    .into_iter()
+                    .filter_map(|neighbour| particles[neighbour].best())
+                    .max_by_key(|(_, fitness)| *fitness)
+                    .map(|(position, _)| position.to_vec())
+                    .ok_or(ParticleSwarmError::Empty)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (particle, guide) in particles.iter_mut().zip(guides) {
+            let personal = particle
+                .best()
+                .map(|(position, _)| position.to_vec())
+                .ok_or(ParticleSwarmError::Empty)?;
+
+            let (position, velocity) = particle.parts_mut();
+
+            for (index, (x, v)) in position.iter_mut().zip(velocity.iter_mut()).enumerate() {
+                let attraction = cognitive * rng.random::<f64>() * (personal[index] - *x)
+                    + social * rng.random::<f64>() * (guide[index] - *x);
+
+                *v = scale * (inertia * *v + attraction);
+
+                if let Some(limit) = self.limit {
+                    *v = v.clamp(-limit, limit);
+                }
+
+                *x += *v;
+
+                if let Some(bound) = self.bounds.as_ref().map(|bounds| &bounds[index]) {
+                    if !bound.contains(x) {
+                        *x = x.clamp(*bound.start(), *bound.end());
+                        *v = 0.0;
+                    }
+                }
+            }
+
+            self.evaluate(particle, rng)?;
+        }
+
+        Ok(generation.advanced())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParticleSwarmError<E> {
+    #[error("empty swarm")]
+    Empty,
+    #[error("invalid dimension")]
+    InvalidDimension,
+    #[error("invalid bounds")]
+    InvalidBounds,
+    #[error("invalid velocity coefficients")]
+    InvalidVelocity,
+    #[error(transparent)]
+    Evaluate(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::cmp::Reverse;
+    use std::convert::Infallible;
+
+    use ordered_float::OrderedFloat;
+
+    use crate::generation::Generation;
+    use crate::individual::Individual;
+    use crate::operator::evaluator::function::Function;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::generator::random::Random;
+    use crate::operator::generator::Generator;
+    use crate::swarm::particle::Particle;
+    use crate::swarm::topology::Topology;
+    use crate::swarm::Swarm;
+
+    use super::{ParticleSwarm, ParticleSwarmError, Velocity};
+
+    type Fitness = Reverse<OrderedFloat<f64>>;
+
+    fn sphere(particle: &Particle<Fitness>) -> Result<Fitness, Infallible> {
+        Ok(Reverse(OrderedFloat(
+            particle.genome().iter().map(|x| (x - 1.0).powi(2)).sum(),
+        )))
+    }
+
+    fn swarm(count: usize) -> Swarm<Fitness> {
+        Random::from(-5.0..5.0)
+            .populate(4)
+            .populate(count)
+            .generate(&mut rand::rng())
+            .map(Swarm::new)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        for topology in [Topology::Global, Topology::ring(), Topology::VonNeumann] {
+            for velocity in [Velocity::default(), Velocity::constriction(2.05, 2.05)] {
+                let generation = ParticleSwarm::new(Function::new(sphere))
+                    .with_topology(topology)
+                    .with_velocity(velocity)
+                    .repeat(300)
+                    .evolve(swarm(25), &mut rng)
+                    .unwrap();
+
+                let (position, fitness) = generation.best().unwrap();
+
+                assert_eq!(generation.id(), &300);
+                assert!(fitness.0 .0 < 1e-6, "{topology:?} {velocity:?}");
+                assert!(position.iter().all(|x| (x - 1.0).abs() < 1e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn test_evolve_bounds() {
+        let mut rng = rand::rng();
+        let count = Cell::new(0);
+
+        let generation = ParticleSwarm::new(Function::new(sphere))
+            .with_bounds(vec![-5.0..=0.0; 4])
+            .with_velocity_limit(0.5)
+            .inspect(|generation: &Swarm<Fitness>| {
+                count.set(count.get() + 1);
+
+                assert!(generation.particles().iter().all(|particle| {
+                    particle.position().iter().all(|x| (-5.0..=0.0).contains(x))
+                        && particle.velocity().iter().all(|v| v.abs() <= 0.5)
+                }));
+            })
+            .limit(100)
+            .repeat(200)
+            .evolve(swarm(20), &mut rng)
+            .unwrap();
+
+        assert_eq!(generation.id(), &100);
+        assert_eq!(count.get(), 100);
+        assert!(generation.best().unwrap().0.iter().all(|x| x.abs() < 1e-3));
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let a = ParticleSwarm::new(Function::new(sphere)).evolve(Swarm::new(Vec::new()), &mut rng);
+        let b = ParticleSwarm::new(Function::new(sphere))
+            .with_velocity(Velocity::constriction(1.0, 1.0))
+            .evolve(swarm(5), &mut rng);
+        let c = ParticleSwarm::new(Function::new(sphere))
+            .with_bounds(vec![0.0..=1.0])
+            .evolve(swarm(5), &mut rng);
+        let d = ParticleSwarm::new(Function::new(sphere))
+            .with_bounds(vec![1.0..=0.0; 4])
+            .evolve(swarm(5), &mut rng);
+        let e = ParticleSwarm::new(Function::new(sphere)).evolve(
+            Swarm::new(vec![
+                Particle::new(vec![0.0]),
+                Particle::new(vec![0.0, 1.0]),
+            ]),
+            &mut rng,
+        );
+
+        assert_eq!(a, Err(ParticleSwarmError::Empty));
+        assert_eq!(b, Err(ParticleSwarmError::InvalidVelocity));
+        assert_eq!(c, Err(ParticleSwarmError::InvalidDimension));
+        assert_eq!(d, Err(ParticleSwarmError::InvalidBounds));
+        assert_eq!(e, Err(ParticleSwarmError::InvalidDimension));
+    }
+}
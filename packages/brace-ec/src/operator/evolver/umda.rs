@@ -0,0 +1,156 @@
+use crate::eda::{EdaError, EdaGeneration, Model};
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::operator::evaluator::Evaluator;
+use crate::util::iter::TryFromIterator;
+
+use super::Evolver;
+
+pub struct Umda<E> {
+    evaluator: E,
+    size: usize,
+    selected: Option<usize>,
+    margin: f64,
+}
+
+impl<E> Umda<E> {
+    pub fn new(evaluator: E, size: usize) -> Self {
+        Self {
+            evaluator,
+            size,
+            selected: None,
+            margin: 0.0,
+        }
+    }
+
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    pub fn with_margin(mut self, margin: f64) -> Self {
+        self.margin = margin;
+        self
+    }
+}
+
+impl<I, E> Evolver<EdaGeneration<I>> for Umda<E>
+where
+    I: Individual<Genome: AsRef<[bool]>> + TryFromIterator<bool> + Clone,
+    E: Evaluator<I>,
+{
+    type Error = EdaError<E::Error>;
+
+    fn evolve<Rng>(
+        &self,
+        mut generation: EdaGeneration<I>,
+        rng: &mut Rng,
+    ) -> Result<EdaGeneration<I>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let selected = self.selected.unwrap_or(self.size / 2);
+
+        if generation.model().is_empty() {
+            return Err(EdaError::Empty);
+        }
+
+        if selected == 0 || selected > self.size {
+            return Err(EdaError::InvalidSize);
+        }
+
+        let mut population = generation.sample(&self.evaluator, self.size, rng)?;
+
+        population.sort_by(|a, b| b.fitness().cmp(a.fitness()));
+
+        let frequencies = Model::frequencies(
+            population[..selected]
+                .iter()
+                .map(|individual| individual.genome()),
+            generation.model().len(),
+        );
+
+        let model = generation.model_mut();
+
+        model.learn(&frequencies, 1.0);
+        model.restrict(self.margin);
+
+        generation.record(population);
+
+        Ok(generation.advanced())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eda::{EdaError, EdaGeneration, Model};
+    use crate::generation::Generation;
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evaluator::count::Count;
+    use crate::operator::evaluator::hiff::Hiff;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::repeat::Repeat;
+
+    use super::Umda;
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = rand::rng();
+
+        let generation = Repeat::new(Umda::new(Count::new::<usize>(true), 50), 30)
+            .evolve(
+                EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(16)),
+                &mut rng,
+            )
+            .unwrap();
+
+        assert_eq!(generation.id(), &30);
+        assert_eq!(generation.population().len(), 50);
+        assert_eq!(generation.best().unwrap().fitness, 16);
+        assert!(generation.model().probabilities().iter().all(|p| *p > 0.9));
+    }
+
+    #[test]
+    fn test_evolve_margin() {
+        let mut rng = rand::rng();
+
+        let generation = Repeat::new(Umda::new(Hiff, 100).with_selected(20).with_margin(0.05), 20)
+            .evolve(
+                EdaGeneration::<Evaluated<[bool; 8], usize>>::new(Model::new(8)),
+                &mut rng,
+            )
+            .unwrap();
+
+        assert!(generation
+            .model()
+            .probabilities()
+            .iter()
+            .all(|p| (0.05..=0.95).contains(p)));
+        assert!(generation.best().unwrap().fitness >= 24);
+    }
+
+    #[test]
+    fn test_evolve_errors() {
+        let mut rng = rand::rng();
+
+        let generation = || EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(4));
+
+        let a = Umda::new(Count::new::<usize>(true), 1).evolve(generation(), &mut rng);
+        let b = Umda::new(Count::new::<usize>(true), 4)
+            .with_selected(5)
+            .evolve(generation(), &mut rng);
+        let c = Umda::new(Count::new::<usize>(true), 4).evolve(
+            EdaGeneration::<Evaluated<Vec<bool>, usize>>::new(Model::new(0)),
+            &mut rng,
+        );
+        let d = Umda::new(Count::new::<usize>(true), 4).evolve(
+            EdaGeneration::<Evaluated<[bool; 3], usize>>::new(Model::new(4)),
+            &mut rng,
+        );
+
+        assert!(matches!(a, Err(EdaError::InvalidSize)));
+        assert!(matches!(b, Err(EdaError::InvalidSize)));
+        assert!(matches!(c, Err(EdaError::Empty)));
+        assert!(matches!(d, Err(EdaError::Sample(_))));
+    }
+}
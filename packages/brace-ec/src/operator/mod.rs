@@ -1,3 +1,4 @@
+pub mod adaptive;
 pub mod either;
 pub mod evaluate;
 pub mod evaluator;
@@ -5,8 +6,12 @@ pub mod evolver;
 pub mod generator;
 pub mod inspect;
 pub mod mutator;
+pub mod neighbourhood;
 pub mod recombinator;
+pub mod repair;
+pub mod repairer;
 pub mod repeat;
+pub mod scheduled;
 pub mod selector;
 pub mod then;
 pub mod weighted;
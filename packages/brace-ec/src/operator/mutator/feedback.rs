@@ -0,0 +1,74 @@
+use crate::individual::Individual;
+use crate::schedule::feedback::OneFifth;
+
+use super::Mutator;
+
+pub struct Feedback<M> {
+    mutator: M,
+    rule: OneFifth,
+}
+
+impl<M> Feedback<M> {
+    pub fn new(mutator: M, rule: OneFifth) -> Self {
+        Self { mutator, rule }
+    }
+}
+
+impl<T, M> Mutator<T> for Feedback<M>
+where
+    M: Mutator<T>,
+    T: Individual + Clone,
+{
+    type Error = M::Error;
+
+    fn mutate<Rng>(&self, individual: T, rng: &mut Rng) -> Result<T, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let parent = individual.clone();
+        let child = self.mutator.mutate(individual, rng)?;
+
+        self.rule.record(child.fitness() > parent.fitness());
+
+        Ok(child)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::Mutator;
+    use crate::schedule::feedback::OneFifth;
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = rand::rng();
+        let rule = OneFifth::new(1.0).with_factor(0.5).with_interval(2);
+        let improve = Add(1)
+            .evaluate_with(|individual: &Evaluated<i32, i32>| {
+                Ok::<_, Infallible>(individual.individual)
+            })
+            .feedback(rule.clone());
+        let worsen = Add(-1)
+            .evaluate_with(|individual: &Evaluated<i32, i32>| {
+                Ok::<_, Infallible>(individual.individual)
+            })
+            .feedback(rule.clone());
+
+        let a = improve.mutate(Evaluated::new(0, 0), &mut rng);
+
+        assert_eq!(a.map(|individual| individual.individual), Ok(1));
+
+        let _ = improve.mutate(Evaluated::new(0, 0), &mut rng);
+
+        assert_eq!(rule.get(), 2.0);
+
+        let _ = worsen.mutate(Evaluated::new(0, 0), &mut rng);
+        let _ = worsen.mutate(Evaluated::new(0, 0), &mut rng);
+
+        assert_eq!(rule.get(), 1.0);
+    }
+}
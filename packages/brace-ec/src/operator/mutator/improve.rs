@@ -0,0 +1,75 @@
+use crate::individual::Individual;
+
+use super::Mutator;
+
+pub struct Improve<M> {
+    mutator: M,
+    steps: usize,
+}
+
+impl<M> Improve<M> {
+    pub fn new(mutator: M, steps: usize) -> Self {
+        Self { mutator, steps }
+    }
+}
+
+impl<T, M> Mutator<T> for Improve<M>
+where
+    T: Individual + Clone,
+    M: Mutator<T>,
+{
+    type Error = M::Error;
+
+    fn mutate<Rng>(&self, individual: T, rng: &mut Rng) -> Result<T, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        (0..self.steps).try_fold(individual, |prev, _| {
+            let next = self.mutator.mutate(prev.clone(), rng)?;
+
+            if next.fitness() > prev.fitness() {
+                Ok(next)
+            } else {
+                Ok(prev)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::Mutator;
+
+    fn evaluate(individual: &Evaluated<i32, i32>) -> Result<i32, Infallible> {
+        Ok(-(individual.individual - 10).abs())
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = rand::rng();
+
+        let a = Add(1)
+            .evaluate_with(evaluate)
+            .improve(5)
+            .mutate(Evaluated::new(0, -10), &mut rng)
+            .unwrap();
+        let b = Add(1)
+            .evaluate_with(evaluate)
+            .improve(50)
+            .mutate(Evaluated::new(0, -10), &mut rng)
+            .unwrap();
+        let c = Add(-1)
+            .evaluate_with(evaluate)
+            .improve(5)
+            .mutate(Evaluated::new(0, -10), &mut rng)
+            .unwrap();
+
+        assert_eq!(a, Evaluated::new(5, -5));
+        assert_eq!(b, Evaluated::new(10, 0));
+        assert_eq!(c, Evaluated::new(0, -10));
+    }
+}
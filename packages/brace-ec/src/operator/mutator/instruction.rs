@@ -0,0 +1,205 @@
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+
+use crate::individual::Individual;
+use crate::linear::instruction::{Instruction, InstructionSet, InstructionSetError};
+use crate::operator::generator::Generator;
+
+use super::Mutator;
+
+#[derive(Clone, Debug)]
+pub struct MicroMutation {
+    instructions: InstructionSet,
+}
+
+impl MicroMutation {
+    pub fn new(instructions: InstructionSet) -> Self {
+        Self { instructions }
+    }
+}
+
+impl<I> Mutator<I> for MicroMutation
+where
+    I: Individual<Genome: AsMut<[Instruction]>>,
+{
+    type Error = InstructionSetError;
+
+    fn mutate<Rng>(&self, mut individual: I, rng: &mut Rng) -> Result<I, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let program = individual.genome_mut().as_mut();
+
+        if program.is_empty() {
+            return Ok(individual);
+        }
+
+        let instruction = &mut program[rng.random_range(0..program.len())];
+
+        match rng.random_range(0..3) {
+            0 => instruction.operation = self.instructions.random_operation(rng)?,
+            1 => instruction.target = self.instructions.random_target(rng)?,
+            _ => match rng.random_bool(0.5) {
+                true => instruction.lhs = self.instructions.random_operand(rng)?,
+                false => instruction.rhs = self.instructions.random_operand(rng)?,
+            },
+        }
+
+        Ok(individual)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MacroMutation {
+    instructions: InstructionSet,
+    length: RangeInclusive<usize>,
+    insertion: f64,
+}
+
+impl MacroMutation {
+    pub fn new(instructions: InstructionSet, length: RangeInclusive<usize>) -> Self {
+        Self {
+            instructions,
+            length,
+            insertion: 0.5,
+        }
+    }
+
+    pub fn with_insertion_rate(mut self, rate: f64) -> Self {
+        self.insertion = rate;
+        self
+    }
+}
+
+impl<I> Mutator<I> for MacroMutation
+where
+    I: Individual<Genome = Vec<Instruction>>,
+{
+    type Error = MacroMutationError;
+
+    fn mutate<Rng>(&self, mut individual: I, rng: &mut Rng) -> Result<I, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.length.is_empty() {
+            return Err(MacroMutationError::InvalidLength);
+        }
+
+        let program = individual.genome_mut();
+        let len = program.len();
+
+        let insert = match (len < *self.length.end(), len > *self.length.start()) {
+            (true, true) => rng.random_bool(self.insertion),
+            (true, false) => true,
+            (false, true) => false,
+            (false, false) => return Ok(individual),
+        };
+
+        if insert {
+            let instruction = self
+                .instructions
+                .generate(rng)
+                .map_err(MacroMutationError::Generate)?;
+
+            program.insert(rng.random_range(0..=len), instruction);
+        } else {
+            program.remove(rng.random_range(0..len));
+        }
+
+        Ok(individual)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MacroMutationError {
+    #[error("empty program length range")]
+    InvalidLength,
+    #[error(transparent)]
+    Generate(InstructionSetError),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linear::instruction::{Instruction, InstructionSet, Operand, Operation};
+    use crate::linear::program::Program;
+    use crate::operator::mutator::Mutator;
+
+    use super::{MacroMutation, MacroMutationError, MicroMutation};
+
+    fn program(len: usize) -> Program {
+        Program::new(vec![
+            Instruction::new(
+                Operation::Add,
+                0,
+                Operand::Register(0),
+                Operand::Input(0)
+            );
+            len
+        ])
+    }
+
+    #[test]
+    fn test_mutate_micro() {
+        let mut rng = rand::rng();
+
+        let set = InstructionSet::new(2, 1).with_operations([Operation::Mul]);
+        let mutator = MicroMutation::new(set);
+
+        let mut changed = false;
+
+        for _ in 0..100 {
+            let original = program(4);
+            let mutated = mutator.mutate(original.clone(), &mut rng).unwrap();
+            let diff = original
+                .instructions()
+                .iter()
+                .zip(mutated.instructions())
+                .filter(|(a, b)| a != b)
+                .count();
+
+            assert!(diff <= 1);
+            assert_eq!(mutated.len(), 4);
+
+            changed |= diff == 1;
+        }
+
+        assert!(changed);
+
+        let a = mutator.mutate(program(0), &mut rng).unwrap();
+
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn test_mutate_macro() {
+        let mut rng = rand::rng();
+
+        let set = InstructionSet::new(2, 1);
+
+        for _ in 0..100 {
+            let a = MacroMutation::new(set.clone(), 2..=6)
+                .mutate(program(4), &mut rng)
+                .unwrap();
+            let b = MacroMutation::new(set.clone(), 2..=6)
+                .mutate(program(2), &mut rng)
+                .unwrap();
+            let c = MacroMutation::new(set.clone(), 2..=6)
+                .mutate(program(6), &mut rng)
+                .unwrap();
+            let d = MacroMutation::new(set.clone(), 4..=4)
+                .mutate(program(4), &mut rng)
+                .unwrap();
+
+            assert!(a.len() == 3 || a.len() == 5);
+            assert_eq!(b.len(), 3);
+            assert_eq!(c.len(), 5);
+            assert_eq!(d.len(), 4);
+        }
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let e = MacroMutation::new(set, 4..=2).mutate(program(3), &mut rng);
+
+        assert_eq!(e, Err(MacroMutationError::InvalidLength));
+    }
+}
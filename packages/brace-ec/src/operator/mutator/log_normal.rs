@@ -0,0 +1,199 @@
+use std::f64::consts::PI;
+
+use thiserror::Error;
+
+use crate::chromosome::Chromosome;
+use crate::individual::self_adaptive::{Parameters, SelfAdaptive};
+use crate::individual::Individual;
+use crate::util::random::normal;
+
+use super::Mutator;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogNormal {
+    global: Option<f64>,
+    local: Option<f64>,
+    beta: f64,
+    minimum: f64,
+}
+
+impl LogNormal {
+    pub fn new() -> Self {
+        Self {
+            global: None,
+            local: None,
+            beta: 5.0_f64.to_radians(),
+            minimum: 1e-10,
+        }
+    }
+
+    pub fn with_learning_rates(mut self, global: f64, local: f64) -> Self {
+        self.global = Some(global);
+        self.local = Some(local);
+        self
+    }
+
+    pub fn with_beta(mut self, beta: f64) -> Self {
+        self.beta = beta;
+        self
+    }
+
+    pub fn with_minimum(mut self, minimum: f64) -> Self {
+        self.minimum = minimum;
+        self
+    }
+
+    fn adapt<Rng>(&self, parameters: &mut Parameters, dimension: usize, rng: &mut Rng)
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let n = dimension as f64;
+
+        match parameters {
+            Parameters::Single(sigma) => {
+                let rate = self.global.unwrap_or(1.0 / n.sqrt());
+
+                *sigma = (*sigma * (rate * normal(rng, 0.0, 1.0)).exp()).max(self.minimum);
+            }
+            Parameters::Genewise(sigmas) | Parameters::Correlated { sigmas, .. } => {
+                let global = self.global.unwrap_or(1.0 / (2.0 * n).sqrt()) * normal(rng, 0.0, 1.0);
+                let local = self.local.unwrap_or(1.0 / (2.0 * n.sqrt()).sqrt());
+
+                for sigma in sigmas.iter_mut() {
+                    *sigma =
+                        (*sigma * (global + local * normal(rng, 0.0, 1.0)).exp()).max(self.minimum);
+                }
+            }
+        }
+
+        if let Parameters::Correlated { angles, .. } = parameters {
+            for angle in angles.iter_mut() {
+                *angle =
+                    (*angle + self.beta * normal(rng, 0.0, 1.0) + PI).rem_euclid(2.0 * PI) - PI;
+            }
+        }
+    }
+}
+
+impl Default for LogNormal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Mutator<SelfAdaptive<T>> for LogNormal
+where
+    T: Individual<Genome: Chromosome<Gene = f64>>,
+{
+    type Error = LogNormalError;
+
+    fn mutate<Rng>(
+        &self,
+        mut individual: SelfAdaptive<T>,
+        rng: &mut Rng,
+    ) -> Result<SelfAdaptive<T>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let dimension = individual.genome().len();
+
+        if !individual.parameters.is_valid(dimension) {
+            return Err(LogNormalError::Mismatch);
+        }
+
+        if dimension == 0 {
+            return Ok(individual);
+        }
+
+        self.adapt(&mut individual.parameters, dimension, rng);
+
+        let mut steps = (0..dimension)
+            .map(|index| normal(rng, 0.0, individual.parameters.sigma(index)))
+            .collect::<Vec<_>>();
+
+        let mut angles = individual.parameters.angles().iter();
+
+        for i in 0..dimension {
+            for j in i + 1..dimension {
+                let Some(angle) = angles.next() else {
+                    break;
+                };
+
+                let (lhs, rhs) = (steps[i], steps[j]);
+
+                steps[i] = lhs * angle.cos() - rhs * angle.sin();
+                steps[j] = lhs * angle.sin() + rhs * angle.cos();
+            }
+        }
+
+        for (index, step) in steps.into_iter().enumerate() {
+            if let Some(gene) = individual.genome_mut().gene_mut(index) {
+                *gene += step;
+            }
+        }
+
+        Ok(individual)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LogNormalError {
+    #[error("strategy parameters do not match genome length")]
+    Mismatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::self_adaptive::{Parameters, SelfAdaptive};
+    use crate::operator::mutator::Mutator;
+
+    use super::{LogNormal, LogNormalError};
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = rand::rng();
+
+        for parameters in [
+            Parameters::single(1.0),
+            Parameters::genewise(3, 1.0),
+            Parameters::correlated(3, 1.0),
+        ] {
+            let individual = SelfAdaptive::new(vec![0.0, 0.0, 0.0], parameters.clone());
+            let mutated = LogNormal::new().mutate(individual, &mut rng).unwrap();
+
+            assert_ne!(mutated.individual, vec![0.0, 0.0, 0.0]);
+            assert_ne!(mutated.parameters, parameters);
+            assert!(mutated.parameters.is_valid(3));
+            assert!(mutated.parameters.sigmas().iter().all(|sigma| *sigma > 0.0));
+            assert!(mutated
+                .parameters
+                .angles()
+                .iter()
+                .all(|angle| angle.abs() <= std::f64::consts::PI));
+        }
+    }
+
+    #[test]
+    fn test_mutate_minimum() {
+        let mut rng = rand::rng();
+        let individual = SelfAdaptive::new(vec![0.0, 0.0], Parameters::genewise(2, 0.0));
+
+        let a = LogNormal::new()
+            .with_minimum(0.25)
+            .mutate(individual, &mut rng)
+            .unwrap();
+
+        assert_eq!(a.parameters.sigmas(), &[0.25, 0.25]);
+    }
+
+    #[test]
+    fn test_mutate_error() {
+        let mut rng = rand::rng();
+        let individual = SelfAdaptive::new(vec![0.0, 0.0], Parameters::genewise(3, 1.0));
+
+        assert_eq!(
+            LogNormal::new().mutate(individual, &mut rng),
+            Err(LogNormalError::Mismatch)
+        );
+    }
+}
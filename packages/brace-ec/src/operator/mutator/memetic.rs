@@ -0,0 +1,155 @@
+use crate::fitness::Fitness;
+use crate::individual::Individual;
+use crate::operator::selector::Selector;
+
+use super::Mutator;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Learning {
+    #[default]
+    Lamarckian,
+    Baldwinian,
+}
+
+pub struct Memetic<L> {
+    search: L,
+    learning: Learning,
+}
+
+impl<L> Memetic<L> {
+    pub fn new(search: L) -> Self {
+        Self {
+            search,
+            learning: Learning::default(),
+        }
+    }
+
+    pub fn with_learning(mut self, learning: Learning) -> Self {
+        self.learning = learning;
+        self
+    }
+}
+
+impl<S> Memetic<LocalSearch<S>> {
+    pub fn selector(selector: S) -> Self {
+        Self::new(LocalSearch::new(selector))
+    }
+}
+
+impl<T, L> Mutator<T> for Memetic<L>
+where
+    T: Individual + Clone,
+    L: Mutator<T>,
+{
+    type Error = L::Error;
+
+    fn mutate<Rng>(&self, mut individual: T, rng: &mut Rng) -> Result<T, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        match self.learning {
+            Learning::Lamarckian => self.search.mutate(individual, rng),
+            Learning::Baldwinian => {
+                let mut improved = self.search.mutate(individual.clone(), rng)?;
+
+                *individual.fitness_mut() =
+                    std::mem::replace(improved.fitness_mut(), T::Fitness::nil());
+
+                Ok(individual)
+            }
+        }
+    }
+}
+
+pub struct LocalSearch<S> {
+    selector: S,
+}
+
+impl<S> LocalSearch<S> {
+    pub fn new(selector: S) -> Self {
+        Self { selector }
+    }
+}
+
+impl<T, S> Mutator<T> for LocalSearch<S>
+where
+    T: Individual,
+    S: Selector<[T], Output = [T; 1]>,
+{
+    type Error = S::Error;
+
+    fn mutate<Rng>(&self, individual: T, rng: &mut Rng) -> Result<T, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let [individual] = self.selector.select(&[individual], rng)?;
+
+        Ok(individual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::first::First;
+    use crate::operator::selector::hill_climb::HillClimb;
+    use crate::operator::selector::Selector;
+    use crate::population::Population;
+
+    use super::{Learning, Memetic};
+
+    fn evaluate(individual: &Evaluated<i32, i32>) -> Result<i32, Infallible> {
+        Ok(-(individual.individual - 10).abs())
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = rand::rng();
+
+        let a = Memetic::new(Add(1).evaluate_with(evaluate).improve(4))
+            .mutate(Evaluated::new(0, -10), &mut rng)
+            .unwrap();
+        let b = Memetic::new(Add(1).evaluate_with(evaluate).improve(4))
+            .with_learning(Learning::Baldwinian)
+            .mutate(Evaluated::new(0, -10), &mut rng)
+            .unwrap();
+        let c = Memetic::selector(HillClimb::new(First, Add(1).evaluate_with(evaluate), 20))
+            .mutate(Evaluated::new(0, -10), &mut rng)
+            .unwrap();
+        let d = Memetic::selector(HillClimb::new(First, Add(1).evaluate_with(evaluate), 20))
+            .with_learning(Learning::Baldwinian)
+            .mutate(Evaluated::new(0, -10), &mut rng)
+            .unwrap();
+
+        assert_eq!(a, Evaluated::new(4, -6));
+        assert_eq!(b, Evaluated::new(0, -6));
+        assert_eq!(c, Evaluated::new(10, 0));
+        assert_eq!(d, Evaluated::new(0, 0));
+    }
+
+    #[test]
+    fn test_select() {
+        let population = [Evaluated::new(0, -10), Evaluated::new(2, -8)];
+
+        let a = population
+            .select(Best.mutate(Memetic::new(Add(1).evaluate_with(evaluate).improve(3)).rate(1.0)))
+            .unwrap();
+        let b = population
+            .select(
+                Best.mutate(
+                    Memetic::new(Add(1).evaluate_with(evaluate).improve(3))
+                        .with_learning(Learning::Baldwinian)
+                        .rate(0.0),
+                ),
+            )
+            .unwrap();
+
+        assert_eq!(a, [Evaluated::new(5, -5)]);
+        assert_eq!(b, [Evaluated::new(2, -8)]);
+    }
+}
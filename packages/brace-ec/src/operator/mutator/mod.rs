@@ -1,6 +1,11 @@
 pub mod add;
 pub mod each;
+pub mod feedback;
+pub mod improve;
+pub mod instruction;
 pub mod invert;
+pub mod log_normal;
+pub mod memetic;
 pub mod noise;
 pub mod rate;
 
@@ -8,15 +13,20 @@ use std::error::Error;
 
 use crate::chromosome::Chromosome;
 use crate::individual::Individual;
+use crate::schedule::feedback::OneFifth;
 use crate::util::iter::IterableMut;
 
 use self::each::{Each, EachReciprocalRate};
+use self::feedback::Feedback;
+use self::improve::Improve;
 use self::rate::Rate;
 
 use super::evaluate::Evaluate;
 use super::evaluator::function::Function;
 use super::evaluator::Evaluator;
 use super::inspect::Inspect;
+use super::repair::Repair;
+use super::repairer::Repairer;
 use super::repeat::{Repeat, RepeatN};
 use super::then::Then;
 
@@ -53,6 +63,27 @@ where
         Then::new(self, mutator)
     }
 
+    fn improve(self, steps: usize) -> Improve<Self>
+    where
+        T: Clone,
+    {
+        Improve::new(self, steps)
+    }
+
+    fn repair<R>(self, repairer: R) -> Repair<Self, R>
+    where
+        R: Repairer<T>,
+    {
+        Repair::new(self, repairer)
+    }
+
+    fn feedback(self, rule: OneFifth) -> Feedback<Self>
+    where
+        T: Clone,
+    {
+        Feedback::new(self, rule)
+    }
+
     fn rate(self, rate: f64) -> Rate<Self> {
         Rate::new(self, rate)
     }
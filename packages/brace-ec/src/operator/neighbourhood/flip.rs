@@ -0,0 +1,61 @@
+use std::convert::Infallible;
+
+use crate::chromosome::Chromosome;
+use crate::individual::Individual;
+
+use super::Neighbourhood;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Flip;
+
+impl<T> Neighbourhood<T> for Flip
+where
+    T: Individual<Genome: Chromosome<Gene = bool>> + Clone,
+{
+    type Move = usize;
+    type Error = Infallible;
+
+    fn neighbours<Rng>(
+        &self,
+        individual: &T,
+        _: &mut Rng,
+    ) -> Result<Vec<(Self::Move, T)>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        Ok((0..individual.genome().len())
+            .map(|index| {
+                let mut neighbour = individual.clone();
+
+                if let Some(gene) = neighbour.genome_mut().gene_mut(index) {
+                    *gene = !*gene;
+                }
+
+                (index, neighbour)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::neighbourhood::Neighbourhood;
+
+    use super::Flip;
+
+    #[test]
+    fn test_neighbours() {
+        let mut rng = rand::rng();
+
+        let neighbours = Flip.neighbours(&[true, false, false], &mut rng).unwrap();
+
+        assert_eq!(
+            neighbours,
+            [
+                (0, [false, false, false]),
+                (1, [true, true, false]),
+                (2, [true, false, true]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,45 @@
+pub mod flip;
+pub mod sample;
+pub mod swap;
+
+use crate::individual::Individual;
+
+use self::sample::Sample;
+
+use super::evaluate::{Evaluate, ParEvaluate};
+use super::evaluator::Evaluator;
+
+pub trait Neighbourhood<T>: Sized
+where
+    T: Individual,
+{
+    type Move;
+    type Error;
+
+    fn neighbours<Rng>(
+        &self,
+        individual: &T,
+        rng: &mut Rng,
+    ) -> Result<Vec<(Self::Move, T)>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized;
+
+    fn sample(self, count: usize) -> Sample<Self> {
+        Sample::new(self, count)
+    }
+
+    fn evaluate<S>(self, evaluator: S) -> Evaluate<Self, S>
+    where
+        S: Evaluator<T>,
+    {
+        Evaluate::new(self, evaluator)
+    }
+
+    fn par_evaluate<S>(self, evaluator: S) -> ParEvaluate<Self, S>
+    where
+        S: Evaluator<T> + Sync,
+        T: Send,
+    {
+        ParEvaluate::new(self, evaluator)
+    }
+}
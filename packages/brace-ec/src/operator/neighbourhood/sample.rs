@@ -0,0 +1,69 @@
+use rand::seq::IteratorRandom;
+
+use crate::individual::Individual;
+
+use super::Neighbourhood;
+
+#[derive(Clone, Debug, Default)]
+pub struct Sample<N> {
+    neighbourhood: N,
+    count: usize,
+}
+
+impl<N> Sample<N> {
+    pub fn new(neighbourhood: N, count: usize) -> Self {
+        Self {
+            neighbourhood,
+            count,
+        }
+    }
+}
+
+impl<T, N> Neighbourhood<T> for Sample<N>
+where
+    T: Individual,
+    N: Neighbourhood<T>,
+{
+    type Move = N::Move;
+    type Error = N::Error;
+
+    fn neighbours<Rng>(
+        &self,
+        individual: &T,
+        rng: &mut Rng,
+    ) -> Result<Vec<(Self::Move, T)>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let neighbours = self.neighbourhood.neighbours(individual, rng)?;
+
+        Ok(neighbours.into_iter().choose_multiple(rng, self.count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::neighbourhood::flip::Flip;
+    use crate::operator::neighbourhood::Neighbourhood;
+
+    use super::Sample;
+
+    #[test]
+    fn test_neighbours() {
+        let mut rng = rand::rng();
+
+        let a = Sample::new(Flip, 2)
+            .neighbours(&[false; 5], &mut rng)
+            .unwrap();
+        let b = Sample::new(Flip, 10)
+            .neighbours(&[false; 5], &mut rng)
+            .unwrap();
+
+        assert_eq!(a.len(), 2);
+        assert_eq!(b.len(), 5);
+        assert!(a.iter().all(
+            |(index, neighbour)| neighbour.iter().filter(|gene| **gene).count() == 1
+                && neighbour[*index]
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+use std::convert::Infallible;
+
+use crate::chromosome::Chromosome;
+use crate::individual::Individual;
+
+use super::Neighbourhood;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Swap;
+
+impl<T> Neighbourhood<T> for Swap
+where
+    T: Individual<Genome: Chromosome<Gene: Clone>> + Clone,
+{
+    type Move = (usize, usize);
+    type Error = Infallible;
+
+    fn neighbours<Rng>(
+        &self,
+        individual: &T,
+        _: &mut Rng,
+    ) -> Result<Vec<(Self::Move, T)>, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let genome = individual.genome();
+        let len = genome.len();
+
+        Ok((0..len)
+            .flat_map(|i| (i + 1..len).map(move |j| (i, j)))
+            .filter_map(|(i, j)| {
+                let a = genome.gene(i)?.clone();
+                let b = genome.gene(j)?.clone();
+                let mut neighbour = individual.clone();
+
+                *neighbour.genome_mut().gene_mut(i)? = b;
+                *neighbour.genome_mut().gene_mut(j)? = a;
+
+                Some(((i, j), neighbour))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::neighbourhood::Neighbourhood;
+
+    use super::Swap;
+
+    #[test]
+    fn test_neighbours() {
+        let mut rng = rand::rng();
+
+        let neighbours = Swap.neighbours(&[1, 2, 3], &mut rng).unwrap();
+
+        assert_eq!(
+            neighbours,
+            [
+                ((0, 1), [2, 1, 3]),
+                ((0, 2), [3, 2, 1]),
+                ((1, 2), [1, 3, 2])
+            ]
+        );
+    }
+}
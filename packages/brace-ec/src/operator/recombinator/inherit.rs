@@ -0,0 +1,70 @@
+use std::convert::Infallible;
+
+use crate::individual::aged::Aged;
+use crate::individual::Individual;
+use crate::population::IterablePopulation;
+use crate::util::map::TryMap;
+
+use super::Recombinator;
+
+pub struct Inherit<R> {
+    recombinator: R,
+}
+
+impl<R> Inherit<R> {
+    pub fn new(recombinator: R) -> Self {
+        Self { recombinator }
+    }
+}
+
+impl<P, I, R> Recombinator<P> for Inherit<R>
+where
+    P: IterablePopulation<Individual = Aged<I>>,
+    I: Individual,
+    R: Recombinator<P, Output: TryMap<Item = Aged<I>>>,
+{
+    type Output = R::Output;
+    type Error = R::Error;
+
+    fn recombine<Rng>(&self, parents: P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let birth = parents
+            .iter()
+            .map(|parent| parent.birth)
+            .min()
+            .unwrap_or_default();
+
+        let offspring = self.recombinator.recombine(parents, rng)?;
+
+        Ok(offspring
+            .try_map(|mut individual| {
+                individual.birth = birth;
+
+                Ok::<_, Infallible>(individual)
+            })
+            .unwrap_or_else(|error| match error {}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::aged::Aged;
+    use crate::operator::recombinator::point::OnePointCrossover;
+    use crate::operator::recombinator::Recombinator;
+
+    use super::Inherit;
+
+    #[test]
+    fn test_recombine() {
+        let mut rng = rand::rng();
+
+        let [a, b] = Inherit::new(OnePointCrossover)
+            .recombine([Aged::new([0, 0, 0], 7), Aged::new([1, 1, 1], 3)], &mut rng)
+            .unwrap();
+
+        assert_eq!(a.birth, 3);
+        assert_eq!(b.birth, 3);
+    }
+}
@@ -0,0 +1,142 @@
+use std::marker::PhantomData;
+
+use thiserror::Error;
+
+use crate::individual::Individual;
+use crate::operator::recombinator::Recombinator;
+use crate::population::Population;
+
+pub struct LinearCrossover<P: Population> {
+    length: usize,
+    marker: PhantomData<fn() -> P>,
+}
+
+impl<P> LinearCrossover<P>
+where
+    P: Population,
+{
+    pub fn new(length: usize) -> Self {
+        Self {
+            length,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I, T> Recombinator<[I; 2]> for LinearCrossover<[I; 2]>
+where
+    I: Individual<Genome = Vec<T>>,
+{
+    type Output = [I; 2];
+    type Error = LinearCrossoverError;
+
+    fn recombine<Rng>(
+        &self,
+        [mut lhs, mut rhs]: [I; 2],
+        rng: &mut Rng,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let (lhs_len, rhs_len) = (lhs.genome().len(), rhs.genome().len());
+
+        if lhs_len == 0 || rhs_len == 0 {
+            return Err(LinearCrossoverError::Empty);
+        }
+
+        if lhs_len > self.length || rhs_len > self.length {
+            return Err(LinearCrossoverError::TooLong);
+        }
+
+        let lhs_start = rng.random_range(0..lhs_len);
+        let rhs_start = rng.random_range(0..rhs_len);
+        let mut lhs_size = rng.random_range(1..=lhs_len - lhs_start);
+        let mut rhs_size = rng.random_range(1..=rhs_len - rhs_start);
+
+        if lhs_len - lhs_size + rhs_size > self.length
+            || rhs_len - rhs_size + lhs_size > self.length
+        {
+            lhs_size = lhs_size.min(rhs_size);
+            rhs_size = lhs_size;
+        }
+
+        let lhs_segment = lhs
+            .genome_mut()
+            .drain(lhs_start..lhs_start + lhs_size)
+            .collect::<Vec<_>>();
+        let rhs_segment = rhs
+            .genome_mut()
+            .splice(rhs_start..rhs_start + rhs_size, lhs_segment)
+            .collect::<Vec<_>>();
+
+        lhs.genome_mut().splice(lhs_start..lhs_start, rhs_segment);
+
+        Ok([lhs, rhs])
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LinearCrossoverError {
+    #[error("unsupported crossover with an empty genome")]
+    Empty,
+    #[error("genome is longer than the maximum length")]
+    TooLong,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linear::instruction::{Instruction, Operand, Operation};
+    use crate::linear::program::Program;
+    use crate::operator::recombinator::Recombinator;
+
+    use super::{LinearCrossover, LinearCrossoverError};
+
+    fn program(target: usize, len: usize) -> Program {
+        Program::new(vec![
+            Instruction::new(
+                Operation::Add,
+                target,
+                Operand::Input(0),
+                Operand::Input(0)
+            );
+            len
+        ])
+    }
+
+    fn count(programs: &[Program; 2], target: usize) -> usize {
+        programs
+            .iter()
+            .flat_map(Program::instructions)
+            .filter(|instruction| instruction.target == target)
+            .count()
+    }
+
+    #[test]
+    fn test_recombine() {
+        let mut rng = rand::rng();
+
+        for _ in 0..100 {
+            let children = LinearCrossover::new(10)
+                .recombine([program(0, 5), program(1, 8)], &mut rng)
+                .unwrap();
+
+            assert!(children[0].len() <= 10);
+            assert!(children[1].len() <= 10);
+            assert_eq!(count(&children, 0), 5);
+            assert_eq!(count(&children, 1), 8);
+            assert!(children[0].instructions().iter().any(|i| i.target == 1));
+            assert!(children[1].instructions().iter().any(|i| i.target == 0));
+        }
+    }
+
+    #[test]
+    fn test_recombine_errors() {
+        let mut rng = rand::rng();
+
+        let a = LinearCrossover::new(10).recombine([program(0, 5), program(1, 0)], &mut rng);
+        let b = LinearCrossover::new(4).recombine([program(0, 5), program(1, 2)], &mut rng);
+
+        assert_eq!(a, Err(LinearCrossoverError::Empty));
+        assert_eq!(b, Err(LinearCrossoverError::TooLong));
+    }
+}
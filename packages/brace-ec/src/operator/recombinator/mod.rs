@@ -1,5 +1,9 @@
 pub mod average;
+pub mod inherit;
+pub mod linear;
+pub mod mutate;
 pub mod point;
+pub mod self_adaptive;
 pub mod sum;
 pub mod uniform;
 
@@ -12,6 +16,8 @@ use super::evaluate::Evaluate;
 use super::evaluator::function::Function;
 use super::evaluator::Evaluator;
 use super::inspect::Inspect;
+use super::repair::Repair;
+use super::repairer::Repairer;
 use super::repeat::{Repeat, RepeatN};
 use super::then::Then;
 
@@ -47,6 +53,13 @@ where
         Then::new(self, recombinator)
     }
 
+    fn repair<R>(self, repairer: R) -> Repair<Self, R>
+    where
+        R: Repairer<P::Individual>,
+    {
+        Repair::new(self, repairer)
+    }
+
     fn repeat(self, count: usize) -> Repeat<Self> {
         Repeat::new(self, count)
     }
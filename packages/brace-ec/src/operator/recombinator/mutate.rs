@@ -0,0 +1,54 @@
+use crate::operator::mutator::Mutator;
+use crate::population::Population;
+use crate::util::map::TryMap;
+
+use super::Recombinator;
+
+#[derive(Clone, Debug, Default)]
+pub struct Mutate<M> {
+    mutator: M,
+}
+
+impl<M> Mutate<M> {
+    pub fn new(mutator: M) -> Self {
+        Self { mutator }
+    }
+}
+
+impl<P, M> Recombinator<P> for Mutate<M>
+where
+    P: Population + TryMap<Item = P::Individual>,
+    M: Mutator<P::Individual>,
+{
+    type Output = P;
+    type Error = M::Error;
+
+    fn recombine<Rng>(&self, parents: P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        parents.try_map(|individual| self.mutator.mutate(individual, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::mutator::add::Add;
+    use crate::operator::recombinator::sum::Sum;
+    use crate::operator::recombinator::Recombinator;
+
+    use super::Mutate;
+
+    #[test]
+    fn test_recombine() {
+        let mut rng = rand::rng();
+
+        let a = Mutate::new(Add(1)).recombine([1, 2], &mut rng);
+        let b = Mutate::new(Add(1)).recombine(vec![3], &mut rng);
+        let c = Sum.then(Mutate::new(Add(1))).recombine([1, 2], &mut rng);
+
+        assert_eq!(a, Ok([2, 3]));
+        assert_eq!(b, Ok(vec![4]));
+        assert_eq!(c.ok(), Some([4]));
+    }
+}
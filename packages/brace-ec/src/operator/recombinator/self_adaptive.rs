@@ -0,0 +1,242 @@
+use std::mem::discriminant;
+
+use thiserror::Error;
+
+use crate::chromosome::Chromosome;
+use crate::fitness::Fitness;
+use crate::individual::self_adaptive::{Parameters, SelfAdaptive};
+use crate::individual::Individual;
+use crate::population::IterablePopulation;
+
+use super::Recombinator;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Recombination {
+    #[default]
+    Discrete,
+    Intermediate,
+}
+
+impl Recombination {
+    fn combine<Rng>(&self, values: &[f64], rng: &mut Rng) -> f64
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        match self {
+            Self::Discrete => values[rng.random_range(0..values.len())],
+            Self::Intermediate => values.iter().sum::<f64>() / values.len() as f64,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StrategyCrossover {
+    object: Recombination,
+    strategy: Recombination,
+}
+
+impl StrategyCrossover {
+    pub fn new(object: Recombination, strategy: Recombination) -> Self {
+        Self { object, strategy }
+    }
+
+    fn mix<'a, Rng>(
+        recombination: Recombination,
+        parents: impl Iterator<Item = &'a [f64]> + Clone,
+        len: usize,
+        rng: &mut Rng,
+    ) -> Vec<f64>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        (0..len)
+            .map(|index| {
+                let values = parents
+                    .clone()
+                    .map(|parent| parent[index])
+                    .collect::<Vec<_>>();
+
+                recombination.combine(&values, rng)
+            })
+            .collect()
+    }
+}
+
+impl Default for StrategyCrossover {
+    fn default() -> Self {
+        Self::new(Recombination::Discrete, Recombination::Intermediate)
+    }
+}
+
+impl<P, T> Recombinator<P> for StrategyCrossover
+where
+    P: IterablePopulation<Individual = SelfAdaptive<T>>,
+    T: Individual<Genome: Chromosome<Gene = f64>> + Clone,
+{
+    type Output = [SelfAdaptive<T>; 1];
+    type Error = StrategyCrossoverError;
+
+    fn recombine<Rng>(&self, parents: P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let parents = parents.iter().collect::<Vec<_>>();
+
+        let Some(first) = parents.first() else {
+            return Err(StrategyCrossoverError::Empty);
+        };
+
+        let dimension = first.genome().len();
+
+        if parents.iter().any(|parent| {
+            parent.genome().len() != dimension
+                || !parent.parameters.is_valid(dimension)
+                || discriminant(&parent.parameters) != discriminant(&first.parameters)
+        }) {
+            return Err(StrategyCrossoverError::Mismatch);
+        }
+
+        let mut individual = first.individual.clone();
+
+        for index in 0..dimension {
+            let values = parents
+                .iter()
+                .filter_map(|parent| parent.genome().gene(index).copied())
+                .collect::<Vec<_>>();
+            let value = self.object.combine(&values, rng);
+
+            if let Some(gene) = individual.genome_mut().gene_mut(index) {
+                *gene = value;
+            }
+        }
+
+        individual.set_fitness(T::Fitness::nil());
+
+        let sigmas = Self::mix(
+            self.strategy,
+            parents.iter().map(|parent| parent.parameters.sigmas()),
+            first.parameters.sigmas().len(),
+            rng,
+        );
+
+        let parameters = match first.parameters {
+            Parameters::Single(_) => Parameters::Single(sigmas[0]),
+            Parameters::Genewise(_) => Parameters::Genewise(sigmas),
+            Parameters::Correlated { .. } => Parameters::Correlated {
+                sigmas,
+                angles: Self::mix(
+                    self.strategy,
+                    parents.iter().map(|parent| parent.parameters.angles()),
+                    first.parameters.angles().len(),
+                    rng,
+                ),
+            },
+        };
+
+        Ok([SelfAdaptive::new(individual, parameters)])
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum StrategyCrossoverError {
+    #[error("empty population")]
+    Empty,
+    #[error("parents have mismatched genomes or strategy parameters")]
+    Mismatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::individual::self_adaptive::{Parameters, SelfAdaptive};
+    use crate::operator::recombinator::Recombinator;
+
+    use super::{Recombination, StrategyCrossover, StrategyCrossoverError};
+
+    #[test]
+    fn test_recombine() {
+        let mut rng = rand::rng();
+        let parents = [
+            SelfAdaptive::new(
+                Evaluated::new(vec![0.0, 0.0], 1),
+                Parameters::genewise(2, 1.0),
+            ),
+            SelfAdaptive::new(
+                Evaluated::new(vec![2.0, 2.0], 2),
+                Parameters::genewise(2, 3.0),
+            ),
+        ];
+
+        let [a] = StrategyCrossover::default()
+            .recombine(parents.clone(), &mut rng)
+            .unwrap();
+        let [b] = StrategyCrossover::new(Recombination::Intermediate, Recombination::Discrete)
+            .recombine(parents, &mut rng)
+            .unwrap();
+
+        assert!(a
+            .individual
+            .individual
+            .iter()
+            .all(|gene| *gene == 0.0 || *gene == 2.0));
+        assert_eq!(a.individual.fitness, 0);
+        assert_eq!(a.parameters, Parameters::genewise(2, 2.0));
+        assert_eq!(b.individual.individual, vec![1.0, 1.0]);
+        assert!(b
+            .parameters
+            .sigmas()
+            .iter()
+            .all(|sigma| *sigma == 1.0 || *sigma == 3.0));
+    }
+
+    #[test]
+    fn test_recombine_correlated() {
+        let mut rng = rand::rng();
+        let parents = [
+            SelfAdaptive::new(
+                vec![0.0, 0.0],
+                Parameters::Correlated {
+                    sigmas: vec![1.0, 1.0],
+                    angles: vec![0.5],
+                },
+            ),
+            SelfAdaptive::new(
+                vec![0.0, 0.0],
+                Parameters::Correlated {
+                    sigmas: vec![2.0, 2.0],
+                    angles: vec![-0.5],
+                },
+            ),
+        ];
+
+        let [a] = StrategyCrossover::default()
+            .recombine(parents, &mut rng)
+            .unwrap();
+
+        assert_eq!(
+            a.parameters,
+            Parameters::Correlated {
+                sigmas: vec![1.5, 1.5],
+                angles: vec![0.0],
+            }
+        );
+    }
+
+    #[test]
+    fn test_recombine_error() {
+        let mut rng = rand::rng();
+
+        let a = StrategyCrossover::default().recombine(
+            [
+                SelfAdaptive::new(vec![0.0], Parameters::single(1.0)),
+                SelfAdaptive::new(vec![0.0], Parameters::genewise(1, 1.0)),
+            ],
+            &mut rng,
+        );
+        let b =
+            StrategyCrossover::default().recombine(Vec::<SelfAdaptive<Vec<f64>>>::new(), &mut rng);
+
+        assert_eq!(a, Err(StrategyCrossoverError::Mismatch));
+        assert_eq!(b, Err(StrategyCrossoverError::Empty));
+    }
+}
@@ -0,0 +1,163 @@
+use thiserror::Error;
+
+use crate::individual::Individual;
+use crate::population::Population;
+use crate::util::map::TryMap;
+
+use super::mutator::Mutator;
+use super::recombinator::Recombinator;
+use super::repairer::Repairer;
+use super::selector::Selector;
+
+pub struct Repair<T, R> {
+    operator: T,
+    repairer: R,
+}
+
+impl<T, R> Repair<T, R> {
+    pub fn new(operator: T, repairer: R) -> Self {
+        Self { operator, repairer }
+    }
+}
+
+impl<P, T, R, I> Selector<P> for Repair<T, R>
+where
+    P: Population<Individual = I> + ?Sized,
+    T: Selector<P, Output: TryMap<Item = I>>,
+    R: Repairer<I>,
+    I: Individual,
+{
+    type Output = T::Output;
+    type Error = RepairError<T::Error, R::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.operator
+            .select(population, rng)
+            .map_err(RepairError::Operate)?
+            .try_map(|individual| self.repairer.repair(individual, rng))
+            .map_err(RepairError::Repair)
+    }
+}
+
+impl<T, R, I> Mutator<I> for Repair<T, R>
+where
+    T: Mutator<I>,
+    R: Repairer<I>,
+    I: Individual,
+{
+    type Error = RepairError<T::Error, R::Error>;
+
+    fn mutate<Rng>(&self, individual: I, rng: &mut Rng) -> Result<I, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let individual = self
+            .operator
+            .mutate(individual, rng)
+            .map_err(RepairError::Operate)?;
+
+        self.repairer
+            .repair(individual, rng)
+            .map_err(RepairError::Repair)
+    }
+}
+
+impl<P, T, R, I> Recombinator<P> for Repair<T, R>
+where
+    P: Population<Individual = I>,
+    T: Recombinator<P, Output: TryMap<Item = I>>,
+    R: Repairer<I>,
+    I: Individual,
+{
+    type Output = T::Output;
+    type Error = RepairError<T::Error, R::Error>;
+
+    fn recombine<Rng>(&self, parents: P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.operator
+            .recombine(parents, rng)
+            .map_err(RepairError::Operate)?
+            .try_map(|individual| self.repairer.repair(individual, rng))
+            .map_err(RepairError::Repair)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RepairError<O, R> {
+    #[error(transparent)]
+    Operate(O),
+    #[error(transparent)]
+    Repair(R),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::recombinator::sum::Sum;
+    use crate::operator::recombinator::Recombinator;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::Selector;
+    use crate::population::Population;
+
+    fn clamp(individual: i32) -> Result<i32, Infallible> {
+        Ok(individual.min(10))
+    }
+
+    fn limit(individual: [bool; 4]) -> Result<[bool; 4], Infallible> {
+        let mut individual = individual;
+
+        while individual.iter().filter(|gene| **gene).count() > 2 {
+            if let Some(gene) = individual.iter_mut().rev().find(|gene| **gene) {
+                *gene = false;
+            }
+        }
+
+        Ok(individual)
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = rand::rng();
+
+        let a = Add(5).repair(clamp).mutate(3, &mut rng).unwrap();
+        let b = Add(5).repair(clamp).mutate(8, &mut rng).unwrap();
+        let c = Add(5)
+            .repair(|individual: Evaluated<i32, i32>| {
+                Ok::<_, Infallible>(Evaluated::new(individual.individual.min(10), 0))
+            })
+            .evaluate_with(|individual| Ok::<_, Infallible>(-individual.individual))
+            .mutate(Evaluated::new(8, 0), &mut rng)
+            .unwrap();
+
+        assert_eq!(a, 8);
+        assert_eq!(b, 10);
+        assert_eq!(c, Evaluated::new(10, -10));
+    }
+
+    #[test]
+    fn test_select() {
+        let population = [[true; 4]];
+
+        let a = population.select(Best.repair(limit)).unwrap();
+
+        assert_eq!(a, [[true, true, false, false]]);
+    }
+
+    #[test]
+    fn test_recombine() {
+        let mut rng = rand::rng();
+
+        let a = Sum.repair(clamp).recombine([4, 9], &mut rng).unwrap();
+
+        assert_eq!(a, [10]);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::individual::Individual;
+
+pub trait Repairer<T>
+where
+    T: Individual,
+{
+    type Error;
+
+    fn repair<Rng>(&self, individual: T, rng: &mut Rng) -> Result<T, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized;
+}
+
+impl<T, F, E> Repairer<T> for F
+where
+    T: Individual,
+    F: Fn(T) -> Result<T, E>,
+{
+    type Error = E;
+
+    fn repair<Rng>(&self, individual: T, _: &mut Rng) -> Result<T, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self(individual)
+    }
+}
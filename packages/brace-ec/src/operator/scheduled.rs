@@ -0,0 +1,209 @@
+use crate::generation::Generation;
+use crate::individual::Individual;
+use crate::population::Population;
+use crate::schedule::{Clock, Schedule};
+
+use super::evolver::Evolver;
+use super::mutator::Mutator;
+use super::recombinator::Recombinator;
+use super::selector::Selector;
+
+pub struct Scheduled<S, F> {
+    clock: Clock,
+    schedule: S,
+    factory: F,
+}
+
+impl<S, F> Scheduled<S, F> {
+    pub fn new(clock: Clock, schedule: S, factory: F) -> Self {
+        Self {
+            clock,
+            schedule,
+            factory,
+        }
+    }
+
+    pub fn value(&self) -> f64
+    where
+        S: Schedule,
+    {
+        self.clock.value(&self.schedule)
+    }
+
+    fn operator<T>(&self) -> T
+    where
+        S: Schedule,
+        F: Fn(f64) -> T,
+    {
+        (self.factory)(self.value())
+    }
+}
+
+impl<P, S, F, T> Selector<P> for Scheduled<S, F>
+where
+    P: Population + ?Sized,
+    S: Schedule,
+    F: Fn(f64) -> T,
+    T: Selector<P>,
+{
+    type Output = T::Output;
+    type Error = T::Error;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.operator().select(population, rng)
+    }
+}
+
+impl<I, S, F, T> Mutator<I> for Scheduled<S, F>
+where
+    I: Individual,
+    S: Schedule,
+    F: Fn(f64) -> T,
+    T: Mutator<I>,
+{
+    type Error = T::Error;
+
+    fn mutate<Rng>(&self, individual: I, rng: &mut Rng) -> Result<I, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.operator().mutate(individual, rng)
+    }
+}
+
+impl<P, S, F, T> Recombinator<P> for Scheduled<S, F>
+where
+    P: Population,
+    S: Schedule,
+    F: Fn(f64) -> T,
+    T: Recombinator<P>,
+{
+    type Output = T::Output;
+    type Error = T::Error;
+
+    fn recombine<Rng>(&self, parents: P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.operator().recombine(parents, rng)
+    }
+}
+
+impl<G, S, F, T> Evolver<G> for Scheduled<S, F>
+where
+    G: Generation,
+    S: Schedule,
+    F: Fn(f64) -> T,
+    T: Evolver<G>,
+{
+    type Error = T::Error;
+
+    fn evolve<Rng>(&self, generation: G, rng: &mut Rng) -> Result<G, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        self.operator().evolve(generation, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::evolver::Evolver;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::noise::Noise;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::recombinator::uniform::UniformCrossover;
+    use crate::operator::recombinator::Recombinator;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::tournament::Tournament;
+    use crate::operator::selector::Selector;
+    use crate::schedule::curve::{Linear, Step};
+    use crate::schedule::Clock;
+
+    use super::Scheduled;
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = rand::rng();
+        let clock = Clock::new();
+        let rate = Scheduled::new(clock.clone(), Linear::new(1.0, 0.0, 10), |rate| {
+            Add(1).rate(rate)
+        });
+        let noise = Scheduled::new(clock.clone(), Step::new(4.0, 0.5, 5), |scale: f64| {
+            Noise::new(0..=scale as i32)
+        });
+
+        assert_eq!(rate.mutate(1, &mut rng).unwrap(), 2);
+
+        clock.set(10);
+
+        assert_eq!(rate.value(), 0.0);
+        assert_eq!(rate.mutate(1, &mut rng).unwrap(), 1);
+        assert_eq!(noise.value(), 1.0);
+
+        for _ in 0..20 {
+            assert!((0..=2).contains(&noise.mutate(1, &mut rng).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_select() {
+        let mut rng = rand::rng();
+        let clock = Clock::new();
+        let population = [1, 2, 3, 4, 5];
+        let tournament = Scheduled::new(
+            clock.clone(),
+            |generation| generation as f64,
+            |size| Tournament::new(size as usize),
+        );
+
+        clock.set(5);
+
+        for _ in 0..10 {
+            assert_eq!(tournament.select(&population, &mut rng).unwrap(), [5]);
+        }
+
+        clock.set(6);
+
+        assert!(tournament.select(&population, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_recombine() {
+        let mut rng = rand::rng();
+        let clock = Clock::new();
+        let crossover = Scheduled::new(clock.clone(), Linear::new(0.0, 1.0, 1), |probability| {
+            UniformCrossover::new(probability)
+        });
+
+        let a = crossover
+            .recombine([[false; 3], [true; 3]], &mut rng)
+            .unwrap();
+
+        clock.set(1);
+
+        let b = crossover
+            .recombine([[false; 3], [true; 3]], &mut rng)
+            .unwrap();
+
+        assert_eq!(a, [[false; 3], [true; 3]]);
+        assert_eq!(b, [[true; 3], [false; 3]]);
+    }
+
+    #[test]
+    fn test_evolve() {
+        let clock = Clock::new();
+        let evolver = Scheduled::new(
+            clock.clone(),
+            |generation| generation as f64,
+            |_| Best.fill().evolver(),
+        );
+
+        assert!(evolver
+            .evolve((0, [1, 2, 3]), &mut rand::rng())
+            .is_ok_and(|generation| generation.1 == [3, 3, 3]));
+    }
+}
@@ -0,0 +1,329 @@
+use std::f64::consts::LN_2;
+
+use thiserror::Error;
+
+use crate::fitness::numeric::Numeric;
+use crate::individual::Individual;
+use crate::operator::evaluate::Evaluate;
+use crate::operator::evaluator::Evaluator;
+use crate::operator::mutator::Mutator;
+use crate::population::Population;
+
+use super::Selector;
+
+pub trait Cooling {
+    fn temperature(&self, step: usize) -> f64;
+
+    fn reheat(&self, _stagnation: usize) -> bool {
+        false
+    }
+}
+
+impl<F> Cooling for F
+where
+    F: Fn(usize) -> f64,
+{
+    fn temperature(&self, step: usize) -> f64 {
+        self(step)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Geometric {
+    initial: f64,
+    factor: f64,
+}
+
+impl Geometric {
+    pub fn new(initial: f64, factor: f64) -> Self {
+        Self { initial, factor }
+    }
+}
+
+impl Cooling for Geometric {
+    fn temperature(&self, step: usize) -> f64 {
+        self.initial * self.factor.powi(step.min(i32::MAX as usize) as i32)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Linear {
+    initial: f64,
+    decrement: f64,
+}
+
+impl Linear {
+    pub fn new(initial: f64, decrement: f64) -> Self {
+        Self { initial, decrement }
+    }
+}
+
+impl Cooling for Linear {
+    fn temperature(&self, step: usize) -> f64 {
+        (self.initial - self.decrement * step as f64).max(0.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Logarithmic {
+    initial: f64,
+}
+
+impl Logarithmic {
+    pub fn new(initial: f64) -> Self {
+        Self { initial }
+    }
+}
+
+impl Cooling for Logarithmic {
+    fn temperature(&self, step: usize) -> f64 {
+        self.initial * LN_2 / (step as f64 + 2.0).ln()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reheat<C> {
+    cooling: C,
+    patience: usize,
+}
+
+impl<C> Reheat<C> {
+    pub fn new(cooling: C, patience: usize) -> Self {
+        Self { cooling, patience }
+    }
+}
+
+impl<C> Cooling for Reheat<C>
+where
+    C: Cooling,
+{
+    fn temperature(&self, step: usize) -> f64 {
+        self.cooling.temperature(step)
+    }
+
+    fn reheat(&self, stagnation: usize) -> bool {
+        stagnation >= self.patience || self.cooling.reheat(stagnation)
+    }
+}
+
+pub struct SimulatedAnnealing<S, M, C = Geometric> {
+    selector: S,
+    mutator: M,
+    iterations: usize,
+    cooling: C,
+}
+
+impl<S, M> SimulatedAnnealing<S, M> {
+    pub fn new(selector: S, mutator: M, iterations: usize) -> Self {
+        Self {
+            selector,
+            mutator,
+            iterations,
+            cooling: Geometric::new(1.0, 0.99),
+        }
+    }
+}
+
+impl<S, M, C> SimulatedAnnealing<S, M, C> {
+    pub fn with_cooling<D>(self, cooling: D) -> SimulatedAnnealing<S, M, D>
+    where
+        D: Cooling,
+    {
+        SimulatedAnnealing {
+            selector: self.selector,
+            mutator: self.mutator,
+            iterations: self.iterations,
+            cooling,
+        }
+    }
+
+    pub fn evaluate_iterations<T, P>(
+        self,
+        evaluator: T,
+    ) -> SimulatedAnnealing<Evaluate<S, T>, Evaluate<M, T>, C>
+    where
+        P: Population<Individual: Clone> + ?Sized,
+        T: Evaluator<P::Individual> + Clone,
+        S: Selector<P, Output = [P::Individual; 1]>,
+        M: Mutator<P::Individual>,
+    {
+        SimulatedAnnealing {
+            selector: self.selector.evaluate(evaluator.clone()),
+            mutator: self.mutator.evaluate(evaluator),
+            iterations: self.iterations,
+            cooling: self.cooling,
+        }
+    }
+
+    pub fn evaluate_mutations<T, P>(self, evaluator: T) -> SimulatedAnnealing<S, Evaluate<M, T>, C>
+    where
+        P: Population<Individual: Clone> + ?Sized,
+        T: Evaluator<P::Individual>,
+        S: Selector<P, Output = [P::Individual; 1]>,
+        M: Mutator<P::Individual>,
+    {
+        SimulatedAnnealing {
+            selector: self.selector,
+            mutator: self.mutator.evaluate(evaluator),
+            iterations: self.iterations,
+            cooling: self.cooling,
+        }
+    }
+}
+
+impl<P, S, M, C> Selector<P> for SimulatedAnnealing<S, M, C>
+where
+    P: Population<Individual: Clone + Individual<Fitness: Numeric>> + ?Sized,
+    S: Selector<P, Output = [P::Individual; 1]>,
+    M: Mutator<P::Individual>,
+    C: Cooling,
+{
+    type Output = [P::Individual; 1];
+    type Error = SimulatedAnnealingError<S::Error, M::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let [mut current] = self
+            .selector
+            .select(population, rng)
+            .map_err(SimulatedAnnealingError::Select)?;
+
+        let mut best = current.clone();
+        let mut step = 0;
+        let mut stagnation = 0;
+
+        for _ in 0..self.iterations {
+            let next = self
+                .mutator
+                .mutate(current.clone(), rng)
+                .map_err(SimulatedAnnealingError::Mutate)?;
+
+            let temperature = self.cooling.temperature(step);
+            let delta = next.fitness().as_f64() - current.fitness().as_f64();
+
+            if next.fitness() >= current.fitness()
+                || (temperature > 0.0 && rng.random::<f64>() < (delta / temperature).exp())
+            {
+                current = next;
+            }
+
+            if current.fitness() > best.fitness() {
+                best = current.clone();
+                stagnation = 0;
+            } else {
+                stagnation += 1;
+            }
+
+            if self.cooling.reheat(stagnation) {
+                step = 0;
+                stagnation = 0;
+            } else {
+                step += 1;
+            }
+        }
+
+        Ok([best])
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SimulatedAnnealingError<S, M> {
+    #[error(transparent)]
+    Select(S),
+    #[error(transparent)]
+    Mutate(M),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evaluator::function::Function;
+    use crate::operator::evaluator::Evaluator;
+    use crate::operator::mutator::add::Add;
+    use crate::operator::mutator::Mutator;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::Selector;
+
+    use super::{Cooling, Geometric, Linear, Logarithmic, Reheat, SimulatedAnnealing};
+
+    #[derive(Clone)]
+    struct HillEvaluator;
+
+    impl Evaluator<i32> for HillEvaluator {
+        type Error = Infallible;
+
+        fn evaluate<Rng>(&self, individual: &i32, _: &mut Rng) -> Result<i32, Self::Error>
+        where
+            Rng: rand::Rng + ?Sized,
+        {
+            match individual {
+                10 => Ok(0),
+                _ => Ok(*individual),
+            }
+        }
+    }
+
+    fn valley(individual: &Evaluated<i32, i32>) -> Result<i32, Infallible> {
+        match individual.individual {
+            10 => Ok(0),
+            value => Ok(value),
+        }
+    }
+
+    #[test]
+    fn test_cooling() {
+        assert_eq!(Geometric::new(8.0, 0.5).temperature(3), 1.0);
+        assert_eq!(Linear::new(1.0, 0.25).temperature(2), 0.5);
+        assert_eq!(Linear::new(1.0, 0.25).temperature(8), 0.0);
+        assert_eq!(Logarithmic::new(3.0).temperature(0), 3.0);
+        assert!(Logarithmic::new(3.0).temperature(100) < 0.5);
+        assert_eq!((|step: usize| step as f64).temperature(4), 4.0);
+        assert!(!Reheat::new(Linear::new(1.0, 0.1), 5).reheat(4));
+        assert!(Reheat::new(Linear::new(1.0, 0.1), 5).reheat(5));
+    }
+
+    #[test]
+    fn test_select() {
+        let mut rng = rand::rng();
+
+        let a = SimulatedAnnealing::new(Best, Add(1), 10)
+            .select(&[1, 2, 3, 4, 5], &mut rng)
+            .unwrap();
+        let b = Best
+            .evaluate(HillEvaluator)
+            .anneal(Add(1).evaluate(HillEvaluator), 10)
+            .with_cooling(Linear::new(0.0, 0.0))
+            .select(&[1, 2, 3, 4, 5], &mut rng)
+            .unwrap();
+        let population = [Evaluated::new(5, 5)];
+
+        let c = Best
+            .anneal(Add(1), 20)
+            .with_cooling(Geometric::new(100.0, 0.99))
+            .evaluate_mutations(Function::new(valley))
+            .select(&population, &mut rng)
+            .unwrap();
+        let d = Best
+            .anneal(Add(1), 20)
+            .with_cooling(Reheat::new(Logarithmic::new(100.0), 3))
+            .evaluate_mutations(Function::new(valley))
+            .select(&population, &mut rng)
+            .unwrap();
+        let e = Best
+            .anneal(Add(1), 20)
+            .with_cooling(Linear::new(0.0, 0.0))
+            .evaluate_mutations(Function::new(valley))
+            .select(&population, &mut rng)
+            .unwrap();
+
+        assert_eq!(a, [15]);
+        assert_eq!(b, [9]);
+        assert!(c[0].individual > 10);
+        assert!(d[0].individual > 10);
+        assert_eq!(e[0], Evaluated::new(9, 9));
+    }
+}
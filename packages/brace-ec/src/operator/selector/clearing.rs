@@ -0,0 +1,187 @@
+use ordered_float::OrderedFloat;
+use thiserror::Error;
+
+use crate::distance::{Genotypic, Metric};
+use crate::fitness::numeric::Numeric;
+use crate::individual::evaluated::Evaluated;
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+use crate::util::iter::Iterable;
+use crate::util::map::MapInto;
+
+use super::Selector;
+
+pub struct Clearing<S, D = Genotypic> {
+    selector: S,
+    radius: f64,
+    capacity: usize,
+    metric: D,
+}
+
+impl<S> Clearing<S> {
+    pub fn new(selector: S, radius: f64) -> Self {
+        Self {
+            selector,
+            radius,
+            capacity: 1,
+            metric: Genotypic,
+        }
+    }
+}
+
+impl<S, D> Clearing<S, D> {
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn with_distance<T>(self, metric: T) -> Clearing<S, T> {
+        Clearing {
+            selector: self.selector,
+            radius: self.radius,
+            capacity: self.capacity,
+            metric,
+        }
+    }
+
+    pub fn clear<I>(&self, population: &[I]) -> Vec<f64>
+    where
+        I: Individual<Fitness: Numeric>,
+        D: Metric<I>,
+    {
+        let mut order = (0..population.len()).collect::<Vec<_>>();
+        let mut fitness = population
+            .iter()
+            .map(|individual| individual.fitness().as_f64())
+            .collect::<Vec<_>>();
+
+        order.sort_by(|a, b| population[*b].fitness().cmp(population[*a].fitness()));
+
+        let mut cleared = vec![false; population.len()];
+
+        for (position, &winner) in order.iter().enumerate() {
+            if cleared[winner] {
+                continue;
+            }
+
+            let mut winners = 1;
+
+            for &other in &order[position + 1..] {
+                if cleared[other]
+                    || self.metric.measure(&population[winner], &population[other]) >= self.radius
+                {
+                    continue;
+                }
+
+                if winners < self.capacity {
+                    winners += 1;
+                } else {
+                    cleared[other] = true;
+                    fitness[other] = f64::NEG_INFINITY;
+                }
+            }
+        }
+
+        fitness
+    }
+}
+
+impl<P, I, S, D> Selector<P> for Clearing<S, D>
+where
+    P: IterablePopulation<Individual = I> + ?Sized,
+    I: Individual<Fitness: Numeric> + Clone,
+    S: Selector<
+        [Evaluated<I, OrderedFloat<f64>>],
+        Output: MapInto<
+            I,
+            Item = Evaluated<I, OrderedFloat<f64>>,
+            Output: Population<Individual = I>,
+        >,
+    >,
+    D: Metric<I>,
+{
+    type Output = <S::Output as MapInto<I>>::Output;
+    type Error = ClearingError<S::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.radius <= 0.0 {
+            return Err(ClearingError::InvalidRadius);
+        }
+
+        if self.capacity == 0 {
+            return Err(ClearingError::InvalidCapacity);
+        }
+
+        let individuals = population.iter().cloned().collect::<Vec<_>>();
+
+        let cleared = self
+            .clear(&individuals)
+            .into_iter()
+            .zip(individuals)
+            .map(|(fitness, individual)| Evaluated::new(individual, OrderedFloat(fitness)))
+            .collect::<Vec<_>>();
+
+        Ok(self
+            .selector
+            .select(&cleared, rng)
+            .map_err(ClearingError::Select)?
+            .map_into(|individual| individual.individual))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ClearingError<S> {
+    #[error("clearing radius must be greater than zero")]
+    InvalidRadius,
+    #[error("niche capacity must be greater than zero")]
+    InvalidCapacity,
+    #[error(transparent)]
+    Select(S),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::selector::worst::Worst;
+    use crate::population::Population;
+
+    use super::{Clearing, ClearingError};
+
+    fn population() -> Vec<Evaluated<f64, u8>> {
+        vec![
+            Evaluated::new(0.0, 10),
+            Evaluated::new(0.1, 9),
+            Evaluated::new(0.2, 8),
+            Evaluated::new(5.0, 1),
+        ]
+    }
+
+    #[test]
+    fn test_clear() {
+        let population = population();
+
+        let a = Clearing::new((), 1.0).clear(&population);
+        let b = Clearing::new((), 1.0).with_capacity(2).clear(&population);
+        let c = Clearing::new((), 0.05).clear(&population);
+
+        assert_eq!(a, [10.0, f64::NEG_INFINITY, f64::NEG_INFINITY, 1.0]);
+        assert_eq!(b, [10.0, 9.0, f64::NEG_INFINITY, 1.0]);
+        assert_eq!(c, [10.0, 9.0, 8.0, 1.0]);
+    }
+
+    #[test]
+    fn test_select() {
+        let population = population();
+
+        let a = population.select(Clearing::new(Worst, 1.0)).unwrap();
+        let b = population.select(Clearing::new(Worst, 0.05)).unwrap();
+        let c = population.select(Clearing::new(Worst, 1.0).with_capacity(0));
+
+        assert_eq!(a, [Evaluated::new(0.1, 9)]);
+        assert_eq!(b, [Evaluated::new(5.0, 1)]);
+        assert!(matches!(c, Err(ClearingError::InvalidCapacity)));
+    }
+}
@@ -0,0 +1,132 @@
+use std::marker::PhantomData;
+
+use rand::seq::IteratorRandom;
+use thiserror::Error;
+
+use crate::chromosome::size::Size;
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+
+use super::Selector;
+
+pub struct DoubleTournament<P: Population + ?Sized> {
+    size: usize,
+    parsimony: f64,
+    marker: PhantomData<fn() -> P>,
+}
+
+impl<P> DoubleTournament<P>
+where
+    P: Population + ?Sized,
+{
+    pub fn new(size: usize, parsimony: f64) -> Self {
+        Self {
+            size,
+            parsimony,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<P> Selector<P> for DoubleTournament<P>
+where
+    P: IterablePopulation<Individual: Individual<Genome: Size> + Clone> + ?Sized,
+{
+    type Output = [P::Individual; 1];
+    type Error = DoubleTournamentError;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.size == 0 {
+            return Err(DoubleTournamentError::Empty);
+        }
+
+        if population.len() < self.size {
+            return Err(DoubleTournamentError::NotEnough);
+        }
+
+        if !(0.5..=1.0).contains(&self.parsimony) {
+            return Err(DoubleTournamentError::InvalidParsimony);
+        }
+
+        let mut winners = (0..2).map(|_| {
+            population
+                .iter()
+                .choose_multiple(rng, self.size)
+                .into_iter()
+                .max_by_key(|individual| individual.fitness())
+                .expect("bound check")
+        });
+
+        let a = winners.next().expect("two winners");
+        let b = winners.next().expect("two winners");
+
+        let (smaller, larger) = match a.genome().size() <= b.genome().size() {
+            true => (a, b),
+            false => (b, a),
+        };
+
+        match rng.random_bool(self.parsimony) {
+            true => Ok([smaller.clone()]),
+            false => Ok([larger.clone()]),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DoubleTournamentError {
+    #[error("empty tournament")]
+    Empty,
+    #[error("not enough participants")]
+    NotEnough,
+    #[error("parsimony probability must be between 0.5 and 1")]
+    InvalidParsimony,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::population::Population;
+
+    use super::{DoubleTournament, DoubleTournamentError};
+
+    #[test]
+    fn test_select() {
+        let population = [
+            Evaluated::new(vec![0; 5], 3),
+            Evaluated::new(vec![0; 2], 1),
+            Evaluated::new(vec![0; 9], 2),
+        ];
+
+        let a = population.select(DoubleTournament::new(3, 1.0)).unwrap();
+        let b = population.select(DoubleTournament::new(1, 1.0)).unwrap();
+        let c = population.select(DoubleTournament::new(4, 1.0));
+        let d = population.select(DoubleTournament::new(0, 1.0));
+        let e = population.select(DoubleTournament::new(2, 0.2));
+
+        assert_eq!(a, [Evaluated::new(vec![0; 5], 3)]);
+        assert!(population.contains(&b[0]));
+        assert_eq!(c, Err(DoubleTournamentError::NotEnough));
+        assert_eq!(d, Err(DoubleTournamentError::Empty));
+        assert_eq!(e, Err(DoubleTournamentError::InvalidParsimony));
+    }
+
+    #[test]
+    fn test_select_pressure() {
+        let population = (0..10)
+            .map(|size| Evaluated::new(vec![0; size + 1], 0))
+            .collect::<Vec<_>>();
+
+        let sizes = (0..200)
+            .map(|_| {
+                population.select(DoubleTournament::new(1, 1.0)).unwrap()[0]
+                    .individual
+                    .len()
+            })
+            .sum::<usize>();
+
+        assert!(sizes < 200 * 5);
+    }
+}
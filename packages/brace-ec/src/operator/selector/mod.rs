@@ -1,15 +1,27 @@
 pub mod and;
+pub mod annealing;
 pub mod best;
+pub mod clearing;
+pub mod double_tournament;
 pub mod fill;
 pub mod first;
 pub mod generate;
 pub mod hill_climb;
 pub mod lexicase;
 pub mod mutate;
+pub mod neighbours;
+pub mod parsimony;
+pub mod penalty;
 pub mod random;
 pub mod recombine;
+pub mod sharing;
+pub mod steepest_ascent;
+pub mod stochastic_ranking;
+pub mod tabu;
 pub mod take;
+pub mod tarpeian;
 pub mod tournament;
+pub mod truncation;
 pub mod windows;
 pub mod worst;
 
@@ -17,13 +29,18 @@ use std::error::Error;
 
 use crate::generation::Generation;
 use crate::individual::Individual;
+use crate::population::grid::Adjacency;
 use crate::population::Population;
 
 use self::and::And;
+use self::annealing::SimulatedAnnealing;
 use self::fill::{Fill, ParFill};
 use self::hill_climb::HillClimb;
 use self::mutate::Mutate;
+use self::neighbours::Neighbours;
 use self::recombine::Recombine;
+use self::steepest_ascent::SteepestAscent;
+use self::tabu::TabuSearch;
 use self::take::Take;
 use self::windows::{ArrayWindows, ParArrayWindows, ParWindows, Windows};
 
@@ -33,7 +50,10 @@ use super::evaluator::Evaluator;
 use super::evolver::select::Select;
 use super::inspect::Inspect;
 use super::mutator::Mutator;
+use super::neighbourhood::Neighbourhood;
 use super::recombinator::Recombinator;
+use super::repair::Repair;
+use super::repairer::Repairer;
 use super::repeat::{Repeat, RepeatN};
 use super::then::Then;
 
@@ -83,6 +103,13 @@ where
         self.evaluate(Function::new(evaluator))
     }
 
+    fn repair<R>(self, repairer: R) -> Repair<Self, R>
+    where
+        R: Repairer<P::Individual>,
+    {
+        Repair::new(self, repairer)
+    }
+
     fn hill_climb<M>(self, mutator: M, iterations: usize) -> HillClimb<Self, M>
     where
         M: Mutator<P::Individual>,
@@ -92,6 +119,37 @@ where
         HillClimb::new(self, mutator, iterations)
     }
 
+    fn anneal<M>(self, mutator: M, iterations: usize) -> SimulatedAnnealing<Self, M>
+    where
+        M: Mutator<P::Individual>,
+        Self: Selector<P, Output = [P::Individual; 1]>,
+        P::Individual: Clone,
+    {
+        SimulatedAnnealing::new(self, mutator, iterations)
+    }
+
+    fn steepest_ascent<N>(self, neighbourhood: N, iterations: usize) -> SteepestAscent<Self, N>
+    where
+        N: Neighbourhood<P::Individual>,
+        Self: Selector<P, Output = [P::Individual; 1]>,
+    {
+        SteepestAscent::new(self, neighbourhood, iterations)
+    }
+
+    fn tabu_search<N>(
+        self,
+        neighbourhood: N,
+        iterations: usize,
+        tenure: usize,
+    ) -> TabuSearch<Self, N>
+    where
+        N: Neighbourhood<P::Individual>,
+        Self: Selector<P, Output = [P::Individual; 1]>,
+        P::Individual: Clone,
+    {
+        TabuSearch::new(self, neighbourhood, iterations, tenure)
+    }
+
     fn evolver<G>(self) -> Select<Self, G>
     where
         G: Generation<Population = P>,
@@ -153,6 +211,13 @@ where
         ParArrayWindows::new(self)
     }
 
+    fn neighbours(self, adjacency: Adjacency) -> Neighbours<Self>
+    where
+        Self: Selector<[P::Individual]>,
+    {
+        Neighbours::new(self, adjacency)
+    }
+
     fn take<const N: usize>(self) -> Take<Self, N>
     where
         Self::Output: IntoIterator<Item = P::Individual>,
@@ -0,0 +1,74 @@
+use crate::individual::Individual;
+use crate::population::grid::{Adjacency, Grid};
+use crate::population::Population;
+
+use super::Selector;
+
+pub struct Neighbours<S> {
+    selector: S,
+    adjacency: Adjacency,
+}
+
+impl<S> Neighbours<S> {
+    pub fn new(selector: S, adjacency: Adjacency) -> Self {
+        Self {
+            selector,
+            adjacency,
+        }
+    }
+}
+
+impl<S, T> Selector<Grid<T>> for Neighbours<S>
+where
+    S: Selector<[T], Output: IntoIterator<Item = T>>,
+    T: Individual + Clone,
+{
+    type Output = Vec<T>;
+    type Error = S::Error;
+
+    fn select<Rng>(&self, population: &Grid<T>, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let mut output = Vec::with_capacity(population.len());
+
+        for index in 0..population.len() {
+            let neighbourhood = population.neighbourhood(index, self.adjacency);
+
+            output.extend(self.selector.select(&neighbourhood, rng)?);
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::mutator::add::Add;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::worst::Worst;
+    use crate::operator::selector::Selector;
+    use crate::population::grid::{Adjacency, Grid};
+    use crate::population::Population;
+
+    use super::Neighbours;
+
+    #[test]
+    fn test_select() {
+        let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+
+        let a = grid
+            .select(Neighbours::new(Best, Adjacency::von_neumann()))
+            .unwrap();
+        let b = grid
+            .select(Neighbours::new(Worst, Adjacency::moore()))
+            .unwrap();
+        let c = grid
+            .select(Best.mutate(Add(1)).neighbours(Adjacency::VonNeumann(0)))
+            .unwrap();
+
+        assert_eq!(a, [7, 8, 9, 7, 8, 9, 9, 9, 9]);
+        assert_eq!(b, [1; 9]);
+        assert_eq!(c, [2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+}
@@ -0,0 +1,90 @@
+use std::cmp::Reverse;
+use std::marker::PhantomData;
+
+use rand::seq::IteratorRandom;
+
+use crate::chromosome::size::Size;
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+
+use super::tournament::TournamentError;
+use super::Selector;
+
+pub struct Parsimony<P: Population + ?Sized> {
+    size: usize,
+    marker: PhantomData<fn() -> P>,
+}
+
+impl<P> Parsimony<P>
+where
+    P: Population + ?Sized,
+{
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn binary() -> Self {
+        Self::new(2)
+    }
+}
+
+impl<P> Selector<P> for Parsimony<P>
+where
+    P: IterablePopulation<Individual: Individual<Genome: Size> + Clone> + ?Sized,
+{
+    type Output = [P::Individual; 1];
+    type Error = TournamentError;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.size == 0 {
+            return Err(TournamentError::Empty);
+        }
+
+        if population.len() < self.size {
+            return Err(TournamentError::NotEnough);
+        }
+
+        Ok([population
+            .iter()
+            .choose_multiple(rng, self.size)
+            .into_iter()
+            .max_by_key(|individual| (individual.fitness(), Reverse(individual.genome().size())))
+            .expect("bound check")
+            .clone()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::selector::tournament::TournamentError;
+    use crate::population::Population;
+
+    use super::Parsimony;
+
+    #[test]
+    fn test_select() {
+        let population = [
+            Evaluated::new(vec![0; 5], 2),
+            Evaluated::new(vec![0; 2], 2),
+            Evaluated::new(vec![0; 3], 2),
+            Evaluated::new(vec![0; 9], 1),
+        ];
+
+        let a = population.select(Parsimony::new(4)).unwrap();
+        let b = population.select(Parsimony::binary()).unwrap();
+        let c = population.select(Parsimony::new(5));
+        let d = population.select(Parsimony::new(0));
+
+        assert_eq!(a, [Evaluated::new(vec![0; 2], 2)]);
+        assert!(population.contains(&b[0]));
+        assert_eq!(c, Err(TournamentError::NotEnough));
+        assert_eq!(d, Err(TournamentError::Empty));
+    }
+}
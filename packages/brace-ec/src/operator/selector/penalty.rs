@@ -0,0 +1,393 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use ordered_float::OrderedFloat;
+use thiserror::Error;
+
+use crate::fitness::constrained::Constrained;
+use crate::fitness::numeric::Numeric;
+use crate::individual::evaluated::Evaluated;
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+use crate::schedule::Clock;
+use crate::util::map::MapInto;
+
+use super::Selector;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scheme {
+    Static(f64),
+    Dynamic {
+        constant: f64,
+        alpha: f64,
+        beta: f64,
+    },
+    Adaptive {
+        coefficient: f64,
+        decrease: f64,
+        increase: f64,
+        window: usize,
+    },
+}
+
+impl Scheme {
+    fn is_valid(&self) -> bool {
+        match *self {
+            Self::Static(coefficient) => coefficient >= 0.0,
+            Self::Dynamic {
+                constant,
+                alpha,
+                beta,
+            } => constant >= 0.0 && alpha >= 0.0 && beta > 0.0,
+            Self::Adaptive {
+                coefficient,
+                decrease,
+                increase,
+                window,
+            } => coefficient > 0.0 && decrease > 1.0 && increase > 1.0 && window > 0,
+        }
+    }
+
+    fn coefficient(&self) -> f64 {
+        match *self {
+            Self::Static(coefficient) => coefficient,
+            Self::Dynamic { .. } => 0.0,
+            Self::Adaptive { coefficient, .. } => coefficient,
+        }
+    }
+}
+
+struct State {
+    generation: Option<u64>,
+    coefficient: f64,
+    history: VecDeque<bool>,
+}
+
+pub struct Penalty<S> {
+    selector: S,
+    scheme: Scheme,
+    clock: Clock,
+    state: Mutex<State>,
+}
+
+impl<S> Penalty<S> {
+    pub fn new(selector: S, scheme: Scheme) -> Self {
+        Self {
+            selector,
+            scheme,
+            clock: Clock::new(),
+            state: Mutex::new(State {
+                generation: None,
+                coefficient: scheme.coefficient(),
+                history: VecDeque::new(),
+            }),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn coefficient(&self) -> f64 {
+        self.state.lock().unwrap().coefficient
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.clock.get()
+    }
+
+    pub fn penalize<T>(&self, fitness: &Constrained<T>) -> f64
+    where
+        T: Numeric,
+    {
+        let state = self.state.lock().unwrap();
+
+        self.score(fitness, state.coefficient)
+    }
+
+    fn score<T>(&self, fitness: &Constrained<T>, coefficient: f64) -> f64
+    where
+        T: Numeric,
+    {
+        let violation = match self.scheme {
+            Scheme::Dynamic { beta, .. } => fitness.violation().powf(beta),
+            _ => fitness.violation(),
+        };
+
+        fitness.objective().as_f64() - coefficient * violation
+    }
+
+    fn advance(&self, state: &mut State, generation: u64) {
+        match self.scheme {
+            Scheme::Static(_) => {}
+            Scheme::Dynamic {
+                constant, alpha, ..
+            } => {
+                state.coefficient = (constant * (generation + 1) as f64).powf(alpha);
+            }
+            Scheme::Adaptive {
+                decrease,
+                increase,
+                window,
+                ..
+            } => {
+                if state.history.len() == window {
+                    if state.history.iter().all(|feasible| *feasible) {
+                        state.coefficient /= decrease;
+                    } else if state.history.iter().all(|feasible| !*feasible) {
+                        state.coefficient *= increase;
+                    }
+                }
+            }
+        }
+
+        state.generation = Some(generation);
+    }
+
+    fn record(&self, state: &mut State, feasible: bool) {
+        if let Scheme::Adaptive { window, .. } = self.scheme {
+            state.history.push_back(feasible);
+
+            if state.history.len() > window {
+                state.history.pop_front();
+            }
+        }
+    }
+}
+
+impl<P, I, T, S> Selector<P> for Penalty<S>
+where
+    P: IterablePopulation<Individual = I> + ?Sized,
+    I: Individual<Fitness = Constrained<T>> + Clone,
+    T: Numeric,
+    S: Selector<
+        [Evaluated<I, OrderedFloat<f64>>],
+        Output: MapInto<
+            I,
+            Item = Evaluated<I, OrderedFloat<f64>>,
+            Output: Population<Individual = I>,
+        >,
+    >,
+{
+    type Output = <S::Output as MapInto<I>>::Output;
+    type Error = PenaltyError<S::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if !self.scheme.is_valid() {
+            return Err(PenaltyError::InvalidScheme);
+        }
+
+        let generation = self.clock.get();
+        let mut state = self.state.lock().unwrap();
+        let first = state.generation != Some(generation);
+
+        if first {
+            self.advance(&mut state, generation);
+        }
+
+        let penalized = population
+            .iter()
+            .map(|individual| {
+                let fitness = self.score(individual.fitness(), state.coefficient);
+
+                Evaluated::new(individual.clone(), OrderedFloat(fitness))
+            })
+            .collect::<Vec<_>>();
+
+        if first {
+            let feasible = penalized
+                .iter()
+                .max_by_key(|individual| individual.fitness)
+                .is_some_and(|individual| individual.individual.fitness().is_feasible());
+
+            self.record(&mut state, feasible);
+        }
+
+        drop(state);
+
+        Ok(self
+            .selector
+            .select(&penalized, rng)
+            .map_err(PenaltyError::Select)?
+            .map_into(|individual| individual.individual))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PenaltyError<S> {
+    #[error("penalty scheme parameters are out of range")]
+    InvalidScheme,
+    #[error(transparent)]
+    Select(S),
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use crate::fitness::constrained::Constrained;
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evolver::Evolver;
+    use crate::operator::selector::best::Best;
+    use crate::operator::selector::Selector;
+    use crate::population::Population;
+    use crate::schedule::Clock;
+
+    use super::{Penalty, PenaltyError, Scheme};
+
+    type Solution = Evaluated<u8, Constrained<OrderedFloat<f64>>>;
+
+    fn population() -> [Solution; 3] {
+        [
+            Evaluated::new(0, Constrained::feasible(OrderedFloat(5.0))),
+            Evaluated::new(1, Constrained::new(OrderedFloat(10.0), 1.0)),
+            Evaluated::new(2, Constrained::new(OrderedFloat(20.0), 2.0)),
+        ]
+    }
+
+    #[test]
+    fn test_select_static() {
+        let population = population();
+
+        let a = population
+            .select(Penalty::new(Best, Scheme::Static(0.0)))
+            .unwrap();
+        let b = population
+            .select(Penalty::new(Best, Scheme::Static(7.0)))
+            .unwrap();
+        let c = population
+            .select(Penalty::new(Best, Scheme::Static(100.0)))
+            .unwrap();
+
+        assert_eq!(a[0].individual, 2);
+        assert_eq!(b[0].individual, 2);
+        assert_eq!(c[0].individual, 0);
+    }
+
+    #[test]
+    fn test_select_dynamic() {
+        let mut rng = rand::rng();
+        let clock = Clock::new();
+        let population = population();
+        let penalty = Penalty::new(
+            Best,
+            Scheme::Dynamic {
+                constant: 2.0,
+                alpha: 2.0,
+                beta: 1.0,
+            },
+        )
+        .with_clock(clock.clone());
+
+        let a = penalty.select(&population, &mut rng).unwrap();
+        let b = penalty.select(&population, &mut rng).unwrap();
+
+        assert_eq!(a[0].individual, 2);
+        assert_eq!(b[0].individual, 2);
+        assert_eq!(penalty.coefficient(), 4.0);
+
+        clock.set(1);
+
+        let c = penalty.select(&population, &mut rng).unwrap();
+
+        assert_eq!(c[0].individual, 0);
+        assert_eq!(penalty.generation(), 1);
+        assert_eq!(penalty.coefficient(), 16.0);
+    }
+
+    #[test]
+    fn test_select_clocked() {
+        let mut rng = rand::rng();
+        let evolver = || {
+            let clock = Clock::new();
+            let penalty = Penalty::new(
+                Best,
+                Scheme::Dynamic {
+                    constant: 2.0,
+                    alpha: 2.0,
+                    beta: 1.0,
+                },
+            )
+            .with_clock(clock.clone());
+
+            Selector::<[Solution; 3]>::fill(penalty)
+                .evolver()
+                .clocked(clock)
+        };
+
+        let (_, a) = evolver().evolve((0, population()), &mut rng).unwrap();
+        let (_, b) = evolver().evolve((5, population()), &mut rng).unwrap();
+
+        assert_eq!(a.map(|individual| individual.individual), [2, 2, 2]);
+        assert_eq!(b.map(|individual| individual.individual), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_select_adaptive() {
+        let mut rng = rand::rng();
+        let clock = Clock::new();
+        let population = population();
+        let penalty = Penalty::new(
+            Best,
+            Scheme::Adaptive {
+                coefficient: 1.0,
+                decrease: 2.0,
+                increase: 4.0,
+                window: 2,
+            },
+        )
+        .with_clock(clock.clone());
+
+        let a = penalty.select(&population, &mut rng).unwrap();
+
+        clock.set(1);
+
+        let b = penalty.select(&population, &mut rng).unwrap();
+        let _ = penalty.select(&population, &mut rng).unwrap();
+
+        assert_eq!(a[0].individual, 2);
+        assert_eq!(b[0].individual, 2);
+        assert_eq!(penalty.coefficient(), 1.0);
+
+        clock.set(2);
+
+        let c = penalty.select(&population, &mut rng).unwrap();
+
+        assert_eq!(c[0].individual, 2);
+        assert_eq!(penalty.coefficient(), 4.0);
+
+        clock.set(3);
+
+        let d = penalty.select(&population, &mut rng).unwrap();
+
+        assert_eq!(d[0].individual, 0);
+        assert_eq!(penalty.coefficient(), 16.0);
+        assert_eq!(
+            penalty.penalize(&Constrained::new(OrderedFloat(20.0), 2.0)),
+            -12.0
+        );
+    }
+
+    #[test]
+    fn test_select_error() {
+        let population = population();
+
+        let a = population.select(Penalty::new(Best, Scheme::Static(-1.0)));
+        let b = population.select(Penalty::new(
+            Best,
+            Scheme::Adaptive {
+                coefficient: 1.0,
+                decrease: 1.0,
+                increase: 2.0,
+                window: 1,
+            },
+        ));
+
+        assert_eq!(a, Err(PenaltyError::InvalidScheme));
+        assert_eq!(b, Err(PenaltyError::InvalidScheme));
+    }
+}
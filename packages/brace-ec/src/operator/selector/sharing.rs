@@ -0,0 +1,170 @@
+use ordered_float::OrderedFloat;
+use thiserror::Error;
+
+use crate::distance::{Genotypic, Metric};
+use crate::fitness::numeric::Numeric;
+use crate::individual::evaluated::Evaluated;
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+use crate::util::map::MapInto;
+
+use super::Selector;
+
+pub struct Sharing<S, D = Genotypic> {
+    selector: S,
+    radius: f64,
+    alpha: f64,
+    metric: D,
+}
+
+impl<S> Sharing<S> {
+    pub fn new(selector: S, radius: f64) -> Self {
+        Self {
+            selector,
+            radius,
+            alpha: 1.0,
+            metric: Genotypic,
+        }
+    }
+}
+
+impl<S, D> Sharing<S, D> {
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn with_distance<T>(self, metric: T) -> Sharing<S, T> {
+        Sharing {
+            selector: self.selector,
+            radius: self.radius,
+            alpha: self.alpha,
+            metric,
+        }
+    }
+
+    pub fn share<I>(&self, population: &[I]) -> Vec<f64>
+    where
+        I: Individual<Fitness: Numeric>,
+        D: Metric<I>,
+    {
+        population
+            .iter()
+            .map(|individual| {
+                let count = population
+                    .iter()
+                    .map(|other| self.metric.measure(individual, other))
+                    .filter(|distance| *distance < self.radius)
+                    .map(|distance| 1.0 - (distance / self.radius).powf(self.alpha))
+                    .sum::<f64>()
+                    .max(1.0);
+
+                let fitness = individual.fitness().as_f64();
+
+                if fitness >= 0.0 {
+                    fitness / count
+                } else {
+                    fitness * count
+                }
+            })
+            .collect()
+    }
+}
+
+impl<P, I, S, D> Selector<P> for Sharing<S, D>
+where
+    P: IterablePopulation<Individual = I> + ?Sized,
+    I: Individual<Fitness: Numeric> + Clone,
+    S: Selector<
+        [Evaluated<I, OrderedFloat<f64>>],
+        Output: MapInto<
+            I,
+            Item = Evaluated<I, OrderedFloat<f64>>,
+            Output: Population<Individual = I>,
+        >,
+    >,
+    D: Metric<I>,
+{
+    type Output = <S::Output as MapInto<I>>::Output;
+    type Error = SharingError<S::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if self.radius <= 0.0 {
+            return Err(SharingError::InvalidRadius);
+        }
+
+        let individuals = population.iter().cloned().collect::<Vec<_>>();
+
+        let shared = self
+            .share(&individuals)
+            .into_iter()
+            .zip(individuals)
+            .map(|(fitness, individual)| Evaluated::new(individual, OrderedFloat(fitness)))
+            .collect::<Vec<_>>();
+
+        Ok(self
+            .selector
+            .select(&shared, rng)
+            .map_err(SharingError::Select)?
+            .map_into(|individual| individual.individual))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SharingError<S> {
+    #[error("sharing radius must be greater than zero")]
+    InvalidRadius,
+    #[error(transparent)]
+    Select(S),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::selector::best::Best;
+    use crate::population::Population;
+
+    use super::{Sharing, SharingError};
+
+    fn population() -> Vec<Evaluated<f64, u8>> {
+        vec![
+            Evaluated::new(0.0, 10),
+            Evaluated::new(0.1, 10),
+            Evaluated::new(0.2, 10),
+            Evaluated::new(5.0, 8),
+        ]
+    }
+
+    #[test]
+    fn test_share() {
+        let population = population();
+
+        let a = Sharing::new((), 1.0).share(&population);
+        let b = Sharing::new((), 0.05).share(&population);
+
+        assert!((a[0] - 10.0 / 2.7).abs() < 1e-9);
+        assert!((a[1] - 10.0 / 2.8).abs() < 1e-9);
+        assert_eq!(a[3], 8.0);
+        assert_eq!(b, [10.0, 10.0, 10.0, 8.0]);
+    }
+
+    #[test]
+    fn test_select() {
+        let population = population();
+
+        let a = population.select(Sharing::new(Best, 1.0)).unwrap();
+        let b = population.select(Sharing::new(Best, 0.05)).unwrap();
+        let c = population
+            .select(Sharing::new(Best, 1.0).with_distance(|_: &_, _: &_| 0.0))
+            .unwrap();
+        let d = population.select(Sharing::new(Best, 0.0));
+
+        assert_eq!(a, [Evaluated::new(5.0, 8)]);
+        assert_eq!(b, [Evaluated::new(0.2, 10)]);
+        assert_eq!(c, [Evaluated::new(0.2, 10)]);
+        assert!(matches!(d, Err(SharingError::InvalidRadius)));
+    }
+}
@@ -0,0 +1,116 @@
+use thiserror::Error;
+
+use crate::individual::Individual;
+use crate::operator::neighbourhood::Neighbourhood;
+use crate::population::Population;
+
+use super::Selector;
+
+pub struct SteepestAscent<S, N> {
+    selector: S,
+    neighbourhood: N,
+    iterations: usize,
+}
+
+impl<S, N> SteepestAscent<S, N> {
+    pub fn new(selector: S, neighbourhood: N, iterations: usize) -> Self {
+        Self {
+            selector,
+            neighbourhood,
+            iterations,
+        }
+    }
+}
+
+impl<P, S, N> Selector<P> for SteepestAscent<S, N>
+where
+    P: Population + ?Sized,
+    S: Selector<P, Output = [P::Individual; 1]>,
+    N: Neighbourhood<P::Individual>,
+{
+    type Output = [P::Individual; 1];
+    type Error = SteepestAscentError<S::Error, N::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let [mut individual] = self
+            .selector
+            .select(population, rng)
+            .map_err(SteepestAscentError::Select)?;
+
+        for _ in 0..self.iterations {
+            let best = self
+                .neighbourhood
+                .neighbours(&individual, rng)
+                .map_err(SteepestAscentError::Neighbourhood)?
+                .into_iter()
+                .map(|(_, neighbour)| neighbour)
+                .max_by(|a, b| a.fitness().cmp(b.fitness()));
+
+            match best {
+                Some(best) if best.fitness() > individual.fitness() => individual = best,
+                _ => break,
+            }
+        }
+
+        Ok([individual])
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SteepestAscentError<S, N> {
+    #[error(transparent)]
+    Select(S),
+    #[error(transparent)]
+    Neighbourhood(N),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evaluator::function::Function;
+    use crate::operator::neighbourhood::flip::Flip;
+    use crate::operator::neighbourhood::swap::Swap;
+    use crate::operator::neighbourhood::Neighbourhood;
+    use crate::operator::selector::first::First;
+    use crate::operator::selector::Selector;
+
+    fn ones(individual: &Evaluated<[bool; 8], usize>) -> Result<usize, Infallible> {
+        Ok(individual.individual.iter().filter(|gene| **gene).count())
+    }
+
+    fn sorted(individual: &Evaluated<[u8; 5], usize>) -> Result<usize, Infallible> {
+        Ok(individual
+            .individual
+            .iter()
+            .enumerate()
+            .filter(|(index, gene)| *index == **gene as usize)
+            .count())
+    }
+
+    #[test]
+    fn test_select() {
+        let mut rng = rand::rng();
+
+        let a = First
+            .steepest_ascent(Flip.evaluate(Function::new(ones)), 5)
+            .select(&[Evaluated::new([false; 8], 0)], &mut rng)
+            .unwrap();
+        let b = First
+            .steepest_ascent(Flip.par_evaluate(Function::new(ones)), 20)
+            .select(&[Evaluated::new([false; 8], 0)], &mut rng)
+            .unwrap();
+        let c = First
+            .steepest_ascent(Swap.evaluate(Function::new(sorted)), 20)
+            .select(&[Evaluated::new([4, 3, 2, 1, 0], 1)], &mut rng)
+            .unwrap();
+
+        assert_eq!(a[0].fitness, 5);
+        assert_eq!(b[0], Evaluated::new([true; 8], 8));
+        assert_eq!(c[0], Evaluated::new([0, 1, 2, 3, 4], 5));
+    }
+}
@@ -0,0 +1,182 @@
+use thiserror::Error;
+
+use crate::fitness::constrained::Constrained;
+use crate::individual::evaluated::Evaluated;
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+use crate::util::map::MapInto;
+
+use super::Selector;
+
+pub struct StochasticRanking<S> {
+    selector: S,
+    probability: f64,
+    sweeps: Option<usize>,
+}
+
+impl<S> StochasticRanking<S> {
+    pub fn new(selector: S) -> Self {
+        Self {
+            selector,
+            probability: 0.45,
+            sweeps: None,
+        }
+    }
+
+    pub fn with_probability(mut self, probability: f64) -> Self {
+        self.probability = probability;
+        self
+    }
+
+    pub fn with_sweeps(mut self, sweeps: usize) -> Self {
+        self.sweeps = Some(sweeps);
+        self
+    }
+
+    pub fn rank<T, Rng>(&self, fitness: &[&Constrained<T>], rng: &mut Rng) -> Vec<usize>
+    where
+        T: Ord,
+        Rng: rand::Rng + ?Sized,
+    {
+        let mut ranking = (0..fitness.len()).collect::<Vec<_>>();
+
+        for _ in 0..self.sweeps.unwrap_or(fitness.len()) {
+            let mut swapped = false;
+
+            for index in 1..ranking.len() {
+                let a = fitness[ranking[index - 1]];
+                let b = fitness[ranking[index]];
+
+                let swap = if (a.is_feasible() && b.is_feasible())
+                    || rng.random::<f64>() < self.probability
+                {
+                    a.objective() < b.objective()
+                } else {
+                    a.violation() > b.violation()
+                };
+
+                if swap {
+                    ranking.swap(index - 1, index);
+                    swapped = true;
+                }
+            }
+
+            if !swapped {
+                break;
+            }
+        }
+
+        ranking
+    }
+}
+
+impl<P, I, T, S> Selector<P> for StochasticRanking<S>
+where
+    P: IterablePopulation<Individual = I> + ?Sized,
+    I: Individual<Fitness = Constrained<T>> + Clone,
+    T: Ord,
+    S: Selector<
+        [Evaluated<I, usize>],
+        Output: MapInto<I, Item = Evaluated<I, usize>, Output: Population<Individual = I>>,
+    >,
+{
+    type Output = <S::Output as MapInto<I>>::Output;
+    type Error = StochasticRankingError<S::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if !(0.0..=1.0).contains(&self.probability) {
+            return Err(StochasticRankingError::InvalidProbability);
+        }
+
+        let individuals = population.iter().collect::<Vec<_>>();
+        let fitness = individuals
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect::<Vec<_>>();
+
+        let ranking = self.rank(&fitness, rng);
+        let count = ranking.len();
+
+        let ranked = ranking
+            .into_iter()
+            .enumerate()
+            .map(|(rank, index)| Evaluated::new(individuals[index].clone(), count - rank - 1))
+            .collect::<Vec<_>>();
+
+        Ok(self
+            .selector
+            .select(&ranked, rng)
+            .map_err(StochasticRankingError::Select)?
+            .map_into(|individual| individual.individual))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum StochasticRankingError<S> {
+    #[error("comparison probability must be between 0 and 1")]
+    InvalidProbability,
+    #[error(transparent)]
+    Select(S),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fitness::constrained::Constrained;
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::selector::best::Best;
+    use crate::population::Population;
+
+    use super::{StochasticRanking, StochasticRankingError};
+
+    type Solution = Evaluated<u8, Constrained<i32>>;
+
+    fn population() -> [Solution; 4] {
+        [
+            Evaluated::new(0, Constrained::new(50, 3.0)),
+            Evaluated::new(1, Constrained::feasible(5)),
+            Evaluated::new(2, Constrained::new(20, 1.0)),
+            Evaluated::new(3, Constrained::feasible(10)),
+        ]
+    }
+
+    #[test]
+    fn test_rank() {
+        let mut rng = rand::rng();
+
+        let population = population();
+        let fitness = population
+            .iter()
+            .map(|individual| &individual.fitness)
+            .collect::<Vec<_>>();
+
+        let a = StochasticRanking::new(())
+            .with_probability(0.0)
+            .rank(&fitness, &mut rng);
+        let b = StochasticRanking::new(())
+            .with_probability(1.0)
+            .rank(&fitness, &mut rng);
+
+        assert_eq!(a, [3, 1, 2, 0]);
+        assert_eq!(b, [0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_select() {
+        let population = population();
+
+        let a = population
+            .select(StochasticRanking::new(Best).with_probability(0.0))
+            .unwrap();
+        let b = population
+            .select(StochasticRanking::new(Best).with_probability(1.0))
+            .unwrap();
+        let c = population.select(StochasticRanking::new(Best).with_probability(2.0));
+
+        assert_eq!(a, [Evaluated::new(3, Constrained::feasible(10))]);
+        assert_eq!(b, [Evaluated::new(0, Constrained::new(50, 3.0))]);
+        assert_eq!(c, Err(StochasticRankingError::InvalidProbability));
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use thiserror::Error;
+
+use crate::individual::Individual;
+use crate::operator::neighbourhood::Neighbourhood;
+use crate::population::Population;
+
+use super::Selector;
+
+pub trait Attribute<M, T> {
+    type Key: Eq;
+
+    fn key(&self, r#move: &M, individual: &T) -> Self::Key;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByMove;
+
+impl<M, T> Attribute<M, T> for ByMove
+where
+    M: Clone + Eq,
+{
+    type Key = M;
+
+    fn key(&self, r#move: &M, _: &T) -> Self::Key {
+        r#move.clone()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByGenome;
+
+impl<M, T> Attribute<M, T> for ByGenome
+where
+    T: Individual<Genome: Hash>,
+{
+    type Key = u64;
+
+    fn key(&self, _: &M, individual: &T) -> Self::Key {
+        let mut hasher = DefaultHasher::new();
+
+        individual.genome().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+pub struct TabuSearch<S, N, A = ByMove> {
+    selector: S,
+    neighbourhood: N,
+    iterations: usize,
+    tenure: usize,
+    attribute: A,
+    aspiration: bool,
+}
+
+impl<S, N> TabuSearch<S, N> {
+    pub fn new(selector: S, neighbourhood: N, iterations: usize, tenure: usize) -> Self {
+        Self {
+            selector,
+            neighbourhood,
+            iterations,
+            tenure,
+            attribute: ByMove,
+            aspiration: true,
+        }
+    }
+}
+
+impl<S, N, A> TabuSearch<S, N, A> {
+    pub fn with_attribute<B>(self, attribute: B) -> TabuSearch<S, N, B> {
+        TabuSearch {
+            selector: self.selector,
+            neighbourhood: self.neighbourhood,
+            iterations: self.iterations,
+            tenure: self.tenure,
+            attribute,
+            aspiration: self.aspiration,
+        }
+    }
+
+    pub fn with_aspiration(mut self, aspiration: bool) -> Self {
+        self.aspiration = aspiration;
+        self
+    }
+}
+
+impl<P, S, N, A> Selector<P> for TabuSearch<S, N, A>
+where
+    P: Population<Individual: Clone> + ?Sized,
+    S: Selector<P, Output = [P::Individual; 1]>,
+    N: Neighbourhood<P::Individual>,
+    A: Attribute<N::Move, P::Individual>,
+{
+    type Output = [P::Individual; 1];
+    type Error = TabuSearchError<S::Error, N::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        let [mut current] = self
+            .selector
+            .select(population, rng)
+            .map_err(TabuSearchError::Select)?;
+
+        let mut best = current.clone();
+        let mut tabu = VecDeque::with_capacity(self.tenure + 1);
+
+        for _ in 0..self.iterations {
+            let candidate = self
+                .neighbourhood
+                .neighbours(&current, rng)
+                .map_err(TabuSearchError::Neighbourhood)?
+                .into_iter()
+                .map(|(r#move, neighbour)| (self.attribute.key(&r#move, &neighbour), neighbour))
+                .filter(|(key, neighbour)| {
+                    !tabu.contains(key) || (self.aspiration && neighbour.fitness() > best.fitness())
+                })
+                .max_by(|(_, a), (_, b)| a.fitness().cmp(b.fitness()));
+
+            let Some((key, neighbour)) = candidate else {
+                break;
+            };
+
+            if self.tenure > 0 {
+                tabu.push_back(key);
+
+                if tabu.len() > self.tenure {
+                    tabu.pop_front();
+                }
+            }
+
+            current = neighbour;
+
+            if current.fitness() > best.fitness() {
+                best = current.clone();
+            }
+        }
+
+        Ok([best])
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TabuSearchError<S, N> {
+    #[error(transparent)]
+    Select(S),
+    #[error(transparent)]
+    Neighbourhood(N),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::evaluator::function::Function;
+    use crate::operator::neighbourhood::flip::Flip;
+    use crate::operator::neighbourhood::Neighbourhood;
+    use crate::operator::selector::first::First;
+    use crate::operator::selector::Selector;
+
+    use super::ByGenome;
+
+    type Trap = Evaluated<[bool; 4], usize>;
+
+    fn trap(individual: &Trap) -> Result<usize, Infallible> {
+        match individual.individual.iter().filter(|gene| **gene).count() {
+            4 => Ok(5),
+            ones => Ok(3 - ones),
+        }
+    }
+
+    #[test]
+    fn test_select() {
+        let mut rng = rand::rng();
+        let population = [Evaluated::new([false; 4], 3)];
+
+        let a = First
+            .steepest_ascent(Flip.evaluate(Function::new(trap)), 10)
+            .select(&population, &mut rng)
+            .unwrap();
+        let b = First
+            .tabu_search(Flip.evaluate(Function::new(trap)), 10, 4)
+            .select(&population, &mut rng)
+            .unwrap();
+        let c = First
+            .tabu_search(Flip.par_evaluate(Function::new(trap)), 10, 16)
+            .with_attribute(ByGenome)
+            .select(&population, &mut rng)
+            .unwrap();
+        let d = First
+            .tabu_search(Flip.evaluate(Function::new(trap)), 10, 0)
+            .select(&population, &mut rng)
+            .unwrap();
+
+        assert_eq!(a[0], Evaluated::new([false; 4], 3));
+        assert_eq!(b[0], Evaluated::new([true; 4], 5));
+        assert_eq!(c[0], Evaluated::new([true; 4], 5));
+        assert_eq!(d[0], Evaluated::new([false; 4], 3));
+    }
+
+    fn path(individual: &Evaluated<[bool; 3], usize>) -> Result<usize, Infallible> {
+        match individual.individual {
+            [false, false, false] => Ok(4),
+            [false, false, true] => Ok(2),
+            [false, true, true] => Ok(3),
+            [true, true, true] => Ok(1),
+            [true, true, false] => Ok(10),
+            _ => Ok(0),
+        }
+    }
+
+    #[test]
+    fn test_select_aspiration() {
+        let mut rng = rand::rng();
+        let population = [Evaluated::new([false; 3], 4)];
+
+        let a = First
+            .tabu_search(Flip.evaluate(Function::new(path)), 4, 3)
+            .select(&population, &mut rng)
+            .unwrap();
+        let b = First
+            .tabu_search(Flip.evaluate(Function::new(path)), 4, 3)
+            .with_aspiration(false)
+            .select(&population, &mut rng)
+            .unwrap();
+
+        assert_eq!(a[0], Evaluated::new([true, true, false], 10));
+        assert_eq!(b[0], Evaluated::new([false; 3], 4));
+    }
+}
@@ -0,0 +1,106 @@
+use thiserror::Error;
+
+use crate::chromosome::size::Size;
+use crate::individual::evaluated::Evaluated;
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+use crate::util::map::MapInto;
+
+use super::Selector;
+
+pub struct Tarpeian<S> {
+    selector: S,
+    rate: f64,
+}
+
+impl<S> Tarpeian<S> {
+    pub fn new(selector: S, rate: f64) -> Self {
+        Self { selector, rate }
+    }
+}
+
+impl<P, I, S> Selector<P> for Tarpeian<S>
+where
+    P: IterablePopulation<Individual = I> + ?Sized,
+    I: Individual<Genome: Size, Fitness: Clone> + Clone,
+    S: Selector<
+        [Evaluated<I, Option<I::Fitness>>],
+        Output: MapInto<
+            I,
+            Item = Evaluated<I, Option<I::Fitness>>,
+            Output: Population<Individual = I>,
+        >,
+    >,
+{
+    type Output = <S::Output as MapInto<I>>::Output;
+    type Error = TarpeianError<S::Error>;
+
+    fn select<Rng>(&self, population: &P, rng: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if !(0.0..=1.0).contains(&self.rate) {
+            return Err(TarpeianError::InvalidRate);
+        }
+
+        let average = population
+            .iter()
+            .map(|individual| individual.genome().size() as f64)
+            .sum::<f64>()
+            / population.len().max(1) as f64;
+
+        let penalized = population
+            .iter()
+            .map(|individual| {
+                let fitness = match individual.genome().size() as f64 > average
+                    && rng.random_bool(self.rate)
+                {
+                    true => None,
+                    false => Some(individual.fitness().clone()),
+                };
+
+                Evaluated::new(individual.clone(), fitness)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(self
+            .selector
+            .select(&penalized, rng)
+            .map_err(TarpeianError::Select)?
+            .map_into(|individual| individual.individual))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TarpeianError<S> {
+    #[error("tarpeian rate must be between 0 and 1")]
+    InvalidRate,
+    #[error(transparent)]
+    Select(S),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::operator::selector::best::Best;
+    use crate::population::Population;
+
+    use super::{Tarpeian, TarpeianError};
+
+    #[test]
+    fn test_select() {
+        let population = [
+            Evaluated::new(vec![0; 2], 1),
+            Evaluated::new(vec![0; 3], 2),
+            Evaluated::new(vec![0; 10], 5),
+        ];
+
+        let a = population.select(Tarpeian::new(Best, 0.0)).unwrap();
+        let b = population.select(Tarpeian::new(Best, 1.0)).unwrap();
+        let c = population.select(Tarpeian::new(Best, 1.5));
+
+        assert_eq!(a, [Evaluated::new(vec![0; 10], 5)]);
+        assert_eq!(b, [Evaluated::new(vec![0; 3], 2)]);
+        assert_eq!(c, Err(TarpeianError::InvalidRate));
+    }
+}
@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+
+use thiserror::Error;
+
+use crate::individual::Individual;
+use crate::population::{IterablePopulation, Population};
+
+use super::Selector;
+
+pub struct Truncation<P: Population + ?Sized> {
+    size: usize,
+    marker: PhantomData<fn() -> P>,
+}
+
+impl<P> Truncation<P>
+where
+    P: Population + ?Sized,
+{
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<P> Selector<P> for Truncation<P>
+where
+    P: IterablePopulation<Individual: Clone> + ?Sized,
+{
+    type Output = Vec<P::Individual>;
+    type Error = TruncationError;
+
+    fn select<Rng>(&self, population: &P, _: &mut Rng) -> Result<Self::Output, Self::Error>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if population.len() < self.size {
+            return Err(TruncationError::NotEnough);
+        }
+
+        let mut individuals = population.iter().collect::<Vec<_>>();
+
+        individuals.sort_by(|a, b| b.fitness().cmp(a.fitness()));
+
+        Ok(individuals.into_iter().take(self.size).cloned().collect())
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TruncationError {
+    #[error("not enough individuals")]
+    NotEnough,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::population::Population;
+
+    use super::{Truncation, TruncationError};
+
+    #[test]
+    fn test_select() {
+        let population = [3, 1, 4, 1, 5];
+
+        assert_eq!(population.select(Truncation::new(3)), Ok(vec![5, 4, 3]));
+        assert_eq!(population.select(Truncation::new(0)), Ok(vec![]));
+        assert_eq!(
+            population.select(Truncation::new(6)),
+            Err(TruncationError::NotEnough)
+        );
+    }
+}
@@ -0,0 +1,441 @@
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+
+use crate::distance::euclidean;
+use crate::fitness::numeric::Numeric;
+use crate::individual::Individual;
+
+use super::Population;
+
+pub trait Descriptor<T> {
+    fn describe(&self, individual: &T) -> Vec<f64>;
+}
+
+impl<T, F, D> Descriptor<T> for F
+where
+    F: Fn(&T) -> D,
+    D: AsRef<[f64]>,
+{
+    fn describe(&self, individual: &T) -> Vec<f64> {
+        self(individual).as_ref().to_vec()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dimension {
+    min: f64,
+    max: f64,
+    bins: usize,
+}
+
+impl Dimension {
+    pub fn new(range: RangeInclusive<f64>, bins: usize) -> Result<Self, ElitesError> {
+        let (min, max) = range.into_inner();
+
+        if bins == 0 || !min.is_finite() || !max.is_finite() || min >= max {
+            return Err(ElitesError::InvalidDimension);
+        }
+
+        Ok(Self { min, max, bins })
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    pub fn bins(&self) -> usize {
+        self.bins
+    }
+
+    pub fn bin(&self, value: f64) -> usize {
+        let scaled = (value - self.min) / (self.max - self.min) * self.bins as f64;
+
+        (scaled.max(0.0) as usize).min(self.bins - 1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tessellation {
+    Grid(Vec<Dimension>),
+    Centroids(Vec<Vec<f64>>),
+}
+
+impl Tessellation {
+    pub fn grid<T>(dimensions: T) -> Self
+    where
+        T: IntoIterator<Item = Dimension>,
+    {
+        Self::Grid(dimensions.into_iter().collect())
+    }
+
+    pub fn centroids(centroids: Vec<Vec<f64>>) -> Self {
+        Self::Centroids(centroids)
+    }
+
+    pub fn cvt<Rng>(
+        cells: usize,
+        bounds: &[RangeInclusive<f64>],
+        samples: usize,
+        iterations: usize,
+        rng: &mut Rng,
+    ) -> Result<Self, ElitesError>
+    where
+        Rng: rand::Rng + ?Sized,
+    {
+        if cells == 0 || samples < cells {
+            return Err(ElitesError::InvalidSamples);
+        }
+
+        if bounds.is_empty() || bounds.iter().any(|range| range.start() >= range.end()) {
+            return Err(ElitesError::InvalidDimension);
+        }
+
+        let points = (0..samples)
+            .map(|_| {
+                bounds
+                    .iter()
+                    .map(|range| rng.random_range(range.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut centroids = points[..cells].to_vec();
+
+        for _ in 0..iterations {
+            let mut sums = vec![vec![0.0; bounds.len()]; cells];
+            let mut counts = vec![0; cells];
+
+            for point in &points {
+                let nearest = nearest(&centroids, point);
+
+                for (sum, value) in sums[nearest].iter_mut().zip(point) {
+                    *sum += value;
+                }
+
+                counts[nearest] += 1;
+            }
+
+            for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+                if count > 0 {
+                    *centroid = sum.into_iter().map(|sum| sum / count as f64).collect();
+                }
+            }
+        }
+
+        Ok(Self::Centroids(centroids))
+    }
+
+    pub fn cells(&self) -> usize {
+        match self {
+            Self::Grid(dimensions) => dimensions.iter().map(Dimension::bins).product(),
+            Self::Centroids(centroids) => centroids.len(),
+        }
+    }
+
+    pub fn dimensions(&self) -> usize {
+        match self {
+            Self::Grid(dimensions) => dimensions.len(),
+            Self::Centroids(centroids) => centroids.first().map_or(0, Vec::len),
+        }
+    }
+
+    pub fn bounds(&self) -> Vec<RangeInclusive<f64>> {
+        match self {
+            Self::Grid(dimensions) => dimensions
+                .iter()
+                .map(|dimension| dimension.min..=dimension.max)
+                .collect(),
+            Self::Centroids(centroids) => (0..self.dimensions())
+                .map(|index| {
+                    let values = centroids.iter().map(|centroid| centroid[index]);
+
+                    values.clone().fold(f64::INFINITY, f64::min)
+                        ..=values.fold(f64::NEG_INFINITY, f64::max)
+                })
+                .collect(),
+        }
+    }
+
+    pub fn cell(&self, descriptor: &[f64]) -> Option<usize> {
+        if descriptor.len() != self.dimensions() || descriptor.iter().any(|value| value.is_nan()) {
+            return None;
+        }
+
+        match self {
+            Self::Grid(dimensions) => {
+                let mut stride = 1;
+                let mut cell = 0;
+
+                for (dimension, value) in dimensions.iter().zip(descriptor) {
+                    cell += dimension.bin(*value) * stride;
+                    stride *= dimension.bins;
+                }
+
+                Some(cell)
+            }
+            Self::Centroids(centroids) if !centroids.is_empty() => {
+                Some(nearest(centroids, descriptor))
+            }
+            Self::Centroids(_) => None,
+        }
+    }
+}
+
+fn nearest(centroids: &[Vec<f64>], point: &[f64]) -> usize {
+    centroids
+        .iter()
+        .map(|centroid| euclidean(centroid, point))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(index, _)| index)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Elites<T> {
+    tessellation: Tessellation,
+    cells: Vec<Option<T>>,
+}
+
+impl<T> Elites<T> {
+    pub fn new(tessellation: Tessellation) -> Self {
+        Self {
+            cells: std::iter::repeat_with(|| None)
+                .take(tessellation.cells())
+                .collect(),
+            tessellation,
+        }
+    }
+
+    pub fn tessellation(&self) -> &Tessellation {
+        &self.tessellation
+    }
+
+    pub fn cells(&self) -> &[Option<T>] {
+        &self.cells
+    }
+
+    pub fn get(&self, cell: usize) -> Option<&T> {
+        self.cells.get(cell).and_then(Option::as_ref)
+    }
+
+    pub fn locate(&self, descriptor: &[f64]) -> Option<&T> {
+        self.get(self.tessellation.cell(descriptor)?)
+    }
+
+    pub fn insert(&mut self, individual: T, descriptor: &[f64]) -> bool
+    where
+        T: Individual,
+    {
+        let Some(cell) = self.tessellation.cell(descriptor) else {
+            return false;
+        };
+
+        match &mut self.cells[cell] {
+            Some(elite) if elite.fitness() >= individual.fitness() => false,
+            slot => {
+                *slot = Some(individual);
+                true
+            }
+        }
+    }
+
+    pub fn coverage(&self) -> f64 {
+        if self.cells.is_empty() {
+            return 0.0;
+        }
+
+        self.cells.iter().flatten().count() as f64 / self.cells.len() as f64
+    }
+
+    pub fn qd_score(&self) -> f64
+    where
+        T: Individual<Fitness: Numeric>,
+    {
+        self.cells
+            .iter()
+            .flatten()
+            .map(|elite| elite.fitness().as_f64())
+            .sum()
+    }
+
+    pub fn best(&self) -> Option<&T>
+    where
+        T: Individual,
+    {
+        self.cells
+            .iter()
+            .flatten()
+            .max_by(|a, b| a.fitness().cmp(b.fitness()))
+    }
+}
+
+impl<T> Population for Elites<T>
+where
+    T: Individual,
+{
+    type Individual = T;
+
+    fn len(&self) -> usize {
+        self.cells.iter().flatten().count()
+    }
+}
+
+impl<T> IntoIterator for Elites<T> {
+    type Item = T;
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Option<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter().flatten()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Elites<T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Flatten<std::slice::Iter<'a, Option<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.iter().flatten()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Elites<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::iter::Flatten<std::slice::IterMut<'a, Option<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.iter_mut().flatten()
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ElitesError {
+    #[error("dimension must have a finite, non-empty range and at least one bin")]
+    InvalidDimension,
+    #[error("centroidal tessellation requires at least as many samples as cells")]
+    InvalidSamples,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::evaluated::Evaluated;
+    use crate::population::{IterablePopulation, Population};
+    use crate::util::iter::Iterable;
+
+    use super::{Descriptor, Dimension, Elites, ElitesError, Tessellation};
+
+    fn grid() -> Tessellation {
+        Tessellation::grid([
+            Dimension::new(0.0..=1.0, 4).unwrap(),
+            Dimension::new(0.0..=10.0, 2).unwrap(),
+        ])
+    }
+
+    #[test]
+    fn test_tessellation() {
+        let grid = grid();
+        let centroids = Tessellation::centroids(vec![vec![0.0, 0.0], vec![1.0, 1.0]]);
+
+        assert_eq!(grid.cells(), 8);
+        assert_eq!(grid.dimensions(), 2);
+        assert_eq!(grid.bounds(), [0.0..=1.0, 0.0..=10.0]);
+        assert_eq!(grid.cell(&[0.0, 0.0]), Some(0));
+        assert_eq!(grid.cell(&[0.3, 0.0]), Some(1));
+        assert_eq!(grid.cell(&[1.0, 10.0]), Some(7));
+        assert_eq!(grid.cell(&[-5.0, 20.0]), Some(4));
+        assert_eq!(grid.cell(&[0.5]), None);
+        assert_eq!(grid.cell(&[f64::NAN, 0.0]), None);
+        assert_eq!(centroids.cell(&[0.2, 0.4]), Some(0));
+        assert_eq!(centroids.cell(&[0.8, 0.4]), Some(1));
+        assert_eq!(centroids.bounds(), [0.0..=1.0, 0.0..=1.0]);
+        assert_eq!(
+            Dimension::new(1.0..=1.0, 2),
+            Err(ElitesError::InvalidDimension)
+        );
+        assert_eq!(
+            Dimension::new(0.0..=1.0, 0),
+            Err(ElitesError::InvalidDimension)
+        );
+    }
+
+    #[test]
+    fn test_cvt() {
+        let mut rng = rand::rng();
+
+        let a = Tessellation::cvt(16, &[0.0..=1.0, 0.0..=1.0], 1000, 10, &mut rng).unwrap();
+        let b = Tessellation::cvt(16, &[0.0..=1.0], 10, 10, &mut rng);
+        let c = Tessellation::cvt(16, &[0.0..=0.0], 100, 10, &mut rng);
+
+        assert_eq!(a.cells(), 16);
+        assert_eq!(a.dimensions(), 2);
+        assert!(a.cell(&[0.5, 0.5]).is_some());
+        assert_eq!(b, Err(ElitesError::InvalidSamples));
+        assert_eq!(c, Err(ElitesError::InvalidDimension));
+
+        if let Tessellation::Centroids(centroids) = a {
+            assert!(centroids
+                .iter()
+                .flatten()
+                .all(|value| (0.0..=1.0).contains(value)));
+        }
+    }
+
+    #[test]
+    fn test_insert() {
+        let describe = |individual: &Evaluated<[f64; 2], i32>| individual.individual;
+
+        let mut elites = Elites::new(grid());
+
+        assert!(elites.is_empty());
+        assert_eq!(elites.best(), None);
+
+        for (genome, fitness) in [
+            ([0.1, 1.0], 1),
+            ([0.2, 2.0], 3),
+            ([0.0, 0.0], 2),
+            ([0.9, 9.0], 5),
+            ([0.5, f64::NAN], 9),
+        ] {
+            let individual = Evaluated::new(genome, fitness);
+            let descriptor = describe.describe(&individual);
+
+            elites.insert(individual, &descriptor);
+        }
+
+        assert_eq!(elites.len(), 2);
+        assert_eq!(elites.coverage(), 0.25);
+        assert_eq!(elites.qd_score(), 8.0);
+        assert_eq!(elites.best(), Some(&Evaluated::new([0.9, 9.0], 5)));
+        assert_eq!(
+            elites.locate(&[0.0, 0.0]),
+            Some(&Evaluated::new([0.2, 2.0], 3))
+        );
+        assert_eq!(elites.get(1), None);
+        assert_eq!(
+            elites.iter().map(|elite| elite.fitness).collect::<Vec<_>>(),
+            [3, 5]
+        );
+    }
+
+    #[test]
+    fn test_population() {
+        fn erase<P>(population: P) -> impl IterablePopulation<Individual = i32>
+        where
+            P: IterablePopulation<Individual = i32>,
+        {
+            population
+        }
+
+        let mut elites = Elites::new(Tessellation::grid([Dimension::new(0.0..=4.0, 4).unwrap()]));
+
+        for value in [0, 3, 1] {
+            elites.insert(value, &[value as f64]);
+        }
+
+        assert_eq!(erase(elites).iter().collect::<Vec<_>>(), [&0, &1, &3]);
+    }
+}
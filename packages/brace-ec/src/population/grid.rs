@@ -0,0 +1,278 @@
+use std::ops::{Index, IndexMut};
+
+use thiserror::Error;
+
+use crate::individual::Individual;
+
+use super::Population;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Adjacency {
+    VonNeumann(usize),
+    Moore(usize),
+}
+
+impl Adjacency {
+    pub fn von_neumann() -> Self {
+        Self::VonNeumann(1)
+    }
+
+    pub fn moore() -> Self {
+        Self::Moore(1)
+    }
+
+    pub fn radius(&self) -> usize {
+        match self {
+            Self::VonNeumann(radius) | Self::Moore(radius) => *radius,
+        }
+    }
+
+    pub fn offsets(&self) -> Vec<(isize, isize)> {
+        let radius = self.radius() as isize;
+
+        (-radius..=radius)
+            .flat_map(|y| (-radius..=radius).map(move |x| (x, y)))
+            .filter(|(x, y)| match self {
+                Self::VonNeumann(_) => x.abs() + y.abs() <= radius,
+                Self::Moore(_) => true,
+            })
+            .collect()
+    }
+}
+
+impl Default for Adjacency {
+    fn default() -> Self {
+        Self::von_neumann()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, cells: Vec<T>) -> Result<Self, GridError> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return Err(GridError::InvalidWidth);
+        }
+
+        Ok(Self { width, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn wrap(&self, x: isize, y: isize) -> usize {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height().max(1) as isize) as usize;
+
+        y * self.width + x
+    }
+
+    pub fn neighbours(&self, index: usize, adjacency: Adjacency) -> Vec<usize> {
+        let (x, y) = self.position(index);
+
+        let mut neighbours = adjacency
+            .offsets()
+            .into_iter()
+            .map(|(dx, dy)| self.wrap(x as isize + dx, y as isize + dy))
+            .collect::<Vec<_>>();
+
+        neighbours.sort_unstable();
+        neighbours.dedup();
+        neighbours
+    }
+
+    pub fn neighbourhood(&self, index: usize, adjacency: Adjacency) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.neighbours(index, adjacency)
+            .into_iter()
+            .map(|neighbour| self.cells[neighbour].clone())
+            .collect()
+    }
+}
+
+impl<T> Population for Grid<T>
+where
+    T: Individual,
+{
+    type Individual = T;
+
+    fn len(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<T> AsRef<[T]> for Grid<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.cells
+    }
+}
+
+impl<T> AsMut<[T]> for Grid<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).expect("position out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("position out of bounds")
+    }
+}
+
+impl<T> IntoIterator for Grid<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.iter_mut()
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GridError {
+    #[error("grid width must be non-zero and divide the number of cells")]
+    InvalidWidth,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::population::{IterableMutPopulation, Population};
+
+    use super::{Adjacency, Grid, GridError};
+
+    fn grid() -> Grid<i32> {
+        Grid::new(4, (0..16).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let a = Grid::new(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let b = Grid::new(0, vec![1, 2, 3]);
+        let c = Grid::new(4, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(a.width(), 3);
+        assert_eq!(a.height(), 2);
+        assert_eq!(a.len(), 6);
+        assert_eq!(a[(2, 1)], 6);
+        assert_eq!(a.get(3, 0), None);
+        assert_eq!(a.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(b, Err(GridError::InvalidWidth));
+        assert_eq!(c, Err(GridError::InvalidWidth));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(Adjacency::von_neumann().offsets().len(), 5);
+        assert_eq!(Adjacency::moore().offsets().len(), 9);
+        assert_eq!(Adjacency::VonNeumann(2).offsets().len(), 13);
+        assert_eq!(Adjacency::Moore(2).offsets().len(), 25);
+        assert_eq!(
+            grid.neighbours(5, Adjacency::von_neumann()),
+            [1, 4, 5, 6, 9]
+        );
+        assert_eq!(
+            grid.neighbours(0, Adjacency::von_neumann()),
+            [0, 1, 3, 4, 12]
+        );
+        assert_eq!(
+            grid.neighbours(0, Adjacency::moore()),
+            [0, 1, 3, 4, 5, 7, 12, 13, 15]
+        );
+        assert_eq!(grid.neighbours(0, Adjacency::Moore(2)).len(), 16);
+        assert_eq!(grid.neighbourhood(15, Adjacency::VonNeumann(0)), [15]);
+    }
+
+    #[test]
+    fn test_iter() {
+        fn increment<P>(population: &mut P)
+        where
+            P: IterableMutPopulation<Individual = i32>,
+        {
+            population
+                .iter_mut()
+                .for_each(|individual| *individual += 1);
+        }
+
+        let mut grid = grid();
+
+        increment(&mut grid);
+
+        assert_eq!(grid.cells(), (1..17).collect::<Vec<_>>());
+        assert_eq!(grid.into_iter().sum::<i32>(), 136);
+    }
+}
@@ -1,3 +1,6 @@
+pub mod elites;
+pub mod grid;
+
 use crate::util::iter::{Iterable, IterableMut, ParIterable, ParIterableMut};
 
 use super::individual::Individual;
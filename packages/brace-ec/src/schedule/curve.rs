@@ -0,0 +1,150 @@
+use std::f64::consts::PI;
+
+use super::Schedule;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constant(pub f64);
+
+impl Schedule for Constant {
+    fn value(&self, _: u64) -> f64 {
+        self.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Linear {
+    start: f64,
+    end: f64,
+    duration: u64,
+}
+
+impl Linear {
+    pub fn new(start: f64, end: f64, duration: u64) -> Self {
+        Self {
+            start,
+            end,
+            duration,
+        }
+    }
+}
+
+impl Schedule for Linear {
+    fn value(&self, generation: u64) -> f64 {
+        let progress = progress(generation, self.duration);
+
+        self.start + (self.end - self.start) * progress
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exponential {
+    start: f64,
+    decay: f64,
+    minimum: f64,
+}
+
+impl Exponential {
+    pub fn new(start: f64, decay: f64) -> Self {
+        Self {
+            start,
+            decay,
+            minimum: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn with_minimum(mut self, minimum: f64) -> Self {
+        self.minimum = minimum;
+        self
+    }
+}
+
+impl Schedule for Exponential {
+    fn value(&self, generation: u64) -> f64 {
+        (self.start * self.decay.powf(generation as f64)).max(self.minimum)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cosine {
+    start: f64,
+    end: f64,
+    duration: u64,
+}
+
+impl Cosine {
+    pub fn new(start: f64, end: f64, duration: u64) -> Self {
+        Self {
+            start,
+            end,
+            duration,
+        }
+    }
+}
+
+impl Schedule for Cosine {
+    fn value(&self, generation: u64) -> f64 {
+        let progress = progress(generation, self.duration);
+
+        self.end + (self.start - self.end) * (1.0 + (PI * progress).cos()) / 2.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Step {
+    start: f64,
+    factor: f64,
+    interval: u64,
+}
+
+impl Step {
+    pub fn new(start: f64, factor: f64, interval: u64) -> Self {
+        Self {
+            start,
+            factor,
+            interval,
+        }
+    }
+}
+
+impl Schedule for Step {
+    fn value(&self, generation: u64) -> f64 {
+        let steps = generation.checked_div(self.interval).unwrap_or_default();
+
+        self.start * self.factor.powf(steps as f64)
+    }
+}
+
+fn progress(generation: u64, duration: u64) -> f64 {
+    match duration {
+        0 => 1.0,
+        _ => (generation as f64 / duration as f64).min(1.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schedule::Schedule;
+
+    use super::{Constant, Cosine, Exponential, Linear, Step};
+
+    #[test]
+    fn test_value() {
+        assert_eq!(Constant(0.3).value(100), 0.3);
+
+        assert_eq!(Linear::new(1.0, 0.0, 10).value(0), 1.0);
+        assert_eq!(Linear::new(1.0, 0.0, 10).value(5), 0.5);
+        assert_eq!(Linear::new(1.0, 0.0, 10).value(20), 0.0);
+        assert_eq!(Linear::new(1.0, 0.0, 0).value(0), 0.0);
+
+        assert_eq!(Exponential::new(8.0, 0.5).value(3), 1.0);
+        assert_eq!(Exponential::new(8.0, 0.5).with_minimum(2.0).value(5), 2.0);
+
+        assert_eq!(Cosine::new(1.0, 0.0, 10).value(0), 1.0);
+        assert!((Cosine::new(1.0, 0.0, 10).value(5) - 0.5).abs() < 1e-12);
+        assert_eq!(Cosine::new(1.0, 0.0, 10).value(10), 0.0);
+
+        assert_eq!(Step::new(1.0, 0.5, 10).value(9), 1.0);
+        assert_eq!(Step::new(1.0, 0.5, 10).value(25), 0.25);
+        assert_eq!(Step::new(1.0, 0.5, 0).value(25), 1.0);
+    }
+}
@@ -0,0 +1,119 @@
+use std::sync::{Arc, Mutex};
+
+use super::Schedule;
+
+#[derive(Debug)]
+struct State {
+    value: f64,
+    successes: usize,
+    trials: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct OneFifth {
+    state: Arc<Mutex<State>>,
+    factor: f64,
+    interval: usize,
+    minimum: f64,
+    maximum: f64,
+}
+
+impl OneFifth {
+    pub fn new(value: f64) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                value,
+                successes: 0,
+                trials: 0,
+            })),
+            factor: 0.817,
+            interval: 10,
+            minimum: 0.0,
+            maximum: f64::INFINITY,
+        }
+    }
+
+    pub fn with_factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    pub fn with_interval(mut self, interval: usize) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_bounds(mut self, minimum: f64, maximum: f64) -> Self {
+        self.minimum = minimum;
+        self.maximum = maximum;
+        self
+    }
+
+    pub fn get(&self) -> f64 {
+        self.state.lock().unwrap().value
+    }
+
+    pub fn record(&self, success: bool) {
+        let mut state = self.state.lock().unwrap();
+
+        state.trials += 1;
+        state.successes += success as usize;
+
+        if state.trials < self.interval.max(1) {
+            return;
+        }
+
+        let ratio = state.successes as f64 / state.trials as f64;
+
+        if ratio > 0.2 {
+            state.value /= self.factor;
+        } else if ratio < 0.2 {
+            state.value *= self.factor;
+        }
+
+        state.value = state.value.clamp(self.minimum, self.maximum);
+        state.successes = 0;
+        state.trials = 0;
+    }
+}
+
+impl Schedule for OneFifth {
+    fn value(&self, _: u64) -> f64 {
+        self.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OneFifth;
+
+    #[test]
+    fn test_record() {
+        let rule = OneFifth::new(1.0).with_factor(0.5).with_interval(5);
+        let shared = rule.clone();
+
+        for success in [true, true, false, false, false] {
+            shared.record(success);
+        }
+
+        assert_eq!(rule.get(), 2.0);
+
+        for _ in 0..5 {
+            shared.record(false);
+        }
+
+        assert_eq!(rule.get(), 1.0);
+
+        for success in [true, false, false, false, false] {
+            shared.record(success);
+        }
+
+        assert_eq!(rule.get(), 1.0);
+
+        let bounded = OneFifth::new(1.0).with_interval(1).with_bounds(0.5, 1.0);
+
+        bounded.record(true);
+
+        assert_eq!(bounded.get(), 1.0);
+    }
+}
@@ -0,0 +1,61 @@
+pub mod curve;
+pub mod feedback;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+pub trait Schedule {
+    fn value(&self, generation: u64) -> f64;
+}
+
+impl<F> Schedule for F
+where
+    F: Fn(u64) -> f64,
+{
+    fn value(&self, generation: u64) -> f64 {
+        self(generation)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Clock {
+    generation: Arc<AtomicU64>,
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self) -> u64 {
+        self.generation.load(Ordering::Relaxed)
+    }
+
+    pub fn set(&self, generation: u64) {
+        self.generation.store(generation, Ordering::Relaxed);
+    }
+
+    pub fn value<S>(&self, schedule: &S) -> f64
+    where
+        S: Schedule + ?Sized,
+    {
+        schedule.value(self.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Clock, Schedule};
+
+    #[test]
+    fn test_clock() {
+        let clock = Clock::new();
+        let shared = clock.clone();
+
+        shared.set(7);
+
+        assert_eq!(clock.get(), 7);
+        assert_eq!(clock.value(&|generation| generation as f64 * 2.0), 14.0);
+        assert_eq!((|generation| generation as f64).value(3), 3.0);
+    }
+}
@@ -0,0 +1,65 @@
+pub mod particle;
+pub mod topology;
+
+use crate::fitness::Fitness;
+use crate::generation::Generation;
+
+use self::particle::Particle;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Swarm<F> {
+    id: usize,
+    particles: Vec<Particle<F>>,
+}
+
+impl<F> Swarm<F> {
+    pub fn new(particles: Vec<Particle<F>>) -> Self {
+        Self { id: 0, particles }
+    }
+
+    pub fn particles(&self) -> &[Particle<F>] {
+        &self.particles
+    }
+}
+
+impl<F> Swarm<F>
+where
+    F: Fitness,
+{
+    pub fn best(&self) -> Option<(&[f64], &F)> {
+        self.particles
+            .iter()
+            .filter_map(Particle::best)
+            .max_by_key(|(_, fitness)| *fitness)
+    }
+}
+
+impl<F> Generation for Swarm<F>
+where
+    F: Fitness,
+{
+    type Id = usize;
+    type Population = Vec<Particle<F>>;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+
+    fn population(&self) -> &Self::Population {
+        &self.particles
+    }
+
+    fn population_mut(&mut self) -> &mut Self::Population {
+        &mut self.particles
+    }
+
+    fn advance(&mut self) {
+        self.id += 1;
+    }
+}
+
+impl<F> From<Vec<Particle<F>>> for Swarm<F> {
+    fn from(particles: Vec<Particle<F>>) -> Self {
+        Self::new(particles)
+    }
+}
@@ -0,0 +1,189 @@
+use std::convert::Infallible;
+
+use crate::fitness::Fitness;
+use crate::individual::Individual;
+use crate::population::Population;
+use crate::util::iter::TryFromIterator;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Particle<F> {
+    position: Vec<f64>,
+    velocity: Vec<f64>,
+    fitness: F,
+    best: Option<(Vec<f64>, F)>,
+}
+
+impl<F> Particle<F>
+where
+    F: Fitness,
+{
+    pub fn new(position: Vec<f64>) -> Self {
+        let velocity = vec![0.0; position.len()];
+
+        Self::with_velocity(position, velocity)
+    }
+
+    pub fn with_velocity(position: Vec<f64>, velocity: Vec<f64>) -> Self {
+        Self {
+            position,
+            velocity,
+            fitness: F::nil(),
+            best: None,
+        }
+    }
+}
+
+impl<F> Particle<F> {
+    pub fn position(&self) -> &[f64] {
+        &self.position
+    }
+
+    pub fn velocity(&self) -> &[f64] {
+        &self.velocity
+    }
+
+    pub fn best(&self) -> Option<(&[f64], &F)> {
+        self.best
+            .as_ref()
+            .map(|(position, fitness)| (position.as_slice(), fitness))
+    }
+
+    pub(crate) fn parts_mut(&mut self) -> (&mut [f64], &mut [f64]) {
+        (&mut self.position, &mut self.velocity)
+    }
+}
+
+impl<F> Particle<F>
+where
+    F: Fitness + Clone,
+{
+    pub fn remember(&mut self) -> bool {
+        if matches!(&self.best, Some((_, best)) if best >= &self.fitness) {
+            return false;
+        }
+
+        self.best = Some((self.position.clone(), self.fitness.clone()));
+
+        true
+    }
+}
+
+impl<F> Individual for Particle<F>
+where
+    F: Fitness,
+{
+    type Genome = [f64];
+    type Fitness = F;
+
+    fn genome(&self) -> &Self::Genome {
+        &self.position
+    }
+
+    fn genome_mut(&mut self) -> &mut Self::Genome {
+        &mut self.position
+    }
+
+    fn fitness(&self) -> &Self::Fitness {
+        &self.fitness
+    }
+
+    fn fitness_mut(&mut self) -> &mut Self::Fitness {
+        &mut self.fitness
+    }
+}
+
+impl<F> Population for Particle<F>
+where
+    F: Fitness,
+{
+    type Individual = f64;
+
+    fn len(&self) -> usize {
+        self.position.len()
+    }
+}
+
+impl<F> TryFromIterator<f64> for Particle<F>
+where
+    F: Fitness,
+{
+    type Error = Infallible;
+
+    fn try_from_iter<I>(iter: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        Ok(Self::from_iter(iter))
+    }
+}
+
+impl<F> FromIterator<f64> for Particle<F>
+where
+    F: Fitness,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<F> From<Vec<f64>> for Particle<F>
+where
+    F: Fitness,
+{
+    fn from(position: Vec<f64>) -> Self {
+        Self::new(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::individual::Individual;
+    use crate::operator::generator::random::Random;
+    use crate::operator::generator::Generator;
+
+    use super::Particle;
+
+    #[test]
+    fn test_remember() {
+        let mut particle = Particle::new(vec![1.0, 2.0]);
+
+        assert_eq!(particle.best(), None);
+
+        *particle.fitness_mut() = 5;
+
+        assert!(particle.remember());
+        assert_eq!(particle.best(), Some((&[1.0, 2.0][..], &5)));
+
+        particle.genome_mut()[0] = 3.0;
+        *particle.fitness_mut() = 4;
+
+        assert!(!particle.remember());
+        assert_eq!(particle.best(), Some((&[1.0, 2.0][..], &5)));
+
+        *particle.fitness_mut() = 6;
+
+        assert!(particle.remember());
+        assert_eq!(particle.best(), Some((&[3.0, 2.0][..], &6)));
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut rng = rand::rng();
+
+        let particles: Vec<Particle<u8>> = Random::from(-1.0..1.0)
+            .populate(3)
+            .populate(10)
+            .generate(&mut rng)
+            .unwrap();
+
+        assert_eq!(particles.len(), 10);
+        assert!(particles
+            .iter()
+            .all(|particle| particle.position().len() == 3
+                && particle.velocity() == [0.0; 3]
+                && particle.best().is_none()));
+    }
+}
@@ -0,0 +1,66 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Topology {
+    #[default]
+    Global,
+    Ring(usize),
+    VonNeumann,
+}
+
+impl Topology {
+    pub fn ring() -> Self {
+        Self::Ring(1)
+    }
+
+    pub fn neighbours(&self, index: usize, len: usize) -> Vec<usize> {
+        match self {
+            Self::Global => (0..len).collect(),
+            Self::Ring(radius) => {
+                let radius = (*radius).min(len / 2);
+                let mut neighbours = (0..=2 * radius)
+                    .map(|offset| (index + len + offset - radius) % len)
+                    .collect::<Vec<_>>();
+
+                neighbours.sort_unstable();
+                neighbours.dedup();
+                neighbours
+            }
+            Self::VonNeumann => {
+                let columns = (len as f64).sqrt().ceil().max(1.0) as usize;
+                let rows = len.div_ceil(columns);
+                let (row, column) = (index / columns, index % columns);
+
+                let mut neighbours = [
+                    (row, column),
+                    ((row + rows - 1) % rows, column),
+                    ((row + 1) % rows, column),
+                    (row, (column + columns - 1) % columns),
+                    (row, (column + 1) % columns),
+                ]
+                .into_iter()
+                .map(|(row, column)| row * columns + column)
+                .filter(|neighbour| *neighbour < len)
+                .collect::<Vec<_>>();
+
+                neighbours.sort_unstable();
+                neighbours.dedup();
+                neighbours
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Topology;
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(Topology::Global.neighbours(2, 4), [0, 1, 2, 3]);
+        assert_eq!(Topology::ring().neighbours(0, 5), [0, 1, 4]);
+        assert_eq!(Topology::Ring(2).neighbours(3, 5), [0, 1, 2, 3, 4]);
+        assert_eq!(Topology::Ring(4).neighbours(1, 3), [0, 1, 2]);
+        assert_eq!(Topology::VonNeumann.neighbours(0, 9), [0, 1, 2, 3, 6]);
+        assert_eq!(Topology::VonNeumann.neighbours(4, 9), [1, 3, 4, 5, 7]);
+        assert_eq!(Topology::VonNeumann.neighbours(1, 7), [0, 1, 2, 4]);
+    }
+}
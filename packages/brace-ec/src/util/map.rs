@@ -42,3 +42,48 @@ impl<T> TryMap for Option<T> {
         }
     }
 }
+
+pub trait MapInto<U> {
+    type Item;
+    type Output;
+
+    fn map_into<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U;
+}
+
+impl<const N: usize, T, U> MapInto<U> for [T; N] {
+    type Item = T;
+    type Output = [U; N];
+
+    fn map_into<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        self.map(f)
+    }
+}
+
+impl<T, U> MapInto<U> for Vec<T> {
+    type Item = T;
+    type Output = Vec<U>;
+
+    fn map_into<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<T, U> MapInto<U> for Option<T> {
+    type Item = T;
+    type Output = Option<U>;
+
+    fn map_into<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        self.map(f)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod iter;
 pub mod map;
+pub mod random;
 pub mod range;
 pub mod sum;
@@ -0,0 +1,39 @@
+use std::f64::consts::PI;
+
+pub fn normal<Rng>(rng: &mut Rng, mean: f64, deviation: f64) -> f64
+where
+    Rng: rand::Rng + ?Sized,
+{
+    let radius = (-2.0 * (1.0 - rng.random::<f64>()).ln()).sqrt();
+    let angle = 2.0 * PI * rng.random::<f64>();
+
+    mean + deviation * radius * angle.cos()
+}
+
+pub fn cauchy<Rng>(rng: &mut Rng, location: f64, scale: f64) -> f64
+where
+    Rng: rand::Rng + ?Sized,
+{
+    location + scale * (PI * (rng.random::<f64>() - 0.5)).tan()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normal;
+
+    #[test]
+    fn test_normal() {
+        let mut rng = rand::rng();
+
+        let samples = (0..10_000)
+            .map(|_| normal(&mut rng, 3.0, 2.0))
+            .collect::<Vec<_>>();
+
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance =
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+
+        assert!((mean - 3.0).abs() < 0.1);
+        assert!((variance.sqrt() - 2.0).abs() < 0.1);
+    }
+}